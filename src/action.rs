@@ -27,6 +27,12 @@ pub use _tuple::tuple;
 use bevy::prelude::Reflect;
 pub use map::Map;
 pub use remake::Remake;
+pub use emit::EmitAs;
+pub use repeat::Repeat;
+pub use retry::{Retry, RetryPolicy};
+pub use store::StoreIn;
+pub use timeout::Timeout;
+pub use weighted::Weighted;
 
 pub mod once;
 pub mod wait;
@@ -36,12 +42,29 @@ pub mod seed;
 pub mod through;
 pub mod pipe;
 pub mod inspect;
+pub mod mark;
 pub mod sequence;
 pub mod omit;
+pub mod net;
+pub mod result;
+pub mod sync;
+pub mod tween;
+#[cfg(feature = "cutscene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cutscene")))]
+pub mod cutscene;
+#[cfg(feature = "analytics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "analytics")))]
+pub mod funnel;
 #[path = "action/tuple.rs"]
 mod _tuple;
 mod map;
 mod remake;
+mod emit;
+mod repeat;
+mod retry;
+mod store;
+mod timeout;
+mod weighted;
 #[cfg(feature = "effect")]
 #[cfg_attr(docsrs, doc(cfg(feature = "effect")))]
 pub mod effect;
@@ -75,6 +98,22 @@ where
         (self.0, self.1)
     }
 
+    /// Gives the name to this action.
+    ///
+    /// Please see [`ActionSeed::named`] for details.
+    #[inline]
+    pub fn named(self, name: impl Into<std::borrow::Cow<'static, str>>) -> Action<I1, O1> {
+        Action(self.0, self.1.named(name))
+    }
+
+    /// Makes this action keep running even while its reactor is paused.
+    ///
+    /// Please see [`ActionSeed::run_even_if_paused`] for details.
+    #[inline]
+    pub fn run_even_if_paused(self) -> Action<I1, O1> {
+        Action(self.0, self.1.run_even_if_paused())
+    }
+
     /// Creates the [`BoxedRunner`].
     ///
     /// This method is mainly useful for creating custom runners.
@@ -0,0 +1,392 @@
+//! Provides [`Cutscene`], the builder that compiles a declarative list of steps into a single action.
+//!
+//! The steps are run in order, and [`Cutscene::parallel`] runs a group of steps concurrently.
+//! The steps can be skipped to a [`Cutscene::label`] by sending [`SkipCutscene`].
+//!
+//! The [`cutscene!`](crate::cutscene) macro is shorthand for the builder.
+
+use crate::action::omit::Omit;
+use crate::action::{delay, once, tween, wait, Action};
+use crate::prelude::ActionSeed;
+use crate::runner::{BoxedRunner, CancellationHandlers, CancellationId, Output, Runner, RunnerIs};
+use bevy::ecs::event::EventCursor;
+use bevy::math::curve::easing::EaseFunction;
+use bevy::prelude::{Entity, Event, Events, Resource, Vec3, World};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// The builder that compiles a declarative list of steps into a single action.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event)]
+/// struct ShowTitle;
+///
+/// Reactor::schedule(|task| async move{
+///     let camera = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     task.will(Update, Cutscene::new()
+///         .event(ShowTitle)
+///         .delay(Duration::from_secs(1))
+///         .parallel(Cutscene::new()
+///             .camera_move(camera, Vec3::X * 100., Duration::from_secs(2), EaseFunction::CubicInOut)
+///             .dialog("Long ago...")
+///         )
+///         .label("battle")
+///         .step(once::run(|| info!("battle start")))
+///     ).await;
+/// });
+/// ```
+#[derive(Default)]
+pub struct Cutscene {
+    steps: Vec<Step>,
+}
+
+enum Step {
+    Action(ActionSeed),
+    Label(Cow<'static, str>),
+}
+
+impl Cutscene {
+    /// Creates an empty [`Cutscene`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the step that runs the `action`.
+    ///
+    /// The output of the action is discarded.
+    #[inline]
+    pub fn step<I, O>(mut self, action: impl Into<Action<I, O>> + 'static) -> Self
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        self.steps.push(Step::Action(action.into().omit()));
+        self
+    }
+
+    /// Adds the step that delays by `duration`.
+    #[inline]
+    pub fn delay(self, duration: Duration) -> Self {
+        self.step(delay::time().with(duration))
+    }
+
+    /// Adds the step that sends the `event`.
+    #[inline]
+    pub fn event<E: Event>(self, event: E) -> Self {
+        self.step(once::event::send().with(event))
+    }
+
+    /// Adds the step that shows the dialog `text` via [`CutsceneDialog`], and waits until it is dismissed.
+    ///
+    /// If [`Localization`](crate::prelude::Localization) exists, `text` is treated as a key and resolved when the step is run.
+    ///
+    /// See [`wait::dialogue::say`](crate::prelude::wait::dialogue::say).
+    #[inline]
+    pub fn dialog(self, text: impl Into<Cow<'static, str>>) -> Self {
+        self.step(wait::dialogue::say().with(text.into()))
+    }
+
+    /// Adds the step that moves the translation of the `camera` to `target` over `duration`.
+    ///
+    /// See [`tween::transform::move_to`](crate::prelude::tween::transform::move_to).
+    #[inline]
+    pub fn camera_move(self, camera: Entity, target: Vec3, duration: Duration, ease: EaseFunction) -> Self {
+        self.step(tween::transform::move_to(target, duration, ease).with(camera))
+    }
+
+    /// Adds the step that runs all steps of the `group` concurrently, and waits until all of them are completed.
+    ///
+    /// The labels in the `group` are ignored.
+    #[inline]
+    pub fn parallel(self, group: Cutscene) -> Self {
+        let actions = group
+            .steps
+            .into_iter()
+            .filter_map(|step| match step {
+                Step::Action(action) => Some(action),
+                Step::Label(_) => None,
+            })
+            .collect::<Vec<_>>();
+        self.step(wait::all().with(actions))
+    }
+
+    /// Adds the label to which the steps can be skipped by [`SkipCutscene`].
+    #[inline]
+    pub fn label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.steps.push(Step::Label(label.into()));
+        self
+    }
+
+    /// Compiles the steps into a single action.
+    #[inline]
+    pub fn build(self) -> ActionSeed {
+        ActionSeed::new(|_, output| CutsceneRunner {
+            steps: self.steps.into(),
+            current: None,
+            cursor: None,
+            output,
+        })
+    }
+}
+
+impl From<Cutscene> for ActionSeed {
+    #[inline]
+    fn from(cutscene: Cutscene) -> Self {
+        cutscene.build()
+    }
+}
+
+impl From<Cutscene> for Action<(), ()> {
+    #[inline]
+    fn from(cutscene: Cutscene) -> Self {
+        cutscene.build().into()
+    }
+}
+
+/// The event that skips the running cutscenes to the label.
+///
+/// The cutscenes that have the label after the current step drop the current step,
+/// call the cancellation handlers registered by it, and resume from the label.
+/// The other cutscenes ignore this event.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn skip(mut ew: EventWriter<SkipCutscene>){
+///     ew.send(SkipCutscene::to("battle"));
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Eq, PartialEq)]
+pub struct SkipCutscene(Cow<'static, str>);
+
+impl SkipCutscene {
+    /// Creates the event that skips to the `label`.
+    #[inline]
+    pub fn to(label: impl Into<Cow<'static, str>>) -> Self {
+        Self(label.into())
+    }
+}
+
+/// The dialog text shown by [`Cutscene::dialog`] and [`wait::dialogue::say`](crate::prelude::wait::dialogue::say).
+///
+/// The UI systems read the text and call [`CutsceneDialog::dismiss`] to advance the cutscene.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn dialog_ui(
+///     dialog: Option<ResMut<CutsceneDialog>>,
+///     input: Res<ButtonInput<KeyCode>>,
+/// ){
+///     let Some(mut dialog) = dialog else {
+///         return;
+///     };
+///     if let Some(text) = dialog.text() {
+///         info!("{text}");
+///     }
+///     if input.just_pressed(KeyCode::Enter) {
+///         dialog.dismiss();
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct CutsceneDialog(pub(crate) Option<Cow<'static, str>>);
+
+impl CutsceneDialog {
+    /// Returns the text shown now.
+    #[inline]
+    pub fn text(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+
+    /// Dismisses the text, so the cutscene advances to the next step.
+    #[inline]
+    pub fn dismiss(&mut self) {
+        self.0.take();
+    }
+}
+
+struct CutsceneRunner {
+    steps: VecDeque<Step>,
+    /// The runner of the current step and the ids of the cancellation handlers registered by it.
+    current: Option<(BoxedRunner, Vec<CancellationId>)>,
+    cursor: Option<EventCursor<SkipCutscene>>,
+    output: Output<()>,
+}
+
+impl CutsceneRunner {
+    fn skip_if_requested(&mut self, world: &mut World, token: &mut CancellationHandlers) {
+        let Some(events) = world.get_resource::<Events<SkipCutscene>>() else {
+            return;
+        };
+        let cursor = self.cursor.get_or_insert_with(|| events.get_cursor_current());
+        let Some(i) = cursor
+            .read(events)
+            .filter_map(|skip| self.steps.iter().position(|step| matches!(step, Step::Label(label) if label == &skip.0)))
+            .last() else {
+            return;
+        };
+        self.steps.drain(..=i);
+        if let Some((runner, ids)) = self.current.take() {
+            drop(runner);
+            let handlers = ids.iter().filter_map(|id| token.handlers.remove(id)).collect::<Vec<_>>();
+            for handler in handlers {
+                handler(world);
+            }
+        }
+    }
+}
+
+impl Runner for CutsceneRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        self.skip_if_requested(world, token);
+        loop {
+            if self.current.is_none() {
+                match self.steps.pop_front() {
+                    Some(Step::Action(action)) => {
+                        self.current.replace((action.with(()).create_runner(Output::default()), Vec::new()));
+                    }
+                    Some(Step::Label(_)) => continue,
+                    None => {
+                        self.output.set(());
+                        return RunnerIs::Completed;
+                    }
+                }
+            }
+            let (runner, ids) = self.current.as_mut().expect("The current step must exist");
+            let status = token.record(ids, |token| runner.run(world, token));
+            match status {
+                RunnerIs::Completed => {
+                    self.current = None;
+                }
+                RunnerIs::Running => return RunnerIs::Running,
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+            }
+        }
+    }
+}
+
+/// Builds [`Cutscene`] from a declarative list of steps.
+///
+/// Each step is written as the method call of [`Cutscene`] without the receiver.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::cutscene;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, cutscene![
+///         dialog("Long ago..."),
+///         parallel(cutscene![
+///             delay(Duration::from_secs(1)),
+///             dialog("in a galaxy far, far away"),
+///         ]),
+///         label("end"),
+///     ]).await;
+/// });
+/// ```
+#[macro_export]
+macro_rules! cutscene {
+    ($($step: ident ($($arg: expr),* $(,)?)),* $(,)?) => {
+        $crate::prelude::Cutscene::new()$(.$step($($arg),*))*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{CutsceneDialog, Localization, Reactor, SkipCutscene};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Events, ResMut};
+    use bevy::utils::HashMap;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    fn add_count(n: usize) -> crate::prelude::ActionSeed {
+        once::run(move |mut count: ResMut<Count>| {
+            count.0 += n;
+        })
+    }
+
+    #[test]
+    fn run_steps_in_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, cutscene![
+                    step(add_count(1)),
+                    dialog("hello"),
+                    parallel(cutscene![
+                        step(add_count(10)),
+                        step(add_count(100)),
+                    ]),
+                ]).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        assert_eq!(app.world().resource::<CutsceneDialog>().text(), Some("hello"));
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        app.world_mut().resource_mut::<CutsceneDialog>().dismiss();
+        app.update();
+        app.assert_resource_eq(Count(111));
+    }
+
+    #[test]
+    fn localize_dialog() {
+        let mut app = test_app();
+        app.insert_resource(Localization::new(HashMap::from_iter([
+            ("greeting".to_string(), "こんにちは".to_string()),
+        ])));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, cutscene![dialog("greeting")]).await;
+            }));
+        });
+        app.update();
+        assert_eq!(app.world().resource::<CutsceneDialog>().text(), Some("こんにちは"));
+    }
+
+    #[test]
+    fn skip_to_label() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, cutscene![
+                    delay(Duration::from_secs(100)),
+                    step(add_count(1)),
+                    label("end"),
+                    step(add_count(10)),
+                ]).await;
+            }));
+        });
+        app.update();
+        app.world_mut().resource_mut::<Events<SkipCutscene>>().send(SkipCutscene::to("end"));
+        app.update();
+        app.assert_resource_eq(Count(10));
+    }
+}
@@ -2,12 +2,18 @@
 
 use crate::action::wait;
 use crate::prelude::ActionSeed;
-use bevy::prelude::{In, Local, Res, TimerMode};
-use bevy::time::{Time, Timer};
+use crate::reactor::time::ReactorDelta;
+use bevy::prelude::{In, Local, Res, ResMut, Resource, TimerMode};
+use bevy::time::Timer;
+use bevy::utils::Instant;
 use std::time::Duration;
 
+pub mod time;
+
 /// Delays by the specified amount of time.
 ///
+/// The elapsed time is scaled by [`ReactorTime`](crate::prelude::ReactorTime) of the reactor.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -22,7 +28,7 @@ use std::time::Duration;
 #[inline(always)]
 pub fn time() -> ActionSeed<Duration> {
     wait::until(
-        move |In(duration): In<Duration>, mut timer: Local<Option<Timer>>, time: Res<Time>| {
+        move |In(duration): In<Duration>, mut timer: Local<Option<Timer>>, time: ReactorDelta| {
             timer
                 .get_or_insert_with(|| Timer::new(duration, TimerMode::Once))
                 .tick(time.delta())
@@ -33,6 +39,10 @@ pub fn time() -> ActionSeed<Duration> {
 
 /// Delays the specified number of frames.
 ///
+/// Precisely, this counts the number of times the schedule passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will) has run,
+/// so in [`FixedUpdate`](bevy::prelude::FixedUpdate) it counts the fixed ticks, which may run several times or not at all in a frame.
+/// To count the fixed ticks from any schedule, use [`delay::fixed_ticks`](crate::prelude::delay::fixed_ticks).
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -51,13 +61,100 @@ pub fn frames() -> ActionSeed<usize> {
     })
 }
 
+/// Delays the specified number of fixed ticks, i.e. runs of [`FixedMain`](bevy::app::FixedMain).
+///
+/// Unlike [`delay::frames`](crate::prelude::delay::frames), the ticks are counted regardless of the schedule on which this action runs.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::fixed_ticks().with(30)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn fixed_ticks() -> ActionSeed<u64> {
+    wait::until(move |In(ticks): In<u64>, mut start: Local<Option<u64>>, fixed_ticks: Res<FixedTicks>| {
+        let start = start.get_or_insert(fixed_ticks.0);
+        ticks <= fixed_ticks.0 - *start
+    })
+}
+
+/// The number of fixed ticks since the app started.
+#[derive(Resource, Default)]
+pub(crate) struct FixedTicks(u64);
+
+pub(crate) fn count_fixed_ticks(mut fixed_ticks: ResMut<FixedTicks>) {
+    fixed_ticks.0 += 1;
+}
+
+/// The absolute deadline awaited by [`delay::until`](crate::prelude::delay::until).
+pub trait Deadline: Clone + Send + Sync + 'static {
+    /// Advances by `delta`, and returns whether the deadline has been reached.
+    fn reached(&mut self, delta: Duration) -> bool;
+}
+
+impl Deadline for Instant {
+    #[inline]
+    fn reached(&mut self, _: Duration) -> bool {
+        *self <= Instant::now()
+    }
+}
+
+impl Deadline for Timer {
+    #[inline]
+    fn reached(&mut self, delta: Duration) -> bool {
+        self.tick(delta).finished()
+    }
+}
+
+/// Delays until the passed deadline is reached.
+///
+/// If the deadline is [`Instant`], this waits until the wall-clock time passes it,
+/// which is useful for the deadlines received from outside such as a server.
+/// If the deadline is [`Timer`], it is ticked by the same time as [`delay::time()`](crate::prelude::delay::time()),
+/// so a timer that is already partially elapsed, e.g. loaded from a save, is resumed from where it left off.
+/// In both cases, the action completes immediately if the deadline has already been reached.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy::utils::Instant;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let deadline = Instant::now() + Duration::from_secs(60);
+///     task.will(Update, delay::until().with(deadline)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn until<D>() -> ActionSeed<D>
+where
+    D: Deadline,
+{
+    wait::until(|In(deadline): In<D>, mut state: Local<Option<D>>, time: ReactorDelta| {
+        state
+            .get_or_insert(deadline)
+            .reached(time.delta())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::{delay, once};
     use crate::prelude::{Reactor, Then};
     use crate::tests::test_app;
-    use bevy::app::{AppExit, First, Startup};
-    use bevy::prelude::{Commands, Events};
+    use crate::tests::increment_count;
+    use bevy::app::{AppExit, First, FixedUpdate, Startup, Update};
+    use bevy::prelude::{Commands, Events, Fixed, Time};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use std::time::Duration;
     use bevy_test_helper::event::DirectEvents;
     use bevy_test_helper::resource::DirectResourceControl;
 
@@ -105,4 +202,68 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
+
+    fn two_fixed_ticks_per_frame(app: &mut bevy::app::App) {
+        app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(50)));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        // Skips the first frames until the delta time becomes constant.
+        app.update();
+        app.update();
+    }
+
+    #[test]
+    fn delay_fixed_ticks() {
+        let mut app = test_app();
+        two_fixed_ticks_per_frame(&mut app);
+        app.world_mut().commands().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, delay::fixed_ticks().with(4).then(increment_count())).await;
+        }));
+        // The reactor is initialized in this frame.
+        app.update();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn advance_actions_every_fixed_tick() {
+        let mut app = test_app();
+        two_fixed_ticks_per_frame(&mut app);
+        app.world_mut().commands().spawn(Reactor::schedule(|task| async move {
+            loop {
+                task.will(FixedUpdate, increment_count()).await;
+            }
+        }));
+        app.update();
+        app.update();
+        let count = app.resource::<Count>().0;
+        app.update();
+        app.assert_resource_eq(Count(count + 2));
+    }
+
+    #[test]
+    fn delay_until_deadline() {
+        use bevy::prelude::TimerMode;
+        use bevy::time::Timer;
+        use bevy::utils::Instant;
+
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let mut timer = Timer::new(Duration::from_millis(500), TimerMode::Once);
+        timer.tick(Duration::from_millis(300));
+        app.world_mut().spawn(Reactor::schedule(move |task| async move {
+            task.will(Update, delay::until().with(Instant::now()).then(increment_count())).await;
+            task.will(Update, delay::until().with(timer).then(increment_count())).await;
+        }));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
 }
@@ -0,0 +1,166 @@
+//! [`delay::time`] provides the variants of [`delay::time()`](crate::prelude::delay::time())
+//! that measure the elapsed time with the clocks other than [`Time`].
+//!
+//! - [`real`]: unaffected by pausing or changing the speed of [`Time<Virtual>`]
+//! - [`virt`]: stops while [`Time<Virtual>`] is paused, even on the fixed schedules
+//! - [`scaled_by`]: multiplied by the value of a resource, e.g. for slow-motion effects
+
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use crate::reactor::time::ReactorDelta;
+use bevy::prelude::{In, Local, Res, Resource, Time, TimerMode};
+use bevy::time::{Real, Timer, Virtual};
+use std::time::Duration;
+
+/// Delays by the specified amount of real time.
+///
+/// Unlike [`delay::time()`](crate::prelude::delay::time()), the elapsed time is measured by [`Time<Real>`],
+/// so it keeps elapsing while the game is paused, and is not scaled by [`ReactorTime`](crate::prelude::ReactorTime).
+/// This is useful for the flows of pause menus.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::run(|mut time: ResMut<Time<Virtual>>| time.pause())).await;
+///     task.will(Update, delay::time::real().with(Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn real() -> ActionSeed<Duration> {
+    wait::until(|In(duration): In<Duration>, mut timer: Local<Option<Timer>>, time: Res<Time<Real>>| {
+        tick(&mut timer, duration, time.delta())
+    })
+}
+
+/// Delays by the specified amount of virtual time.
+///
+/// The elapsed time is measured by [`Time<Virtual>`] and scaled by [`ReactorTime`](crate::prelude::ReactorTime),
+/// so it stops while [`Time<Virtual>`] is paused, even if the action runs on the fixed schedules
+/// where [`Time`] is [`Time<Fixed>`](bevy::time::Fixed).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(FixedUpdate, delay::time::virt().with(Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn virt() -> ActionSeed<Duration> {
+    wait::until(|In(duration): In<Duration>, mut timer: Local<Option<Timer>>, time: Res<Time<Virtual>>, reactor: ReactorDelta| {
+        tick(&mut timer, duration, time.delta().mul_f32(reactor.scale()))
+    })
+}
+
+/// Delays by the specified amount of time multiplied by the value returned by `scale` from the resource `R`.
+///
+/// The elapsed time is the same as [`delay::time()`](crate::prelude::delay::time()) multiplied by the scale,
+/// which is read every frame, so changing the resource affects the delays already running.
+/// If the resource does not exist, the scale is `1.0`, and negative scales are regarded as `0.0`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct SlowMotion(f32);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, delay::time::scaled_by(|slow: &SlowMotion| slow.0).with(Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn scaled_by<R>(scale: impl Fn(&R) -> f32 + Send + Sync + 'static) -> ActionSeed<Duration>
+where
+    R: Resource,
+{
+    wait::until(move |In(duration): In<Duration>, mut timer: Local<Option<Timer>>, resource: Option<Res<R>>, time: ReactorDelta| {
+        let scale = resource.map(|resource| scale(&resource).max(0.)).unwrap_or(1.);
+        tick(&mut timer, duration, time.delta().mul_f32(scale))
+    })
+}
+
+fn tick(timer: &mut Option<Timer>, duration: Duration, delta: Duration) -> bool {
+    timer
+        .get_or_insert_with(|| Timer::new(duration, TimerMode::Once))
+        .tick(delta)
+        .just_finished()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::delay;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::Update;
+    use bevy::prelude::{Resource, Time, Virtual};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Resource)]
+    struct SlowMotion(f32);
+
+    fn spawn_delay(app: &mut bevy::app::App, seed: crate::prelude::ActionSeed<Duration>) {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, seed.with(Duration::from_millis(300)).then(increment_count())).await;
+        }));
+    }
+
+    #[test]
+    fn real_time_elapses_while_paused() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        spawn_delay(&mut app, delay::time::real());
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn virtual_time_stops_while_paused() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        spawn_delay(&mut app, delay::time::virt());
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn scaled_by_resource() {
+        let mut app = test_app();
+        app.insert_resource(SlowMotion(0.5));
+        spawn_delay(&mut app, delay::time::scaled_by(|slow: &SlowMotion| slow.0));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -1,5 +1,10 @@
 //! Convert the operations with side effects such as asynchronous runtime or thread
 //! into the referential-transparent actions.
+//!
+//! To run blocking IO such as reading files or HTTP requests without freezing the app,
+//! use [`effect::thread::spawn`](crate::prelude::effect::thread::spawn) or
+//! [`effect::tokio::spawn`](crate::prelude::effect::tokio::spawn) (requires `tokio` feature flag).
+//! Their runners check the result every frame, and output the computed value on the main thread.
 
 use std::future::Future;
 
@@ -9,6 +14,7 @@ pub mod tokio;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod thread;
 pub mod bevy_task;
+pub mod algorithm;
 
 /// This trait is implemented for functions that return future or future.
 pub trait AsyncFunctor<I, Out, M> {
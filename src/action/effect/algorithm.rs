@@ -0,0 +1,158 @@
+//! Runs incremental algorithms on the main thread, spreading their steps over multiple frames.
+//!
+//! actions
+//!
+//! - [`effect::algorithm::time_sliced`](crate::prelude::effect::algorithm::time_sliced)
+
+use crate::prelude::{ActionSeed, CancellationHandlers, RunnerIs};
+use crate::runner::{Output, Runner};
+use bevy::prelude::World;
+use bevy::utils::Instant;
+use std::time::Duration;
+
+/// Advances the iterator passed as input for at most `budget` per frame,
+/// and then wait until it yields `Some`.
+///
+/// Each item of the iterator represents one step of the algorithm (e.g. A*, flood fill);
+/// `None` means the algorithm is still in progress, and `Some` is its result.
+/// At least one step is performed per frame even if the budget is exhausted.
+///
+/// The output value is the first `Some` yielded, or `None` if the iterator ends without a result.
+///
+/// Unlike [`effect::thread::spawn`](crate::prelude::effect::thread::spawn),
+/// the steps run inside the runner, so the iterator does not need to be [`Send`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let steps = (0..10_000_usize).map(|i| (i == 9_999).then_some(i));
+///     let found: Option<usize> = task.will(Update, {
+///         effect::algorithm::time_sliced(Duration::from_millis(2)).with(steps)
+///     }).await;
+/// });
+/// ```
+pub fn time_sliced<It, O>(budget: Duration) -> ActionSeed<It, Option<O>>
+where
+    It: IntoIterator<Item=Option<O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |steps: It, output| {
+        TimeSlicedRunner {
+            steps: steps.into_iter(),
+            budget,
+            output,
+        }
+    })
+}
+
+struct TimeSlicedRunner<It, O> {
+    steps: It,
+    budget: Duration,
+    output: Output<Option<O>>,
+}
+
+impl<It, O> Runner for TimeSlicedRunner<It, O>
+where
+    It: Iterator<Item=Option<O>>,
+    O: 'static,
+{
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        let start = Instant::now();
+        loop {
+            match self.steps.next() {
+                Some(Some(out)) => {
+                    self.output.set(Some(out));
+                    return RunnerIs::Completed;
+                }
+                Some(None) => {
+                    if self.budget <= start.elapsed() {
+                        return RunnerIs::Running;
+                    }
+                }
+                None => {
+                    self.output.set(None);
+                    return RunnerIs::Completed;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{effect, once};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::prelude::{Commands, In, ResMut, Startup, Update};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    fn steps() -> impl Iterator<Item=Option<usize>> {
+        (0..).map(|i| (i == 3).then_some(i))
+    }
+
+    #[test]
+    fn one_step_per_frame_if_budget_is_zero() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(steps)
+                        .pipe(effect::algorithm::time_sliced(Duration::ZERO))
+                        .pipe(once::run(|In(num): In<Option<usize>>, mut count: ResMut<Count>| {
+                            count.0 = num.unwrap();
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+            app.assert_resource_eq(Count(0));
+        }
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn complete_in_one_frame_if_budget_is_enough() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(steps)
+                        .pipe(effect::algorithm::time_sliced(Duration::from_secs(60)))
+                        .pipe(once::run(|In(num): In<Option<usize>>, mut count: ResMut<Count>| {
+                            count.0 = num.unwrap();
+                        }))
+                }).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn output_none_if_iterator_ends() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let out = task.will(Update, {
+                    effect::algorithm::time_sliced::<_, usize>(Duration::from_secs(60)).with(vec![None, None])
+                }).await;
+                assert!(out.is_none());
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
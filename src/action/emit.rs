@@ -0,0 +1,95 @@
+use crate::action::once;
+use crate::prelude::Pipe;
+use bevy::prelude::{Event, EventWriter, In};
+
+/// Sends the output of the action as an event when the action is completed.
+///
+/// This allows normal systems to react to the milestones of the reactor
+/// without sharing resources.
+pub trait EmitAs<I, O, A> {
+    /// Sends `E::from(output)` via [`EventWriter`] when the action is completed,
+    /// and outputs the original output.
+    ///
+    /// The event is sent in the same frame as the action is completed.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Event)]
+    /// struct BossDefeated(usize);
+    ///
+    /// impl From<usize> for BossDefeated {
+    ///     fn from(score: usize) -> Self {
+    ///         Self(score)
+    ///     }
+    /// }
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, once::run(|| 100).emit_as::<BossDefeated>()).await;
+    /// });
+    /// ```
+    fn emit_as<E>(self) -> A
+    where
+        E: Event + From<O>;
+}
+
+impl<I, O, A, ActionOrSeed> EmitAs<I, O, A> for ActionOrSeed
+where
+    ActionOrSeed: Pipe<I, O, O, A>,
+    O: Clone + 'static,
+{
+    #[inline]
+    fn emit_as<E>(self) -> A
+    where
+        E: Event + From<O>,
+    {
+        self.pipe(once::run(|In(output): In<O>, mut ew: EventWriter<E>| {
+            ew.send(E::from(output.clone()));
+            output
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{EmitAs, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Event, Events, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Event, Debug, Eq, PartialEq)]
+    struct Milestone(usize);
+
+    impl From<usize> for Milestone {
+        fn from(value: usize) -> Self {
+            Self(value)
+        }
+    }
+
+    #[test]
+    fn emit_output_as_event() {
+        let mut app = test_app();
+        app.add_event::<Milestone>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let output = task.will(Update, once::run(|| 3).emit_as::<Milestone>()).await;
+                task.will(Update, once::run(|In(output): In<usize>, mut count: ResMut<Count>| {
+                    count.0 = output;
+                }).with(output)).await;
+            }));
+        });
+        app.update();
+        let mut cursor = app.world().resource::<Events<Milestone>>().get_cursor();
+        let events = cursor.read(app.world().resource::<Events<Milestone>>()).collect::<Vec<_>>();
+        assert_eq!(events, vec![&Milestone(3)]);
+
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+}
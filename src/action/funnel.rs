@@ -0,0 +1,107 @@
+//! [`funnel`] provides the actions that record the steps of the flow to [`Analytics`](crate::prelude::Analytics).
+//!
+//! actions
+//!
+//! - [`step`]
+
+use crate::action::once::analytics;
+use crate::action::seed::ActionSeed;
+use crate::prelude::{Action, AnalyticsEvent, CancellationHandlers};
+use crate::runner::{BoxedRunner, Runner, RunnerIs};
+use bevy::prelude::World;
+use std::borrow::Cow;
+
+/// The property of the events recorded by [`step`], whose value is `enter` or `leave`.
+pub const PHASE: &str = "phase";
+
+/// Runs the action as the step `name` of the funnel.
+///
+/// When the action starts, the event named `name` with [`PHASE`] `enter` is recorded,
+/// and when it completes, the same event with [`PHASE`] `leave` is recorded,
+/// so the analytics backend can see at which step the players dropped out.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, funnel::step("tutorial_move", wait::input::just_pressed().with(KeyCode::KeyW))).await;
+///     task.will(Update, funnel::step("tutorial_jump", wait::input::just_pressed().with(KeyCode::Space))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn step<I, O>(
+    name: impl Into<Cow<'static, str>>,
+    action: impl Into<Action<I, O>> + Send + Sync + 'static,
+) -> ActionSeed<(), O>
+where
+    I: 'static,
+    O: 'static,
+{
+    let name = name.into();
+    ActionSeed::new(move |_, output| StepRunner {
+        name,
+        entered: false,
+        inner: action.into().create_runner(output),
+    })
+}
+
+struct StepRunner {
+    name: Cow<'static, str>,
+    entered: bool,
+    inner: BoxedRunner,
+}
+
+impl StepRunner {
+    fn record(&self, world: &World, phase: &'static str) {
+        analytics::record(world, || AnalyticsEvent::new(self.name.clone()).with_prop(PHASE, phase));
+    }
+}
+
+impl Runner for StepRunner {
+    fn run(&mut self, world: &mut World, cancellation_handlers: &mut CancellationHandlers) -> RunnerIs {
+        if !self.entered {
+            self.entered = true;
+            self.record(world, "enter");
+        }
+        let status = self.inner.run(world, cancellation_handlers);
+        if status.is_completed() {
+            self.record(world, "leave");
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::funnel::PHASE;
+    use crate::action::once::analytics::tests::record_events;
+    use crate::action::{delay, funnel};
+    use crate::prelude::{AnalyticsEvent, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::Update;
+
+    #[test]
+    fn record_enter_and_leave() {
+        let mut app = test_app();
+        let events = record_events(&mut app);
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, funnel::step("tutorial", delay::frames().with(2))).await;
+        }));
+        app.update();
+        app.update();
+        assert_eq!(*events.lock().unwrap(), vec![
+            AnalyticsEvent::new("tutorial").with_prop(PHASE, "enter"),
+        ]);
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(*events.lock().unwrap(), vec![
+            AnalyticsEvent::new("tutorial").with_prop(PHASE, "enter"),
+            AnalyticsEvent::new("tutorial").with_prop(PHASE, "leave"),
+        ]);
+    }
+}
@@ -0,0 +1,48 @@
+//! Provides the actions that mark the time on the reactor.
+//!
+//! The marked time can be waited for with [`wait::mark::elapsed`](crate::prelude::wait::mark::elapsed),
+//! so flows can branch on how long ago an earlier step happened.
+//!
+//! actions
+//!
+//! - [`mark::set`](crate::prelude::mark::set)
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use crate::runner::CurrentReactor;
+use bevy::prelude::{Commands, Component, Res, Time};
+use bevy::utils::HashMap;
+use std::borrow::Cow;
+use std::time::Duration;
+
+/// The times marked on the reactor entity.
+#[derive(Component, Default)]
+pub(crate) struct Marks(pub(crate) HashMap<Cow<'static, str>, Duration>);
+
+/// Marks the current time with `name` on the reactor that runs this action.
+///
+/// The time is measured by [`Time`], and the mark with the same name is overwritten.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, mark::set("combat_start")).await;
+///     task.will(Update, wait::mark::elapsed("combat_start", Duration::from_secs(30))).await;
+/// });
+/// ```
+#[inline]
+pub fn set(name: impl Into<Cow<'static, str>>) -> ActionSeed {
+    let name = name.into();
+    once::run(move |mut commands: Commands, reactor: Res<CurrentReactor>, time: Res<Time>| {
+        let name = name.clone();
+        let now = time.elapsed();
+        commands.entity(reactor.0).entry::<Marks>().or_default().and_modify(move |mut marks| {
+            marks.0.insert(name, now);
+        });
+    })
+}
@@ -0,0 +1,194 @@
+//! `net` provides actions to measure the quality of network connections.
+//!
+//! actions
+//!
+//! - [`net::ping`](crate::prelude::net::ping)
+
+use crate::action::Action;
+use crate::prelude::{ActionSeed, CancellationHandlers, Runner, RunnerIs};
+use crate::runner::{BoxedRunner, Output};
+use bevy::prelude::World;
+use bevy::utils::Instant;
+use std::time::Duration;
+
+/// The round-trip time statistics measured by [`ping`].
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct PingStats {
+    /// The round-trip times of each sample in the order they were measured.
+    pub samples: Vec<Duration>,
+    /// The minimum round-trip time.
+    pub min: Duration,
+    /// The maximum round-trip time.
+    pub max: Duration,
+    /// The average round-trip time.
+    pub average: Duration,
+}
+
+impl PingStats {
+    fn new(samples: Vec<Duration>) -> Self {
+        let min = samples.iter().min().copied().unwrap_or_default();
+        let max = samples.iter().max().copied().unwrap_or_default();
+        let average = if samples.is_empty() {
+            Duration::ZERO
+        } else {
+            samples.iter().sum::<Duration>() / samples.len() as u32
+        };
+        Self {
+            samples,
+            min,
+            max,
+            average,
+        }
+    }
+}
+
+/// Measures the round-trip times of the action created by `probe`.
+///
+/// The input value is the number of samples.
+/// The probes are run one after another, and each one should send a request and then wait for its response.
+/// The round-trip time is measured in wall-clock time from when the probe is started until it is completed.
+///
+/// To give up on unresponsive connections, combine it with [`Timeout`](crate::prelude::Timeout).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Ping;
+///
+/// #[derive(Event, Clone)]
+/// struct Pong;
+///
+/// Reactor::schedule(|task| async move{
+///     let stats = task.will(Update, {
+///         net::ping(|| {
+///             once::event::send().with(Ping)
+///                 .then(wait::event::comes::<Pong>())
+///         })
+///             .with(5)
+///             .timeout(Duration::from_secs(10))
+///     }).await;
+///     if stats.is_none_or(|stats| Duration::from_millis(150) < stats.average) {
+///         info!("connection is too slow");
+///     }
+/// });
+/// ```
+pub fn ping<F, A, I, O>(probe: F) -> ActionSeed<usize, PingStats>
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>> + 'static,
+    I: 'static,
+    O: 'static,
+{
+    ActionSeed::new(|samples, output| PingRunner {
+        probe: move || probe().into().create_runner(Output::default()),
+        remaining: samples,
+        current: None,
+        samples: Vec::with_capacity(samples),
+        output,
+    })
+}
+
+struct PingRunner<F> {
+    probe: F,
+    remaining: usize,
+    current: Option<(BoxedRunner, Instant)>,
+    samples: Vec<Duration>,
+    output: Output<PingStats>,
+}
+
+impl<F> Runner for PingRunner<F>
+where
+    F: Fn() -> BoxedRunner,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        loop {
+            if self.current.is_none() {
+                if self.remaining == 0 {
+                    self.output.set(PingStats::new(std::mem::take(&mut self.samples)));
+                    return RunnerIs::Completed;
+                }
+                self.remaining -= 1;
+                let runner = (self.probe)();
+                self.current.replace((runner, Instant::now()));
+            }
+            let Some((runner, start)) = self.current.as_mut() else {
+                continue;
+            };
+            match runner.run(world, token) {
+                RunnerIs::Completed => {
+                    self.samples.push(start.elapsed());
+                    self.current = None;
+                }
+                RunnerIs::Running => return RunnerIs::Running,
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, net, once};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Resource, Default, Debug, Eq, PartialEq)]
+    struct Stats(Option<net::PingStats>);
+
+    #[test]
+    fn measure_all_samples() {
+        let mut app = test_app();
+        app.init_resource::<Stats>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    net::ping(|| delay::frames().with(1))
+                        .with(3)
+                        .pipe(once::run(|In(stats): In<net::PingStats>, mut res: ResMut<Stats>| {
+                            res.0 = Some(stats);
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        let stats = app.resource::<Stats>().0.clone().unwrap();
+        assert_eq!(stats.samples.len(), 3);
+        assert!(stats.min <= stats.average);
+        assert!(stats.average <= stats.max);
+    }
+
+    #[test]
+    fn empty_stats_if_no_samples() {
+        let mut app = test_app();
+        app.init_resource::<Stats>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    net::ping(|| once::run(|| {}))
+                        .with(0)
+                        .pipe(once::run(|In(stats): In<net::PingStats>, mut res: ResMut<Stats>| {
+                            res.0 = Some(stats);
+                        }))
+                }).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Stats(Some(net::PingStats {
+            samples: Vec::new(),
+            min: Duration::ZERO,
+            max: Duration::ZERO,
+            average: Duration::ZERO,
+        })));
+    }
+}
@@ -2,19 +2,47 @@
 
 use crate::action::seed::ActionSeed;
 use crate::prelude::RunnerIs;
-use crate::runner::{CancellationHandlers, Output, Runner};
+use crate::runner::{system_access, CancellationHandlers, Output, Runner};
 pub use _no_op::{no_op, no_op_with_generics};
-use bevy::prelude::{IntoSystem, System, SystemIn, SystemInput, World};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::ecs::world::CommandQueue;
+use bevy::prelude::{Commands, IntoSystem, System, SystemIn, SystemInput, World};
 
+#[cfg(feature = "analytics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "analytics")))]
+pub mod analytics;
+pub mod entity;
 pub mod event;
+#[cfg(feature = "input")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+pub mod input;
+#[cfg(feature = "locale")]
+#[cfg_attr(docsrs, doc(cfg(feature = "locale")))]
+pub mod locale;
 pub mod non_send;
 pub mod res;
+#[cfg(feature = "rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rng")))]
+pub mod rng;
 pub mod switch;
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+pub mod ui;
 #[path = "once/no_op.rs"]
 mod _no_op;
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod asset;
 #[cfg(feature = "audio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
 pub mod audio;
+#[cfg(feature = "gizmos")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gizmos")))]
+pub mod debug;
+#[cfg(feature = "scene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scene")))]
+pub mod scene;
 #[cfg(feature = "state")]
 #[cfg_attr(docsrs, doc(cfg(feature = "state")))]
 pub mod state;
@@ -50,6 +78,87 @@ where
     })
 }
 
+/// Once run the function with [`Commands`].
+///
+/// Unlike [`once::run`](crate::prelude::once::run), the function is [`FnOnce`], so it can consume the captured values.
+/// The commands are applied before this action is completed,
+/// so the structural changes such as spawned hierarchies are visible to the next action.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let name = Name::new("player");
+///     let player: Entity = task.will(Update, once::commands(move |mut commands: Commands|{
+///         commands.spawn(name).with_children(|parent|{
+///             parent.spawn(Transform::default());
+///         }).id()
+///     })).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn commands<F, O>(f: F) -> ActionSeed<(), O>
+where
+    F: FnOnce(Commands) -> O + Send + Sync + 'static,
+    O: 'static,
+{
+    world(move |world: &mut World| {
+        let mut queue = CommandQueue::default();
+        let output = f(Commands::new(&mut queue, world));
+        queue.apply(world);
+        output
+    })
+}
+
+/// Once run the function with the exclusive access to [`World`].
+///
+/// Unlike [`once::run`](crate::prelude::once::run) with an exclusive system, the function is [`FnOnce`],
+/// so it can consume the captured values.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entities: usize = task.will(Update, once::world(|world: &mut World|{
+///         world.entities().len() as usize
+///     })).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn world<F, O>(f: F) -> ActionSeed<(), O>
+where
+    F: FnOnce(&mut World) -> O + Send + Sync + 'static,
+    O: 'static,
+{
+    ActionSeed::new(move |_, output| OnceWorldRunner {
+        f: Some(f),
+        output,
+    })
+}
+
+struct OnceWorldRunner<F, O> {
+    f: Option<F>,
+    output: Output<O>,
+}
+
+impl<F, O> Runner for OnceWorldRunner<F, O>
+where
+    F: FnOnce(&mut World) -> O,
+{
+    fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        if let Some(f) = self.f.take() {
+            self.output.set(f(world));
+        }
+        RunnerIs::Completed
+    }
+}
+
 struct OnceRunner<Sys>
 where
     Sys: System,
@@ -74,4 +183,48 @@ where
         self.output.set(out);
         RunnerIs::Completed
     }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        Some(system_access(&self.system))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::Reactor;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::hierarchy::{BuildChildren, ChildBuild, Children};
+    use bevy::prelude::{Commands, In, ResMut, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn commands_applied_before_next_action() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let children = vec![1, 2];
+                let parent = task.will(Update, once::commands(move |mut commands: Commands| {
+                    commands.spawn_empty().with_children(|parent| {
+                        for _ in children {
+                            parent.spawn_empty();
+                        }
+                    }).id()
+                })).await;
+                let len = task.will(Update, once::world(move |world: &mut World| {
+                    world.get::<Children>(parent).map(|children| children.len())
+                })).await;
+                task.will(Update, once::run(|In(len): In<Option<usize>>, mut count: ResMut<Count>| {
+                    count.0 = len.unwrap_or_default();
+                }).with(len)).await;
+            }));
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
 }
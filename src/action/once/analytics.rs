@@ -0,0 +1,181 @@
+//! [`once::analytics`] creates a task that only once run system related to [`Analytics`].
+//!
+//! The events are passed to [`AnalyticsSink`], so the scripted flows can report to any analytics backend
+//! without depending on it.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::prelude::{In, Res, Resource, World};
+use std::borrow::Cow;
+
+/// Receives the analytics events recorded by the actions.
+///
+/// This is implemented for closures that take [`AnalyticsEvent`].
+pub trait AnalyticsSink: Send + Sync + 'static {
+    /// Records the event.
+    fn record(&self, event: &AnalyticsEvent);
+}
+
+impl<F> AnalyticsSink for F
+where
+    F: Fn(&AnalyticsEvent) + Send + Sync + 'static,
+{
+    #[inline]
+    fn record(&self, event: &AnalyticsEvent) {
+        self(event);
+    }
+}
+
+/// The event recorded by [`once::analytics::event`](crate::prelude::once::analytics::event)
+/// and [`funnel::step`](crate::prelude::funnel::step).
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct AnalyticsEvent {
+    /// The name of the event.
+    pub name: Cow<'static, str>,
+    /// The properties of the event in the order they were added.
+    pub props: Vec<(Cow<'static, str>, String)>,
+}
+
+impl AnalyticsEvent {
+    /// Creates a new event without properties.
+    #[inline]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            props: Vec::new(),
+        }
+    }
+
+    /// Adds the property.
+    #[inline]
+    pub fn with_prop(mut self, key: impl Into<Cow<'static, str>>, value: impl ToString) -> Self {
+        self.props.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Returns the value of the property `key` if it exists.
+    #[inline]
+    pub fn prop(&self, key: &str) -> Option<&str> {
+        self
+            .props
+            .iter()
+            .find_map(|(k, v)| (k == key).then_some(v.as_str()))
+    }
+}
+
+impl From<&'static str> for AnalyticsEvent {
+    #[inline]
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for AnalyticsEvent {
+    #[inline]
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+/// The resource that holds the current [`AnalyticsSink`].
+///
+/// If this resource does not exist, the events are discarded.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin))
+///     .insert_resource(Analytics::new(|event: &AnalyticsEvent| {
+///         info!("analytics: {} {:?}", event.name, event.props);
+///     }));
+/// ```
+#[derive(Resource)]
+pub struct Analytics(Box<dyn AnalyticsSink>);
+
+impl Analytics {
+    /// Creates a new [`Analytics`].
+    #[inline]
+    pub fn new(sink: impl AnalyticsSink) -> Self {
+        Self(Box::new(sink))
+    }
+
+    /// Records the event to the sink.
+    #[inline]
+    pub fn record(&self, event: &AnalyticsEvent) {
+        self.0.record(event);
+    }
+}
+
+/// Records the passed event to [`Analytics`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::analytics::event().with(
+///         AnalyticsEvent::new("tutorial_step_3").with_prop("skipped", false)
+///     )).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn event<E>() -> ActionSeed<E>
+where
+    E: Into<AnalyticsEvent> + 'static,
+{
+    once::run(|In(event): In<E>, analytics: Option<Res<Analytics>>| {
+        if let Some(analytics) = analytics {
+            analytics.record(&event.into());
+        }
+    })
+}
+
+pub(crate) fn record(world: &World, event: impl FnOnce() -> AnalyticsEvent) {
+    if let Some(analytics) = world.get_resource::<Analytics>() {
+        analytics.record(&event());
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::action::once;
+    use crate::prelude::{Analytics, AnalyticsEvent, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::Commands;
+    use std::sync::{Arc, Mutex};
+
+    pub(crate) fn record_events(app: &mut App) -> Arc<Mutex<Vec<AnalyticsEvent>>> {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        app.insert_resource(Analytics::new(move |event: &AnalyticsEvent| {
+            recorded.lock().unwrap().push(event.clone());
+        }));
+        events
+    }
+
+    #[test]
+    fn record_events_in_order() {
+        let mut app = test_app();
+        let events = record_events(&mut app);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::analytics::event().with("tutorial_started")).await;
+                task.will(Update, once::analytics::event().with(AnalyticsEvent::new("tutorial_step_3").with_prop("skipped", false))).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(*events.lock().unwrap(), vec![
+            AnalyticsEvent::new("tutorial_started"),
+            AnalyticsEvent::new("tutorial_step_3").with_prop("skipped", false),
+        ]);
+    }
+}
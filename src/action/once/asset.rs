@@ -0,0 +1,32 @@
+//! [`once::asset`] creates a task that only once run system related to [`Asset`].
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::asset::{Asset, AssetPath, AssetServer, Handle};
+use bevy::prelude::{In, Res};
+
+/// Starts loading the asset at the passed path.
+///
+/// The output value is [`Handle`] of the asset.
+/// To wait until the asset has been loaded, pipe it into [`wait::asset::loaded`](crate::prelude::wait::asset::loaded).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let handle: Handle<Image> = task.will(Update, once::asset::load().with("texture.png")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn load<A, Path>() -> ActionSeed<Path, Handle<A>>
+where
+    A: Asset,
+    Path: Into<AssetPath<'static>> + 'static,
+{
+    once::run(|In(path): In<Path>, asset_server: Res<AssetServer>| {
+        asset_server.load(path.into())
+    })
+}
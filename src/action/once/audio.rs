@@ -6,11 +6,11 @@ use bevy::prelude::{Commands, Entity, In, Res};
 use crate::action::once;
 use crate::prelude::ActionSeed;
 
-/// Spawns [`AudioBundle`].
+/// Spawns [`AudioPlayer`] that plays the audio at the passed path.
 ///
-/// The output value is [`Entity`] that [`AudioBundle`] is attached to.
+/// The output value is [`Entity`] that [`AudioPlayer`] is attached to.
 ///
-/// [`AudioBundle`]: bevy::audio::AudioBundle
+/// To wait until the audio has finished playing, pipe it into [`wait::audio::finished`](crate::prelude::wait::audio::finished).
 ///
 /// ## Examples
 ///
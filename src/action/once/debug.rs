@@ -0,0 +1,61 @@
+//! [`once::debug`] creates a task that only once run system related to the debug rendering.
+//!
+//! These actions allow a debug-console reactor to orchestrate the diagnostic modes.
+//! The debug renderings of physics engines, such as avian's `PhysicsGizmos`, are usually provided as [`GizmoConfigGroup`]s,
+//! so they can be toggled with [`once::debug::set_gizmo_group_enabled`](crate::prelude::once::debug::set_gizmo_group_enabled).
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::gizmos::config::{GizmoConfigGroup, GizmoConfigStore};
+use bevy::prelude::{In, ResMut};
+use std::any::TypeId;
+
+/// Enables or disables all registered gizmo config groups.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::debug::set_gizmos_enabled().with(false)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn set_gizmos_enabled() -> ActionSeed<bool> {
+    once::run(|In(enabled): In<bool>, store: Option<ResMut<GizmoConfigStore>>| {
+        let Some(mut store) = store else {
+            return;
+        };
+        for (_, config, _) in store.iter_mut() {
+            config.enabled = enabled;
+        }
+    })
+}
+
+/// Enables or disables the gizmo config group `G`.
+///
+/// If `G` has not been registered, it does nothing.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::debug::set_gizmo_group_enabled::<DefaultGizmoConfigGroup>().with(true)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn set_gizmo_group_enabled<G>() -> ActionSeed<bool>
+where
+    G: GizmoConfigGroup,
+{
+    once::run(|In(enabled): In<bool>, store: Option<ResMut<GizmoConfigStore>>| {
+        if let Some((config, _)) = store.and_then(|store| store.into_inner().get_config_mut_dyn(&TypeId::of::<G>())) {
+            config.enabled = enabled;
+        }
+    })
+}
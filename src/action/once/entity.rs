@@ -0,0 +1,271 @@
+//! [`once::entity`] creates a task that only once run system related to [`Entity`].
+
+use bevy::hierarchy::{Children, DespawnRecursiveExt};
+use bevy::prelude::{Bundle, Commands, Component, Entity, In, Query, With};
+use crate::action::once;
+use crate::prelude::ActionSeed;
+
+/// Inserts the bundle into the passed [`Entity`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, once::entity::insert().with((entity, Name::new("player")))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn insert<B>() -> ActionSeed<(Entity, B)>
+where
+    B: Bundle,
+{
+    once::run(|In((entity, bundle)): In<(Entity, B)>, mut commands: Commands| {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.insert(bundle);
+        }
+    })
+}
+
+/// Removes the bundle `B` from the passed [`Entity`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Name::new("player")).id()
+///     })).await;
+///     task.will(Update, once::entity::remove::<Name>().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn remove<B>() -> ActionSeed<Entity>
+where
+    B: Bundle,
+{
+    once::run(|In(entity): In<Entity>, mut commands: Commands| {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.remove::<B>();
+        }
+    })
+}
+
+/// Despawns the passed [`Entity`] and its descendants.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, once::entity::despawn().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn() -> ActionSeed<Entity> {
+    once::run(|In(entity): In<Entity>, mut commands: Commands| {
+        if let Some(entity_commands) = commands.get_entity(entity) {
+            entity_commands.despawn_recursive();
+        }
+    })
+}
+
+/// Outputs a clone of the component `C` of the passed [`Entity`].
+///
+/// If the entity or the component does not exist, it outputs `None`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Name::new("player")).id()
+///     })).await;
+///     let name: Option<Name> = task.will(Update, once::entity::get::<Name>().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn get<C>() -> ActionSeed<Entity, Option<C>>
+where
+    C: Component + Clone,
+{
+    once::run(|In(entity): In<Entity>, components: Query<&C>| {
+        components.get(entity).ok().cloned()
+    })
+}
+
+/// Despawns all descendants of the passed [`Entity`].
+///
+/// The entity itself is kept.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let level = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, once::entity::despawn_descendants().with(level)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn_descendants() -> ActionSeed<Entity> {
+    once::run(|In(entity): In<Entity>, mut commands: Commands| {
+        if let Some(mut entity_commands) = commands.get_entity(entity) {
+            entity_commands.despawn_descendants();
+        }
+    })
+}
+
+/// Despawns the children of the passed [`Entity`] recursively,
+/// except for the children that have the component `M`.
+///
+/// The entity itself and the kept children (including their descendants) are not despawned.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Anchor;
+///
+/// Reactor::schedule(|task| async move{
+///     let level = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, once::entity::despawn_descendants_except::<Anchor>().with(level)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn_descendants_except<M>() -> ActionSeed<Entity>
+where
+    M: Component,
+{
+    once::run(|In(entity): In<Entity>,
+               mut commands: Commands,
+               children: Query<&Children>,
+               keep: Query<(), With<M>>| {
+        let Ok(children) = children.get(entity) else {
+            return;
+        };
+        for child in children.iter().filter(|child| !keep.contains(**child)) {
+            commands.entity(*child).despawn_recursive();
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::Reactor;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::hierarchy::{BuildChildren, ChildBuild, Children};
+    use bevy::prelude::{Commands, Component, Entity, With};
+
+    #[derive(Component, Clone, Debug, Eq, PartialEq)]
+    struct Root;
+
+    #[derive(Component)]
+    struct Anchor;
+
+    #[derive(Component)]
+    struct Leaf;
+
+    fn spawn_level(mut commands: Commands) -> Entity {
+        commands
+            .spawn(Root)
+            .with_children(|parent| {
+                parent.spawn(Leaf).with_children(|parent| {
+                    parent.spawn(Leaf);
+                });
+                parent.spawn(Anchor).with_children(|parent| {
+                    parent.spawn(Leaf);
+                });
+            })
+            .id()
+    }
+
+    #[test]
+    fn insert_get_and_remove() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let entity = task.will(Update, once::run(|mut commands: Commands| {
+                    commands.spawn(Leaf).id()
+                })).await;
+                task.will(Update, once::entity::insert().with((entity, Root))).await;
+                let root = task.will(Update, once::entity::get::<Root>().with(entity)).await;
+                assert_eq!(root, Some(Root));
+                task.will(Update, once::entity::remove::<Root>().with(entity)).await;
+                let root = task.will(Update, once::entity::get::<Root>().with(entity)).await;
+                assert_eq!(root, None);
+                task.will(Update, once::entity::despawn().with(entity)).await;
+            }));
+        });
+        for _ in 0..7 {
+            app.update();
+        }
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<Entity, With<Leaf>>().iter(world).len(), 0);
+    }
+
+    #[test]
+    fn despawn_all_descendants() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let root = task.will(Update, once::run(spawn_level)).await;
+                task.will(Update, once::entity::despawn_descendants().with(root)).await;
+            }));
+        });
+        app.update();
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<Entity, With<Root>>().iter(world).len(), 1);
+        assert_eq!(world.query_filtered::<Entity, With<Anchor>>().iter(world).len(), 0);
+        assert_eq!(world.query_filtered::<Entity, With<Leaf>>().iter(world).len(), 0);
+    }
+
+    #[test]
+    fn keep_children_with_marker() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let root = task.will(Update, once::run(spawn_level)).await;
+                task.will(Update, once::entity::despawn_descendants_except::<Anchor>().with(root)).await;
+            }));
+        });
+        app.update();
+        app.update();
+
+        let world = app.world_mut();
+        let root = world.query_filtered::<&Children, With<Root>>().single(world);
+        assert_eq!(root.len(), 1);
+        let anchor = world.query_filtered::<&Children, With<Anchor>>().single(world);
+        assert_eq!(anchor.len(), 1);
+        assert_eq!(world.query_filtered::<Entity, With<Leaf>>().iter(world).len(), 1);
+    }
+}
@@ -0,0 +1,351 @@
+//! [`once::input`] creates a task that only once run system related to [`ButtonInput`].
+//!
+//! These actions are useful for the flows such as cutscenes,
+//! which neutralize the buffered input on entry and restore the held buttons on exit to avoid ghost inputs.
+//! [`once::input::block`] and [`once::input::unblock`] gate the input systems of the game via [`InputGuard`].
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use crate::reactor::NativeReactor;
+use crate::runner::CurrentReactor;
+use bevy::input::ButtonInput;
+use bevy::prelude::{Entity, Gamepad, In, KeyCode, MouseButton, OnRemove, Query, Res, ResMut, Resource, Trigger};
+use bevy::utils::HashSet;
+use std::hash::Hash;
+
+/// The resource that holds the reactors blocking the input via [`once::input::block`](crate::prelude::once::input::block).
+///
+/// The input is blocked while any reactor blocks it.
+/// The block is released automatically when the reactor is completed or cancelled,
+/// so the input does not remain blocked even if the reactor is despawned before [`once::input::unblock`](crate::prelude::once::input::unblock).
+///
+/// Use [`input_unblocked`] as the run condition of the systems that handle the input.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct InputGuard {
+    blockers: HashSet<Entity>,
+}
+
+impl InputGuard {
+    /// Returns true if any reactor blocks the input.
+    #[inline]
+    pub fn is_blocked(&self) -> bool {
+        !self.blockers.is_empty()
+    }
+}
+
+/// The run condition that returns true if no reactor blocks the input.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn jump(){}
+///
+/// App::new()
+///     .add_plugins(FlurxPlugin)
+///     .add_systems(Update, jump.run_if(input_unblocked))
+///     .add_systems(Startup, |mut commands: Commands|{
+///         commands.spawn(Reactor::schedule(|task| async move{
+///             task.will(Update, once::input::block()).await;
+///             task.will(Update, delay::frames().with(60)).await;
+///             task.will(Update, once::input::unblock()).await;
+///         }));
+///     });
+/// ```
+#[inline]
+pub fn input_unblocked(guard: Option<Res<InputGuard>>) -> bool {
+    guard.is_none_or(|guard| !guard.is_blocked())
+}
+
+/// Blocks the input until [`once::input::unblock`](crate::prelude::once::input::unblock) is called by the same reactor,
+/// or the reactor is completed or cancelled.
+///
+/// See [`InputGuard`] for details.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::input::block()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn block() -> ActionSeed {
+    once::run(|reactor: Res<CurrentReactor>, mut guard: ResMut<InputGuard>| {
+        guard.blockers.insert(reactor.0);
+    })
+}
+
+/// Releases the block of the input by [`once::input::block`](crate::prelude::once::input::block).
+///
+/// The input remains blocked if other reactors still block it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::input::unblock()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn unblock() -> ActionSeed {
+    once::run(|reactor: Res<CurrentReactor>, mut guard: ResMut<InputGuard>| {
+        guard.blockers.remove(&reactor.0);
+    })
+}
+
+pub(crate) fn release_removed_reactor(
+    trigger: Trigger<OnRemove, NativeReactor>,
+    guard: Option<ResMut<InputGuard>>,
+) {
+    if let Some(mut guard) = guard {
+        if guard.blockers.contains(&trigger.entity()) {
+            guard.blockers.remove(&trigger.entity());
+        }
+    }
+}
+
+/// The snapshot of the pressed buttons captured by [`once::input::snapshot`](crate::prelude::once::input::snapshot).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputSnapshot<T> {
+    pressed: Vec<T>,
+}
+
+impl<T> InputSnapshot<T> {
+    /// Returns the buttons that were pressed when the snapshot was captured.
+    #[inline(always)]
+    pub fn pressed(&self) -> &[T] {
+        &self.pressed
+    }
+}
+
+/// Releases all buttons of [`ButtonInput<T>`] and clears their just pressed and just released states.
+///
+/// The buttons held physically are treated as released until they are pressed again.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::input::clear::<KeyCode>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn clear<T>() -> ActionSeed
+where
+    T: Copy + Eq + Hash + Send + Sync + 'static,
+{
+    once::run(|input: Option<ResMut<ButtonInput<T>>>| {
+        if let Some(mut input) = input {
+            input.reset_all();
+        }
+    })
+}
+
+/// Releases all buttons of [`ButtonInput<KeyCode>`], [`ButtonInput<MouseButton>`] and all [`Gamepad`]s.
+///
+/// See [`once::input::clear`](crate::prelude::once::input::clear) for details.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::input::clear_all()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn clear_all() -> ActionSeed {
+    once::run(|key: Option<ResMut<ButtonInput<KeyCode>>>,
+               mouse: Option<ResMut<ButtonInput<MouseButton>>>,
+               gamepads: Query<&mut Gamepad>| {
+        if let Some(mut key) = key {
+            key.reset_all();
+        }
+        if let Some(mut mouse) = mouse {
+            mouse.reset_all();
+        }
+        reset_gamepads(gamepads);
+    })
+}
+
+/// Releases all buttons of all [`Gamepad`]s.
+///
+/// See [`once::input::clear`](crate::prelude::once::input::clear) for details.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::input::clear_gamepads()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn clear_gamepads() -> ActionSeed {
+    once::run(reset_gamepads)
+}
+
+fn reset_gamepads(mut gamepads: Query<&mut Gamepad>) {
+    for mut gamepad in gamepads.iter_mut() {
+        gamepad.digital_mut().reset_all();
+    }
+}
+
+/// Captures the pressed buttons of [`ButtonInput<T>`].
+///
+/// The captured snapshot can be restored by [`once::input::restore`](crate::prelude::once::input::restore).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let snapshot = task.will(Update, once::input::snapshot::<KeyCode>()).await;
+///     task.will(Update, once::input::clear_all()).await;
+///     // play the cutscene
+///     task.will(Update, once::input::restore().with(snapshot)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn snapshot<T>() -> ActionSeed<(), InputSnapshot<T>>
+where
+    T: Copy + Eq + Hash + Send + Sync + 'static,
+{
+    once::run(|input: Option<Res<ButtonInput<T>>>| {
+        InputSnapshot {
+            pressed: input
+                .map(|input| input.get_pressed().copied().collect())
+                .unwrap_or_default(),
+        }
+    })
+}
+
+/// Presses the buttons captured by [`once::input::snapshot`](crate::prelude::once::input::snapshot).
+///
+/// The restored buttons are not treated as just pressed, so they don't trigger actions such as
+/// [`wait::input::just_pressed`](crate::prelude::wait::input::just_pressed).
+/// Note that a button released during the flow is restored as pressed until it is released again.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let snapshot = task.will(Update, once::input::snapshot::<KeyCode>()).await;
+///     task.will(Update, once::input::clear_all()).await;
+///     // play the cutscene
+///     task.will(Update, once::input::restore().with(snapshot)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn restore<T>() -> ActionSeed<InputSnapshot<T>>
+where
+    T: Copy + Eq + Hash + Send + Sync + 'static,
+{
+    once::run(|In(snapshot): In<InputSnapshot<T>>, input: Option<ResMut<ButtonInput<T>>>| {
+        let Some(mut input) = input else {
+            return;
+        };
+        for button in snapshot.pressed {
+            if !input.pressed(button) {
+                input.press(button);
+                input.clear_just_pressed(button);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{input_unblocked, Reactor};
+    use crate::reactor::NativeReactor;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::input::ButtonInput;
+    use bevy::prelude::{Commands, Entity, Gamepad, GamepadButton, IntoSystemConfigs, KeyCode, MouseButton, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn clear_all_buttons() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::input::clear_all()).await;
+            }));
+        });
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyA);
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.world_mut().get_mut::<Gamepad>(gamepad).unwrap().digital_mut().press(GamepadButton::South);
+        app.update();
+
+        assert!(!app.world().resource::<ButtonInput<KeyCode>>().pressed(KeyCode::KeyA));
+        assert!(!app.world().resource::<ButtonInput<MouseButton>>().pressed(MouseButton::Left));
+        assert!(!app.world().get::<Gamepad>(gamepad).unwrap().pressed(GamepadButton::South));
+    }
+
+    #[test]
+    fn restore_snapshot() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let snapshot = task.will(Update, once::input::snapshot::<KeyCode>()).await;
+                task.will(Update, once::input::clear::<KeyCode>()).await;
+                task.will(Update, once::input::restore().with(snapshot)).await;
+            }));
+        });
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyA);
+        app.update();
+        app.update();
+        assert!(!app.world().resource::<ButtonInput<KeyCode>>().pressed(KeyCode::KeyA));
+
+        app.update();
+        let input = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(input.pressed(KeyCode::KeyA));
+        assert!(!input.just_pressed(KeyCode::KeyA));
+    }
+
+    #[test]
+    fn unblock_if_reactor_cancelled() {
+        let mut app = test_app();
+        app.add_systems(Update, (|mut count: ResMut<Count>| count.increment()).run_if(input_unblocked));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::input::block()).await;
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        let blocked = app.world().resource::<Count>().0;
+        app.update();
+        app.assert_resource_eq(Count(blocked));
+
+        let reactor = app.world_mut().query_filtered::<Entity, With<NativeReactor>>().single(app.world());
+        app.world_mut().despawn(reactor);
+        app.update();
+        app.assert_resource_eq(Count(blocked + 1));
+    }
+}
@@ -0,0 +1,154 @@
+//! [`once::locale`] creates a task that only once run system related to [`Localization`].
+//!
+//! The flows written against string keys can work across languages without change,
+//! by resolving the keys with [`Localization`] when the actions are run.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::prelude::{In, Res, Resource};
+use bevy::utils::hashbrown::HashMap;
+use std::borrow::{Borrow, Cow};
+use std::hash::{BuildHasher, Hash};
+
+/// Resolves the string keys into the localized strings.
+pub trait Localize: Send + Sync + 'static {
+    /// Returns the localized string of the `key`, or `None` if it is not found.
+    fn localize(&self, key: &str) -> Option<Cow<'static, str>>;
+}
+
+impl<K, V, S> Localize for HashMap<K, V, S>
+where
+    K: Borrow<str> + Eq + Hash + Send + Sync + 'static,
+    V: AsRef<str> + Send + Sync + 'static,
+    S: BuildHasher + Send + Sync + 'static,
+{
+    #[inline]
+    fn localize(&self, key: &str) -> Option<Cow<'static, str>> {
+        self.get(key).map(|text| Cow::Owned(text.as_ref().to_string()))
+    }
+}
+
+/// The resource that holds the current [`Localize`].
+///
+/// The texts of [`wait::dialogue::say`](crate::prelude::wait::dialogue::say), [`once::ui::toast`](crate::prelude::once::ui::toast),
+/// [`Cutscene::dialog`](crate::prelude::Cutscene::dialog) and [`once::locale::text`](crate::prelude::once::locale::text)
+/// are resolved with it when the actions are run, so replacing this resource switches the language even in the middle of a flow.
+/// Other tables such as [`std::collections::HashMap`] can be collected into it with [`FromIterator`].
+/// If this resource does not exist or the key is not found, the key itself is used.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::utils::HashMap;
+/// use bevy_flurx::prelude::*;
+///
+/// let japanese = HashMap::from_iter([
+///     ("greeting".to_string(), "こんにちは".to_string()),
+/// ]);
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin))
+///     .insert_resource(Localization::new(japanese));
+/// ```
+#[derive(Resource)]
+pub struct Localization(Box<dyn Localize>);
+
+impl Localization {
+    /// Creates a new [`Localization`].
+    #[inline]
+    pub fn new(localize: impl Localize) -> Self {
+        Self(Box::new(localize))
+    }
+
+    /// Returns the localized string of the `key`, or the key itself if it is not found.
+    #[inline]
+    pub fn localize(&self, key: &str) -> Cow<'static, str> {
+        self.0.localize(key).unwrap_or_else(|| Cow::Owned(key.to_string()))
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Localization
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    #[inline]
+    fn from_iter<T: IntoIterator<Item=(K, V)>>(iter: T) -> Self {
+        Self::new(iter
+            .into_iter()
+            .map(|(key, text)| (key.into(), text.into()))
+            .collect::<bevy::utils::HashMap<String, String>>())
+    }
+}
+
+/// Resolves the passed key with [`Localization`], and outputs the localized string.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let greeting: String = task.will(Update, once::locale::text().with("greeting")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn text<K>() -> ActionSeed<K, String>
+where
+    K: Into<Cow<'static, str>> + 'static,
+{
+    once::run(|In(key): In<K>, localization: Option<Res<Localization>>| {
+        let key = key.into();
+        localize(localization.as_deref(), key).into_owned()
+    })
+}
+
+pub(crate) fn localize(localization: Option<&Localization>, key: Cow<'static, str>) -> Cow<'static, str> {
+    match localization {
+        Some(localization) => localization.localize(&key),
+        None => key,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Localization, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Resource};
+    use bevy::utils::HashMap;
+
+    #[derive(Resource, Debug, Eq, PartialEq)]
+    struct Texts(String, String);
+
+    #[test]
+    fn fallback_to_key_if_not_found() {
+        let mut app = test_app();
+        app.insert_resource(Localization::new(HashMap::from_iter([
+            ("greeting".to_string(), "hello".to_string()),
+        ])));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let greeting = task.will(Update, once::locale::text().with("greeting")).await;
+                let missing = task.will(Update, once::locale::text().with("missing")).await;
+                task.will(Update, once::res::insert().with(Texts(greeting, missing))).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Texts>(), &Texts("hello".to_string(), "missing".to_string()));
+    }
+
+    #[test]
+    fn localize_with_any_table() {
+        let borrowed = Localization::new(HashMap::from_iter([("greeting", "hello")]));
+        assert_eq!(borrowed.localize("greeting"), "hello");
+
+        let collected = Localization::from_iter(std::collections::HashMap::from([("greeting", "hello")]));
+        assert_eq!(collected.localize("greeting"), "hello");
+        assert_eq!(collected.localize("missing"), "missing");
+    }
+}
@@ -3,6 +3,16 @@
 use bevy::prelude::{Commands, In, Resource};
 use crate::action::once;
 use crate::action::seed::ActionSeed;
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+use bevy::prelude::{AppTypeRegistry, World};
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+use bevy::reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+use crate::runner::{BoxedRunner, CancellationHandlers, Output, Runner, RunnerIs};
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+use bevy::ecs::component::Tick;
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+use std::{io, path::Path};
 
 /// Once init a resource.
 ///
@@ -77,6 +87,291 @@ where
 }
 
 
+/// Serializes the resource into RON via [`Reflect`], and then writes it to the file at the input path.
+///
+/// The file is written on a worker thread, so the reactor is not blocked during IO.
+/// The output value is the result of the serialization and the file writing.
+///
+/// `R` is registered into [`AppTypeRegistry`] automatically if it has not been registered yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Reflect, Default)]
+/// struct Settings{
+///     volume: f32,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, once::res::save_to_file::<Settings, _>().with("settings.ron")).await;
+///     if let Err(e) = result {
+///         error!("failed to save settings: {e}");
+///     }
+/// });
+/// ```
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "persist")))]
+pub fn save_to_file<R, P>() -> ActionSeed<P, io::Result<()>>
+where
+    R: Resource + Reflect + TypePath + GetTypeRegistration,
+    P: AsRef<Path> + Send + Sync + 'static,
+{
+    use crate::prelude::{effect, Pipe};
+
+    ActionSeed::define(|path: P| {
+        once::run(serialize_resource::<R>)
+            .pipe(effect::thread::spawn(move |ron: io::Result<String>| {
+                std::fs::write(path, ron?)
+            }))
+    })
+}
+
+/// Reads RON from the file at the input path on a worker thread,
+/// and then deserializes it via [`Reflect`] and inserts it as the resource.
+///
+/// The output value is the result of the file reading and the deserialization.
+/// If it fails, the resource is not changed.
+///
+/// `R` is registered into [`AppTypeRegistry`] automatically if it has not been registered yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Reflect, Default)]
+/// struct Settings{
+///     volume: f32,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     if task.will(Update, once::res::load_from_file::<Settings, _>().with("settings.ron")).await.is_err() {
+///         task.will(Update, once::res::init::<Settings>()).await;
+///     }
+/// });
+/// ```
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "persist")))]
+pub fn load_from_file<R, P>() -> ActionSeed<P, io::Result<()>>
+where
+    R: Resource + Reflect + TypePath + FromReflect + GetTypeRegistration,
+    P: AsRef<Path> + Send + Sync + 'static,
+{
+    use crate::prelude::{effect, Pipe};
+
+    ActionSeed::define(|path: P| {
+        effect::thread::spawn(move |_| std::fs::read_to_string(path))
+            .with(())
+            .pipe(once::run(deserialize_resource::<R>))
+    })
+}
+
+/// Loads the resource from the RON file at the input path, and then saves it into the file each time it changes.
+///
+/// If the file does not exist yet, the current resource is kept as it is, and it is saved as soon as it changes.
+/// Like [`save_to_file`] and [`load_from_file`], the file is read and written on worker threads.
+/// The next write starts only after the previous one has finished, so the writes always land in the order of the changes,
+/// and the changes made during a write are saved together by the next write.
+///
+/// This action keeps running until the reactor is despawned;
+/// it completes only if reading, writing or (de)serialization fails, with that error as the output.
+///
+/// `R` is registered into [`AppTypeRegistry`] automatically if it has not been registered yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Reflect, Default)]
+/// struct Settings{
+///     volume: f32,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::res::init::<Settings>()).await;
+///     if let Err(e) = task.will(Update, once::res::persist::<Settings, _>().with("settings.ron")).await {
+///         error!("failed to persist settings: {e}");
+///     }
+/// });
+/// ```
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "persist")))]
+pub fn persist<R, P>() -> ActionSeed<P, io::Result<()>>
+where
+    R: Resource + Reflect + TypePath + FromReflect + GetTypeRegistration,
+    P: AsRef<Path> + Send + Sync + 'static,
+{
+    ActionSeed::define(|path: P| {
+        let read_path = path.as_ref().to_path_buf();
+        let write_path = read_path.clone();
+        persist_with::<R>(
+            move || match std::fs::read_to_string(&read_path) {
+                Ok(ron) => Ok(Some(ron)),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(e) => Err(e),
+            },
+            move |ron| std::fs::write(&write_path, ron),
+        )
+            .with(())
+    })
+}
+
+/// The body of [`persist`], which reads the RON once via `read` and then passes it to `write` on each change.
+///
+/// Both `read` and `write` are called on worker threads via [`effect::thread::spawn`](crate::prelude::effect::thread::spawn).
+/// `read` returns `None` if nothing has been saved yet.
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+fn persist_with<R>(
+    read: impl FnOnce() -> io::Result<Option<String>> + Send + Sync + 'static,
+    write: impl Fn(String) -> io::Result<()> + Send + Sync + 'static,
+) -> ActionSeed<(), io::Result<()>>
+where
+    R: Resource + Reflect + TypePath + FromReflect + GetTypeRegistration,
+{
+    ActionSeed::new(|_, output| PersistRunner::<R, _, _> {
+        read: Some(read),
+        write: std::sync::Arc::new(write),
+        io: None,
+        loaded: Output::default(),
+        written: Output::default(),
+        saved: None,
+        output,
+        _m: std::marker::PhantomData,
+    })
+}
+
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+struct PersistRunner<R, F, W> {
+    read: Option<F>,
+    write: std::sync::Arc<W>,
+    /// The runner of the file reading or writing in progress.
+    io: Option<BoxedRunner>,
+    loaded: Output<io::Result<Option<String>>>,
+    written: Output<io::Result<()>>,
+    /// The change tick of the resource that is already in the file.
+    saved: Option<Tick>,
+    output: Output<io::Result<()>>,
+    _m: std::marker::PhantomData<R>,
+}
+
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+impl<R, F, W> PersistRunner<R, F, W>
+where
+    R: Resource + Reflect + TypePath + FromReflect + GetTypeRegistration,
+    F: FnOnce() -> io::Result<Option<String>> + Send + Sync + 'static,
+    W: Fn(String) -> io::Result<()> + Send + Sync + 'static,
+{
+    fn finish_io(&mut self, world: &mut World) -> io::Result<()> {
+        if let Some(ron) = self.loaded.take() {
+            if let Some(ron) = ron? {
+                deserialize_resource::<R>(In(Ok(ron)), world)?;
+            }
+            // The loaded resource is already in the file, so it must not be written back.
+            self.saved = world.get_resource_change_ticks::<R>().map(|ticks| ticks.changed);
+        }
+        self.written.take().unwrap_or(Ok(()))
+    }
+
+    fn next_io(&mut self, world: &mut World) -> io::Result<Option<BoxedRunner>> {
+        use crate::prelude::effect;
+
+        if let Some(read) = self.read.take() {
+            return Ok(Some(effect::thread::spawn(move |_| read()).with(()).create_runner(self.loaded.clone())));
+        }
+        let Some(changed) = world.get_resource_change_ticks::<R>().map(|ticks| ticks.changed) else {
+            return Ok(None);
+        };
+        if self.saved == Some(changed) {
+            return Ok(None);
+        }
+        self.saved = Some(changed);
+        let ron = serialize_resource::<R>(world)?;
+        let write = self.write.clone();
+        Ok(Some(effect::thread::spawn(move |ron| write(ron)).with(ron).create_runner(self.written.clone())))
+    }
+}
+
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+impl<R, F, W> Runner for PersistRunner<R, F, W>
+where
+    R: Resource + Reflect + TypePath + FromReflect + GetTypeRegistration,
+    F: FnOnce() -> io::Result<Option<String>> + Send + Sync + 'static,
+    W: Fn(String) -> io::Result<()> + Send + Sync + 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        loop {
+            if let Some(io) = self.io.as_mut() {
+                match io.run(world, token) {
+                    RunnerIs::Completed => self.io = None,
+                    other => return other,
+                }
+                if let Err(e) = self.finish_io(world) {
+                    self.output.set(Err(e));
+                    return RunnerIs::Completed;
+                }
+            }
+            match self.next_io(world) {
+                Ok(Some(io)) => self.io = Some(io),
+                Ok(None) => return RunnerIs::Running,
+                Err(e) => {
+                    self.output.set(Err(e));
+                    return RunnerIs::Completed;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+fn serialize_resource<R>(world: &mut World) -> io::Result<String>
+where
+    R: Resource + Reflect + TypePath + GetTypeRegistration,
+{
+    use bevy::reflect::serde::TypedReflectSerializer;
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    registry.write().register::<R>();
+    let Some(resource) = world.get_resource::<R>() else {
+        return Err(io::Error::new(io::ErrorKind::NotFound, format!("resource `{}` does not exist", R::type_path())));
+    };
+    let registry = registry.read();
+    let serializer = TypedReflectSerializer::new(resource.as_partial_reflect(), &registry);
+    ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+fn deserialize_resource<R>(In(ron): In<io::Result<String>>, world: &mut World) -> io::Result<()>
+where
+    R: Resource + Reflect + TypePath + FromReflect + GetTypeRegistration,
+{
+    use bevy::reflect::serde::TypedReflectDeserializer;
+    use serde::de::DeserializeSeed;
+
+    let ron = ron?;
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    registry.write().register::<R>();
+    let resource = {
+        let registry = registry.read();
+        let mut deserializer = ron::Deserializer::from_str(&ron)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let reflected = TypedReflectDeserializer::of::<R>(&registry)
+            .deserialize(&mut deserializer)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        R::from_reflect(reflected.as_partial_reflect()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("failed to convert into `{}`", R::type_path()))
+        })?
+    };
+    world.insert_resource(resource);
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::once::res;
@@ -124,4 +419,113 @@ mod tests {
         app.update();
         assert!(app.world().get_resource::<TestResource>().is_none());
     }
-}
\ No newline at end of file
+
+    #[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+    #[derive(bevy::prelude::Resource, bevy::prelude::Reflect, Debug, PartialEq)]
+    struct Settings {
+        volume: u8,
+        name: String,
+    }
+
+    /// Updates the app twice, waiting for the file access on the worker thread in between.
+    #[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+    fn update_with_io(app: &mut bevy::app::App) {
+        app.update();
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        app.update();
+    }
+
+    #[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+    fn persist_app(file: std::sync::Arc<std::sync::Mutex<Option<String>>>) -> bevy::app::App {
+        let mut app = test_app();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let read_file = file.clone();
+            let write_file = file.clone();
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, res::persist_with::<Settings>(
+                    move || Ok(read_file.lock().unwrap().clone()),
+                    move |ron| {
+                        *write_file.lock().unwrap() = Some(ron);
+                        Ok(())
+                    },
+                )).await.unwrap();
+            }));
+        });
+        app
+    }
+
+    #[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+    #[test]
+    fn serialize_and_deserialize_resource() {
+        use bevy::prelude::In;
+        use bevy_test_helper::resource::DirectResourceControl;
+
+        let mut app = test_app();
+        app.insert_resource(Settings {
+            volume: 3,
+            name: "flurx".to_string(),
+        });
+        let ron = res::serialize_resource::<Settings>(app.world_mut()).unwrap();
+        app.world_mut().remove_resource::<Settings>();
+        res::deserialize_resource::<Settings>(In(Ok(ron)), app.world_mut()).unwrap();
+        app.assert_resource_eq(Settings {
+            volume: 3,
+            name: "flurx".to_string(),
+        });
+    }
+
+    #[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+    #[test]
+    fn persist_saves_resource_on_change() {
+        use bevy_test_helper::resource::DirectResourceControl;
+        use std::sync::{Arc, Mutex};
+
+        let file = Arc::new(Mutex::new(None));
+        let mut app = persist_app(file.clone());
+        app.insert_resource(Settings {
+            volume: 3,
+            name: "flurx".to_string(),
+        });
+        update_with_io(&mut app);
+        assert!(file.lock().unwrap().is_none());
+
+        app.resource_mut::<Settings>().volume = 5;
+        update_with_io(&mut app);
+        let saved = file.lock().unwrap().clone().unwrap();
+
+        update_with_io(&mut app);
+        assert_eq!(file.lock().unwrap().as_ref(), Some(&saved));
+
+        let mut app = persist_app(Arc::new(Mutex::new(Some(saved))));
+        app.insert_resource(Settings {
+            volume: 0,
+            name: String::new(),
+        });
+        update_with_io(&mut app);
+        app.assert_resource_eq(Settings {
+            volume: 5,
+            name: "flurx".to_string(),
+        });
+    }
+
+    #[cfg(all(feature = "persist", not(target_arch = "wasm32")))]
+    #[test]
+    fn persist_does_not_write_back_loaded_resource() {
+        use bevy_test_helper::resource::DirectResourceControl;
+        use std::sync::{Arc, Mutex};
+
+        let file = Arc::new(Mutex::new(Some("(volume: 7, name: \"saved\")".to_string())));
+        let mut app = persist_app(file.clone());
+        app.insert_resource(Settings {
+            volume: 0,
+            name: String::new(),
+        });
+        update_with_io(&mut app);
+        update_with_io(&mut app);
+        app.assert_resource_eq(Settings {
+            volume: 7,
+            name: "saved".to_string(),
+        });
+        assert_eq!(file.lock().unwrap().as_deref(), Some("(volume: 7, name: \"saved\")"));
+    }
+}
@@ -0,0 +1,244 @@
+//! [`once::rng`] creates a task that only once run system related to [`FlurxRng`].
+//!
+//! Randomized waits and choices in reactors can flow through the single reproducible random source.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::prelude::{In, ReflectResource, ResMut, Resource};
+use bevy::reflect::Reflect;
+use std::ops::{Range, RangeInclusive};
+
+/// The reproducible random number generator used by [`once::rng`](crate::prelude::once::rng).
+///
+/// This resource is initialized by [`FlurxPlugin`](crate::prelude::FlurxPlugin) with the seed `0`,
+/// so the same sequence of numbers is generated every run unless it is reseeded.
+/// The whole state is a single `u64`, so it can be saved and restored along with the game data,
+/// e.g. with [`once::res::save_to_file`](crate::prelude::once::res::save_to_file) behind the `persist` feature flag.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn roll(mut rng: ResMut<FlurxRng>){
+///     let dice = rng.gen_range(1..=6);
+///     info!("{dice}");
+/// }
+/// ```
+#[derive(Resource, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "persist", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlurxRng {
+    state: u64,
+}
+
+impl FlurxRng {
+    /// Creates a new [`FlurxRng`] with the `seed`.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+        }
+    }
+
+    /// Resets the state with the `seed`.
+    #[inline]
+    pub fn reseed(&mut self, seed: u64) {
+        self.state = seed;
+    }
+
+    /// Generates the next random `u64`.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Generates a random value in the `range`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the range is empty.
+    #[inline]
+    pub fn gen_range<R: SampleRange>(&mut self, range: R) -> R::Output {
+        range.sample(self)
+    }
+}
+
+/// The range from which [`FlurxRng::gen_range`] samples a value.
+///
+/// This is implemented for [`Range`] and [`RangeInclusive`] of the primitive integers, [`f32`] and [`f64`].
+pub trait SampleRange {
+    /// The type of the sampled value.
+    type Output;
+
+    /// Samples a value in the range.
+    fn sample(self, rng: &mut FlurxRng) -> Self::Output;
+}
+
+fn sample_span(rng: &mut FlurxRng, span: u128) -> i128 {
+    if span > u64::MAX as u128 {
+        rng.next_u64() as i128
+    } else {
+        ((rng.next_u64() as u128 * span) >> 64) as i128
+    }
+}
+
+macro_rules! impl_sample_int {
+    ($($t: ty),*) => {$(
+        impl SampleRange for Range<$t> {
+            type Output = $t;
+
+            #[inline]
+            fn sample(self, rng: &mut FlurxRng) -> Self::Output {
+                assert!(self.start < self.end, "cannot sample empty range");
+                let span = (self.end as i128 - self.start as i128) as u128;
+                (self.start as i128 + sample_span(rng, span)) as $t
+            }
+        }
+
+        impl SampleRange for RangeInclusive<$t> {
+            type Output = $t;
+
+            #[inline]
+            fn sample(self, rng: &mut FlurxRng) -> Self::Output {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "cannot sample empty range");
+                let span = (end as i128 - start as i128) as u128 + 1;
+                (start as i128 + sample_span(rng, span)) as $t
+            }
+        }
+    )*};
+}
+
+impl_sample_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+macro_rules! impl_sample_float {
+    ($($t: ty),*) => {$(
+        impl SampleRange for Range<$t> {
+            type Output = $t;
+
+            #[inline]
+            fn sample(self, rng: &mut FlurxRng) -> Self::Output {
+                assert!(self.start < self.end, "cannot sample empty range");
+                let unit = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+                (self.start as f64 + (self.end as f64 - self.start as f64) * unit) as $t
+            }
+        }
+
+        impl SampleRange for RangeInclusive<$t> {
+            type Output = $t;
+
+            #[inline]
+            fn sample(self, rng: &mut FlurxRng) -> Self::Output {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "cannot sample empty range");
+                let unit = (rng.next_u64() >> 11) as f64 / ((1u64 << 53) - 1) as f64;
+                (start as f64 + (end as f64 - start as f64) * unit) as $t
+            }
+        }
+    )*};
+}
+
+impl_sample_float!(f32, f64);
+
+/// Reseeds [`FlurxRng`] with the passed seed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::rng::seed().with(42)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn seed() -> ActionSeed<u64> {
+    once::run(|In(seed): In<u64>, mut rng: ResMut<FlurxRng>| {
+        rng.reseed(seed);
+    })
+}
+
+/// Generates a random value in the passed range with [`FlurxRng`].
+///
+/// ## Panics
+///
+/// Panics if the range is empty.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let millis: u64 = task.will(Update, once::rng::gen_range().with(500_u64..1500)).await;
+///     task.will(Update, delay::time().with(Duration::from_millis(millis))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn gen_range<R>() -> ActionSeed<R, R::Output>
+where
+    R: SampleRange + 'static,
+    R::Output: 'static,
+{
+    once::run(|In(range): In<R>, mut rng: ResMut<FlurxRng>| {
+        rng.gen_range(range)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{FlurxRng, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Resource};
+
+    #[derive(Resource, Debug, PartialEq)]
+    struct Rolls(Vec<i32>, f32);
+
+    fn roll(app: &mut bevy::app::App) -> Rolls {
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::rng::seed().with(7)).await;
+                let mut rolls = Vec::new();
+                for _ in 0..5 {
+                    rolls.push(task.will(Update, once::rng::gen_range().with(-3..=3)).await);
+                }
+                let f = task.will(Update, once::rng::gen_range().with(0.5_f32..1.0)).await;
+                task.will(Update, once::res::insert().with(Rolls(rolls, f))).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.world_mut().remove_resource::<Rolls>().unwrap()
+    }
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let Rolls(first, f1) = roll(&mut test_app());
+        let Rolls(second, f2) = roll(&mut test_app());
+        assert_eq!(first, second);
+        assert_eq!(f1, f2);
+        assert!(first.iter().all(|n| (-3..=3).contains(n)));
+        assert!((0.5..1.0).contains(&f1));
+    }
+
+    #[test]
+    fn full_range() {
+        let mut rng = FlurxRng::new(1);
+        rng.gen_range(0..=u64::MAX);
+        rng.gen_range(i64::MIN..=i64::MAX);
+        assert_eq!(rng.gen_range(5..6), 5);
+    }
+}
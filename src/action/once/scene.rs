@@ -0,0 +1,32 @@
+//! [`once::scene`] creates a task that only once run system related to scenes.
+
+use crate::action::once;
+use crate::prelude::ActionSeed;
+use bevy::prelude::{In, ResMut};
+use bevy::scene::{InstanceId, SceneSpawner};
+
+/// Despawns the scene instance associated with the passed [`InstanceId`].
+///
+/// The instance is actually despawned when [`SceneSpawner`] processes the request in the [`SpawnScene`](bevy::app::SpawnScene) schedule,
+/// so pipe it into [`wait::scene::unloaded`](crate::prelude::wait::scene::unloaded) to wait until it has gone.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::scene::InstanceId;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let instance: InstanceId = task.will(Update, once::run(|asset_server: Res<AssetServer>, mut spawner: ResMut<SceneSpawner>|{
+///         spawner.spawn(asset_server.load("<scene_path>"))
+///     })).await;
+///     task.will(Update, once::scene::despawn().with(instance)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawn() -> ActionSeed<InstanceId> {
+    once::run(|In(instance): In<InstanceId>, mut spawner: ResMut<SceneSpawner>| {
+        spawner.despawn_instance(instance);
+    })
+}
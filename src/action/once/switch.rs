@@ -54,12 +54,73 @@ pub fn off<M>() -> ActionSeed
 }
 
 
+/// The operation on [`Switch`] applied by [`once::switch::batch`](crate::prelude::once::switch::batch).
+#[derive(Copy, Clone)]
+pub struct SwitchOp(fn(&mut World));
+
+impl SwitchOp {
+    /// The operation that turns [`Switch`] on.
+    #[inline]
+    pub fn on<M>() -> Self
+        where M: Send + Sync + 'static
+    {
+        Self(|world| {
+            Switch::<M>::setup(world, true);
+        })
+    }
+
+    /// The operation that turns [`Switch`] off.
+    #[inline]
+    pub fn off<M>() -> Self
+        where M: Send + Sync + 'static
+    {
+        Self(|world| {
+            Switch::<M>::setup(world, false);
+        })
+    }
+}
+
+/// Applies all the switch operations within a single system run.
+///
+/// Unlike awaiting [`once::switch::on`] and [`once::switch::off`] one by one, which takes a frame for each,
+/// all switches are toggled at once, so the run conditions such as [`switch_just_turned_on`](crate::prelude::switch_just_turned_on)
+/// see a consistent snapshot.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::prelude::once::switch::SwitchOp;
+///
+/// struct Title;
+/// struct Gameplay;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::switch::batch([
+///         SwitchOp::off::<Title>(),
+///         SwitchOp::on::<Gameplay>(),
+///     ])).await;
+/// });
+/// ```
+#[inline]
+pub fn batch(ops: impl IntoIterator<Item = SwitchOp>) -> ActionSeed {
+    let ops = ops.into_iter().collect::<Vec<_>>();
+    once::run(move |world: &mut World| {
+        for op in &ops {
+            (op.0)(world);
+        }
+    })
+}
+
+
 #[cfg(test)]
 mod tests {
     use bevy::app::Startup;
     use bevy::prelude::{Commands, IntoSystemConfigs, ResMut, Update};
     use bevy_test_helper::resource::bool::{Bool, BoolExtension};
     use crate::action::once;
+    use crate::action::once::switch::SwitchOp;
     use crate::prelude::{switch_just_turned_off, switch_just_turned_on};
     use crate::reactor::Reactor;
     use crate::tests::test_app;
@@ -139,4 +200,31 @@ mod tests {
         app.update();
         assert!(app.is_bool_true());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn batch_toggles_switches_in_same_frame() {
+        struct T2;
+
+        let mut app = test_app();
+        app
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, once::switch::on::<T>()).await;
+                    task.will(Update, once::switch::batch([
+                        SwitchOp::off::<T>(),
+                        SwitchOp::on::<T2>(),
+                    ])).await;
+                }));
+            })
+            .add_systems(Update, (|mut b: ResMut<Bool>| {
+                **b = true;
+            })
+                .run_if(switch_just_turned_off::<T>)
+                .run_if(switch_just_turned_on::<T2>));
+
+        app.update();
+        assert!(app.is_bool_false());
+        app.update();
+        assert!(app.is_bool_true());
+    }
+}
@@ -0,0 +1,154 @@
+//! [`once::ui`] creates a task that only once run system related to [`UiFocus`] and [`Toast`].
+//!
+//! The focus is tracked by the crate-provided [`UiFocus`] resource,
+//! so keyboard and gamepad menu navigation can be driven by reactors end to end
+//! without depending on a specific UI framework.
+
+use crate::action::once;
+use crate::action::once::locale::{localize, Localization};
+use crate::prelude::ActionSeed;
+use bevy::prelude::{Entity, Event, EventWriter, In, Reflect, ReflectResource, Res, ResMut, Resource};
+use std::borrow::Cow;
+
+/// The resource that holds the focused UI entity.
+///
+/// This is only updated by [`once::ui::focus`](crate::prelude::once::ui::focus), [`once::ui::blur`](crate::prelude::once::ui::blur),
+/// or the systems that modify it directly, and can be awaited by [`wait::ui::focus_changed`](crate::prelude::wait::ui::focus_changed).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Highlighted(bool);
+///
+/// fn highlight(
+///     focus: Res<UiFocus>,
+///     mut buttons: Query<(Entity, &mut Highlighted)>,
+/// ){
+///     for (entity, mut highlighted) in buttons.iter_mut(){
+///         highlighted.0 = focus.get() == Some(entity);
+///     }
+/// }
+/// ```
+#[derive(Resource, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[reflect(Resource)]
+pub struct UiFocus(pub Option<Entity>);
+
+impl UiFocus {
+    /// Returns the focused entity.
+    #[inline(always)]
+    pub const fn get(&self) -> Option<Entity> {
+        self.0
+    }
+}
+
+/// Once focus the passed entity.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let start_button = Entity::PLACEHOLDER;
+///     task.will(Update, once::ui::focus().with(start_button)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn focus() -> ActionSeed<Entity> {
+    once::run(|In(entity): In<Entity>, mut focus: ResMut<UiFocus>| {
+        focus.0 = Some(entity);
+    })
+}
+
+/// Once clear the focus.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::ui::blur()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn blur() -> ActionSeed {
+    once::run(|mut focus: ResMut<UiFocus>| {
+        focus.0 = None;
+    })
+}
+
+/// The event sent by [`once::ui::toast`](crate::prelude::once::ui::toast) to show a short notification.
+///
+/// The UI systems read this event and show the text in their own way.
+#[derive(Event, Debug, Clone, Eq, PartialEq)]
+pub struct Toast(pub String);
+
+/// Once send [`Toast`] with the passed text.
+///
+/// If [`Localization`] exists, the text is treated as a key and resolved when this action is run.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::ui::toast().with("saved")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn toast<K>() -> ActionSeed<K>
+where
+    K: Into<Cow<'static, str>> + 'static,
+{
+    once::run(|In(key): In<K>, localization: Option<Res<Localization>>, mut ew: EventWriter<Toast>| {
+        ew.send(Toast(localize(localization.as_deref(), key.into()).into_owned()));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Localization, Reactor, Toast, UiFocus};
+    use crate::tests::test_app;
+    use bevy::app::Update;
+    use bevy::prelude::Events;
+    use bevy::utils::HashMap;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn focus_and_blur() {
+        let mut app = test_app();
+        let button = app.world_mut().spawn_empty().id();
+        app.world_mut().spawn(Reactor::schedule(move |task| async move {
+            task.will(Update, once::ui::focus().with(button)).await;
+            task.will(Update, once::ui::blur()).await;
+        }));
+        app.update();
+        app.update();
+        app.assert_resource_eq(UiFocus(Some(button)));
+        app.update();
+        app.assert_resource_eq(UiFocus(None));
+    }
+
+    #[test]
+    fn send_localized_toast() {
+        let mut app = test_app();
+        app.insert_resource(Localization::new(HashMap::from_iter([("saved", "保存しました")])));
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, once::ui::toast().with("saved")).await;
+        }));
+        app.update();
+        app.update();
+        let toasts = app.world().resource::<Events<Toast>>();
+        assert_eq!(toasts.iter_current_update_events().cloned().collect::<Vec<_>>(), vec![Toast("保存しました".to_string())]);
+    }
+}
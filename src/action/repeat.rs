@@ -0,0 +1,186 @@
+use crate::action::Action;
+use crate::prelude::{ActionSeed, CancellationHandlers};
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+
+/// Repeats the action created by the function.
+///
+/// Since an action can only be run once, this trait is implemented for functions that create the action,
+/// and the runner is re-created from the function on each iteration.
+/// The next iteration starts in the frame after the previous one has completed.
+pub trait Repeat<I, O> {
+    /// Creates an action that repeats the action `n` times, and outputs the value of the last iteration.
+    ///
+    /// The action is run at least once even if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::actions;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, {
+    ///         wait::any().with(actions![
+    ///             (|| wait::input::just_pressed().with(KeyCode::KeyA)).repeat(3),
+    ///             wait::input::just_pressed().with(KeyCode::Escape),
+    ///         ])
+    ///     }).await;
+    /// });
+    /// ```
+    fn repeat(self, n: usize) -> ActionSeed<(), O>;
+
+    /// Creates an action that repeats the action until its output satisfies `predicate`,
+    /// and outputs the value.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Event, Clone)]
+    /// struct Damage(usize);
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let critical: Damage = task.will(Update, {
+    ///         (|| wait::event::read::<Damage>()).repeat_until(|damage: &Damage| 100 <= damage.0)
+    ///     }).await;
+    /// });
+    /// ```
+    fn repeat_until(self, predicate: impl FnMut(&O) -> bool + Send + Sync + 'static) -> ActionSeed<(), O>;
+}
+
+impl<F, A, I, O> Repeat<I, O> for F
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<I, O>> + 'static,
+    I: 'static,
+    O: 'static,
+{
+    #[inline]
+    fn repeat(self, n: usize) -> ActionSeed<(), O> {
+        let mut count = 0;
+        self.repeat_until(move |_| {
+            count += 1;
+            n <= count
+        })
+    }
+
+    #[inline]
+    fn repeat_until(self, predicate: impl FnMut(&O) -> bool + Send + Sync + 'static) -> ActionSeed<(), O> {
+        ActionSeed::new(|_, output| RepeatRunner {
+            create: move || self().into(),
+            predicate,
+            current: None,
+            o1: Output::default(),
+            output,
+        })
+    }
+}
+
+struct RepeatRunner<C, P, O> {
+    create: C,
+    predicate: P,
+    current: Option<BoxedRunner>,
+    o1: Output<O>,
+    output: Output<O>,
+}
+
+impl<C, P, I, O> Runner for RepeatRunner<C, P, O>
+where
+    C: Fn() -> Action<I, O>,
+    P: FnMut(&O) -> bool,
+    I: 'static,
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let runner = self.current.get_or_insert_with(|| (self.create)().create_runner(self.o1.clone()));
+        match runner.run(world, token) {
+            RunnerIs::Completed => {}
+            other => return other,
+        }
+        self.current = None;
+        let Some(o) = self.o1.take() else {
+            return RunnerIs::Running;
+        };
+        if (self.predicate)(&o) {
+            self.output.set(o);
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor, Repeat};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn increment() -> crate::prelude::ActionSeed<(), usize> {
+        once::run(|mut count: ResMut<Count>| {
+            count.increment();
+            count.0
+        })
+    }
+
+    #[test]
+    fn repeat_3_times() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let out = task.will(Update, increment.repeat(3)).await;
+                assert_eq!(out, 3);
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn run_at_least_once() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, increment.repeat(0)).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn repeat_until_predicate_satisfied() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    increment
+                        .repeat_until(|count: &usize| *count == 2)
+                        .pipe(once::run(|In(out): In<usize>, mut count: ResMut<Count>| {
+                            count.0 = out * 10;
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(20));
+    }
+}
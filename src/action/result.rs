@@ -0,0 +1,357 @@
+//! Provides the mechanism to combine actions that output [`Result`].
+//!
+//! Like `?` in async Rust, the combined actions skip the remaining steps if the previous action outputs `Err`.
+//!
+//! trait
+//!
+//! - [`TryPipe`]
+//! - [`TryThen`]
+//! - [`MapOk`]
+//! - [`MapErr`]
+//! - [`AndThen`]
+
+use crate::action::map::Map;
+use crate::action::pipe::Pipe;
+use crate::action::remake::Remake;
+use crate::prelude::{Action, ActionSeed, CancellationHandlers};
+use crate::runner::{BoxedRunner, Runner, RunnerIs};
+use bevy::prelude::World;
+
+/// Provides the mechanism to pipe the actions that output [`Result`].
+pub trait TryPipe<I1, T1, T2, E, ActionOrSeed> {
+    /// Combine this action and the passed [`ActionSeed`] if this action outputs `Ok`.
+    ///
+    /// The `Ok` value is passed to the seed as input.
+    /// If this action outputs `Err`, the seed will not be run and the error is output as is.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let result: Result<usize, String> = task.will(Update, {
+    ///         once::run(|| Ok::<_, String>(1))
+    ///             .try_pipe(once::run(|In(num): In<usize>| {
+    ///                 Ok(num + 1)
+    ///             }))
+    ///     }).await;
+    ///     assert_eq!(result, Ok(2));
+    /// });
+    /// ```
+    fn try_pipe(self, seed: ActionSeed<T1, Result<T2, E>>) -> ActionOrSeed;
+}
+
+impl<I1, T1, T2, E, A, Re> TryPipe<I1, T1, T2, E, A> for Re
+where
+    I1: 'static,
+    T1: 'static,
+    T2: 'static,
+    E: 'static,
+    Re: Remake<I1, Result<T1, E>, Result<T2, E>, A>,
+{
+    #[inline]
+    fn try_pipe(self, seed: ActionSeed<T1, Result<T2, E>>) -> A {
+        self.pipe(ActionSeed::new(|input: Result<T1, E>, output| {
+            TryRunner(match input {
+                Ok(o1) => Some(seed.create_runner(o1, output)),
+                Err(e) => {
+                    output.set(Err(e));
+                    None
+                }
+            })
+        }))
+    }
+}
+
+/// Create the action combined with the subsequent action if this action outputs `Ok`.
+pub trait TryThen<I1, T1, T2, E, ActionOrSeed> {
+    /// Returns the action combined with the subsequent action.
+    ///
+    /// If this action outputs `Ok`, its value is discarded and the subsequent action is run.
+    /// If this action outputs `Err`, the subsequent action will not be run and the error is output as is.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let result: Result<usize, String> = task.will(Update, {
+    ///         once::run(|| Ok::<_, String>(()))
+    ///             .try_then(once::run(|| Err::<(), _>("failed".to_string())))
+    ///             // This action will not be run.
+    ///             .try_then(once::run(|| Ok(1)))
+    ///     }).await;
+    ///     assert!(result.is_err());
+    /// });
+    /// ```
+    fn try_then<I2>(self, action: impl Into<Action<I2, Result<T2, E>>> + Send + Sync + 'static) -> ActionOrSeed
+    where
+        I2: 'static;
+}
+
+impl<I1, T1, T2, E, A, Re> TryThen<I1, T1, T2, E, A> for Re
+where
+    I1: 'static,
+    T1: 'static,
+    T2: 'static,
+    E: 'static,
+    Re: Remake<I1, Result<T1, E>, Result<T2, E>, A>,
+{
+    #[inline]
+    fn try_then<I2>(self, action: impl Into<Action<I2, Result<T2, E>>> + Send + Sync + 'static) -> A
+    where
+        I2: 'static,
+    {
+        self.pipe(ActionSeed::new(|input: Result<T1, E>, output| {
+            TryRunner(match input {
+                Ok(_) => Some(action.into().create_runner(output)),
+                Err(e) => {
+                    output.set(Err(e));
+                    None
+                }
+            })
+        }))
+    }
+}
+
+/// Maps the `Ok` value of the action that outputs [`Result`], leaving `Err` untouched.
+pub trait MapOk<I1, T1, T2, E, ActionOrSeed> {
+    /// Maps `Result<T1, E>` to `Result<T2, E>` by applying the function to the `Ok` value.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let result: Result<usize, String> = task.will(Update, {
+    ///         once::run(|| Ok::<_, String>(1))
+    ///             .map_ok(|num| num + 1)
+    ///     }).await;
+    ///     assert_eq!(result, Ok(2));
+    /// });
+    /// ```
+    fn map_ok(self, f: impl FnOnce(T1) -> T2 + Send + Sync + 'static) -> ActionOrSeed;
+}
+
+impl<I1, T1, T2, E, A, Re> MapOk<I1, T1, T2, E, A> for Re
+where
+    T2: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+    Re: Map<I1, Result<T1, E>, Result<T2, E>, A>,
+{
+    #[inline]
+    fn map_ok(self, f: impl FnOnce(T1) -> T2 + Send + Sync + 'static) -> A {
+        self.map(|result| result.map(f))
+    }
+}
+
+/// Maps the `Err` value of the action that outputs [`Result`], leaving `Ok` untouched.
+pub trait MapErr<I1, T, E1, E2, ActionOrSeed> {
+    /// Maps `Result<T, E1>` to `Result<T, E2>` by applying the function to the `Err` value.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let result: Result<(), String> = task.will(Update, {
+    ///         once::run(|| Err::<(), _>("failed"))
+    ///             .map_err(|e| {
+    ///                 error!("{e}");
+    ///                 e.to_string()
+    ///             })
+    ///     }).await;
+    ///     assert!(result.is_err());
+    /// });
+    /// ```
+    fn map_err(self, f: impl FnOnce(E1) -> E2 + Send + Sync + 'static) -> ActionOrSeed;
+}
+
+impl<I1, T, E1, E2, A, Re> MapErr<I1, T, E1, E2, A> for Re
+where
+    T: Send + Sync + 'static,
+    E2: Send + Sync + 'static,
+    Re: Map<I1, Result<T, E1>, Result<T, E2>, A>,
+{
+    #[inline]
+    fn map_err(self, f: impl FnOnce(E1) -> E2 + Send + Sync + 'static) -> A {
+        self.map(|result| result.map_err(f))
+    }
+}
+
+/// Chains the fallible function to the action that outputs [`Result`].
+pub trait AndThen<I1, T1, T2, E, ActionOrSeed> {
+    /// Calls the function with the `Ok` value, and outputs its result.
+    ///
+    /// If this action outputs `Err`, the function will not be called and the error is output as is.
+    /// To chain the subsequent action rather than a function, use [`TryPipe::try_pipe`] instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let result: Result<u8, String> = task.will(Update, {
+    ///         once::run(|| Ok::<_, String>(300_usize))
+    ///             .and_then(|num| u8::try_from(num).map_err(|e| e.to_string()))
+    ///     }).await;
+    ///     assert!(result.is_err());
+    /// });
+    /// ```
+    fn and_then(self, f: impl FnOnce(T1) -> Result<T2, E> + Send + Sync + 'static) -> ActionOrSeed;
+}
+
+impl<I1, T1, T2, E, A, Re> AndThen<I1, T1, T2, E, A> for Re
+where
+    T2: Send + Sync + 'static,
+    E: Send + Sync + 'static,
+    Re: Map<I1, Result<T1, E>, Result<T2, E>, A>,
+{
+    #[inline]
+    fn and_then(self, f: impl FnOnce(T1) -> Result<T2, E> + Send + Sync + 'static) -> A {
+        self.map(|result| result.and_then(f))
+    }
+}
+
+struct TryRunner(Option<BoxedRunner>);
+
+impl Runner for TryRunner {
+    #[inline]
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        match self.0.as_mut() {
+            Some(runner) => runner.run(world, token),
+            None => RunnerIs::Completed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once};
+    use crate::prelude::{AndThen, MapErr, MapOk, Pipe, Reactor, Then, TryPipe, TryThen};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Outcome(Option<Result<usize, &'static str>>);
+
+    fn store() -> crate::prelude::ActionSeed<Result<usize, &'static str>> {
+        once::run(|In(result): In<Result<usize, &'static str>>, mut outcome: ResMut<Outcome>| {
+            outcome.0 = Some(result);
+        })
+    }
+
+    #[test]
+    fn try_pipe_ok() {
+        let mut app = test_app();
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, {
+                    once::run(|| Ok(1))
+                        .try_pipe(once::run(|In(num): In<usize>| Ok(num + 1)))
+                }).await;
+                task.will(Update, store().with(result)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Outcome(Some(Ok(2))));
+    }
+
+    #[test]
+    fn try_pipe_skip_if_err() {
+        let mut app = test_app();
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, {
+                    once::run(|| Err("failed"))
+                        .try_pipe(once::run(|In(num): In<usize>, mut count: ResMut<Count>| {
+                            count.increment();
+                            Ok(num)
+                        }))
+                }).await;
+                task.will(Update, store().with(result)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.assert_resource_eq(Outcome(Some(Err("failed"))));
+    }
+
+    #[test]
+    fn try_then_short_circuit() {
+        let mut app = test_app();
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, {
+                    once::run(|| Ok::<_, &'static str>(()))
+                        .try_then(once::run(|| Err::<(), _>("failed")))
+                        .try_then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                            Ok(3)
+                        }))
+                }).await;
+                task.will(Update, store().with(result)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.assert_resource_eq(Outcome(Some(Err("failed"))));
+    }
+
+    #[test]
+    fn try_then_run_next_if_ok() {
+        let mut app = test_app();
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(|| Ok::<_, &'static str>(()))
+                        .try_then(delay::frames().with(1).then(once::run(|| Ok(3))))
+                        .pipe(store())
+                }).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Outcome(None));
+        app.update();
+        app.assert_resource_eq(Outcome(Some(Ok(3))));
+    }
+
+    #[test]
+    fn map_ok_and_then_map_err() {
+        let mut app = test_app();
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(|| Ok::<usize, &'static str>(1))
+                        .map_ok(|num| num + 1)
+                        .and_then(|num| if num == 2 { Err("two") } else { Ok(num) })
+                        .map_err(|_| "mapped")
+                        .pipe(store())
+                }).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Outcome(Some(Err("mapped"))));
+    }
+}
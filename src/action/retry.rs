@@ -0,0 +1,235 @@
+use crate::action::{delay, Action};
+use crate::prelude::{ActionSeed, CancellationHandlers};
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+use std::time::Duration;
+
+/// The policy of [`Retry::retry`], which specifies how many times the action is retried and how long to wait between attempts.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy_flurx::prelude::*;
+///
+/// let policy = RetryPolicy::times(3).backoff_frames(30);
+/// let policy = RetryPolicy::times(5).backoff(Duration::from_millis(500));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RetryPolicy {
+    retries: usize,
+    backoff: Backoff,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum Backoff {
+    None,
+    Frames(usize),
+    Time(Duration),
+}
+
+impl RetryPolicy {
+    /// Creates the policy that retries the action at most `retries` times after the first attempt fails.
+    ///
+    /// By default, the next attempt starts in the frame after the previous one has failed.
+    #[inline]
+    pub const fn times(retries: usize) -> Self {
+        Self {
+            retries,
+            backoff: Backoff::None,
+        }
+    }
+
+    /// Waits for the specified number of frames between attempts.
+    #[inline]
+    pub const fn backoff_frames(mut self, frames: usize) -> Self {
+        self.backoff = Backoff::Frames(frames);
+        self
+    }
+
+    /// Waits for the specified duration between attempts.
+    ///
+    /// The elapsed time is measured with [`Time`](bevy::prelude::Time), same as [`delay::time`].
+    #[inline]
+    pub const fn backoff(mut self, duration: Duration) -> Self {
+        self.backoff = Backoff::Time(duration);
+        self
+    }
+
+    fn create_backoff_runner(&self) -> Option<BoxedRunner> {
+        match self.backoff {
+            Backoff::None => None,
+            Backoff::Frames(frames) => Some(delay::frames().with(frames).create_runner(Output::default())),
+            Backoff::Time(duration) => Some(delay::time().with(duration).create_runner(Output::default())),
+        }
+    }
+}
+
+/// Retries the action that outputs [`Result`] while it fails.
+///
+/// Since an action can only be run once, this trait is implemented for functions that create the action,
+/// and the runner is re-created from the function on each attempt, same as [`Repeat`](crate::prelude::Repeat).
+pub trait Retry<I, O, E> {
+    /// Creates an action that re-creates and runs the action each time its output is `Err`,
+    /// waiting the backoff of `policy` between attempts.
+    ///
+    /// The output is `Ok` as soon as an attempt succeeds,
+    /// or the last `Err` if all attempts have failed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// fn connect() -> ActionSeed<(), Result<(), String>>{
+    ///     once::run(|| Err("refused".to_string()))
+    /// }
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     if let Err(e) = task.will(Update, connect.retry(RetryPolicy::times(3).backoff_frames(30))).await {
+    ///         error!("{e}");
+    ///     }
+    /// });
+    /// ```
+    fn retry(self, policy: RetryPolicy) -> ActionSeed<(), Result<O, E>>;
+}
+
+impl<F, A, I, O, E> Retry<I, O, E> for F
+where
+    F: Fn() -> A + Send + Sync + 'static,
+    A: Into<Action<I, Result<O, E>>> + 'static,
+    I: 'static,
+    O: 'static,
+    E: 'static,
+{
+    #[inline]
+    fn retry(self, policy: RetryPolicy) -> ActionSeed<(), Result<O, E>> {
+        ActionSeed::new(move |_, output| RetryRunner {
+            create: move || self().into(),
+            policy,
+            retries: 0,
+            current: None,
+            backoff: None,
+            o1: Output::default(),
+            output,
+        })
+    }
+}
+
+struct RetryRunner<C, O, E> {
+    create: C,
+    policy: RetryPolicy,
+    retries: usize,
+    current: Option<BoxedRunner>,
+    backoff: Option<BoxedRunner>,
+    o1: Output<Result<O, E>>,
+    output: Output<Result<O, E>>,
+}
+
+impl<C, I, O, E> Runner for RetryRunner<C, O, E>
+where
+    C: Fn() -> Action<I, Result<O, E>>,
+    I: 'static,
+    O: 'static,
+    E: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if let Some(backoff) = self.backoff.as_mut() {
+            match backoff.run(world, token) {
+                RunnerIs::Completed => self.backoff = None,
+                other => return other,
+            }
+        }
+        let runner = self.current.get_or_insert_with(|| (self.create)().create_runner(self.o1.clone()));
+        match runner.run(world, token) {
+            RunnerIs::Completed => {}
+            other => return other,
+        }
+        self.current = None;
+        match self.o1.take() {
+            Some(Err(_)) if self.retries < self.policy.retries => {
+                self.retries += 1;
+                self.backoff = self.policy.create_backoff_runner();
+                RunnerIs::Running
+            }
+            Some(out) => {
+                self.output.set(out);
+                RunnerIs::Completed
+            }
+            None => RunnerIs::Running,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Pipe, Reactor, Retry, RetryPolicy};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Eq, PartialEq)]
+    struct Outcome(Result<usize, usize>);
+
+    fn fail_until(n: usize) -> impl Fn() -> crate::prelude::ActionSeed<(), Result<usize, usize>> + Send + Sync {
+        move || once::run(move |mut count: ResMut<Count>| {
+            count.increment();
+            if n <= count.0 { Ok(count.0) } else { Err(count.0) }
+        })
+    }
+
+    fn spawn_reactor(app: &mut bevy::app::App, succeed_at: usize, policy: RetryPolicy) {
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, fail_until(succeed_at)
+                    .retry(policy)
+                    .pipe(once::run(|In(out): In<Result<usize, usize>>, mut commands: Commands| {
+                        commands.insert_resource(Outcome(out));
+                    }))).await;
+            }));
+        });
+    }
+
+    #[test]
+    fn output_ok_if_retry_succeeded() {
+        let mut app = test_app();
+        spawn_reactor(&mut app, 3, RetryPolicy::times(3));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+        app.assert_resource_eq(Outcome(Ok(3)));
+    }
+
+    #[test]
+    fn output_last_error_if_all_attempts_failed() {
+        let mut app = test_app();
+        spawn_reactor(&mut app, 10, RetryPolicy::times(2));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+        app.assert_resource_eq(Outcome(Err(3)));
+    }
+
+    #[test]
+    fn wait_backoff_between_attempts() {
+        let mut app = test_app();
+        spawn_reactor(&mut app, 2, RetryPolicy::times(1).backoff_frames(3));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        for _ in 0..3 {
+            app.update();
+            app.assert_resource_eq(Count(1));
+        }
+        for _ in 0..2 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+        app.assert_resource_eq(Outcome(Ok(2)));
+    }
+}
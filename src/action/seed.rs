@@ -3,6 +3,7 @@
 use crate::action::Action;
 use crate::runner::{BoxedRunner, Output, Runner};
 use bevy::prelude::Reflect;
+use std::borrow::Cow;
 
 /// If [`In`](bevy::prelude::In) type of the struct implements this is `()`,
 /// its struct also implements Into<[`Action`]> automatically.
@@ -13,8 +14,11 @@ use bevy::prelude::Reflect;
 /// [`Action`]: Action
 /// [`Pipe::pipe`]: crate::prelude::Pipe::pipe
 #[derive(Reflect)]
-#[repr(transparent)]
-pub struct ActionSeed<I = (), O = ()>(Box<dyn FnOnce(I, Output<O>) -> BoxedRunner + Send + Sync> );
+pub struct ActionSeed<I = (), O = ()> {
+    create_runner: Box<dyn FnOnce(I, Output<O>) -> BoxedRunner + Send + Sync>,
+    name: Option<Cow<'static, str>>,
+    run_even_if_paused: bool,
+}
 
 
 impl<I, O> ActionSeed<I, O>
@@ -28,9 +32,9 @@ where
     where
         R: Runner  + 'static,
     {
-        ActionSeed(Box::new(move |input, output| {
+        ActionSeed::from(move |input, output| {
             BoxedRunner::new(f(input, output))
-        }))
+        })
     }
 
     /// Define [`ActionSeed`] based on the function that returns an action from the input.
@@ -58,6 +62,71 @@ where
         ActionSeed::from(|input, output| f(input).into().create_runner(output))
     }
 
+    /// Gives the name to this seed.
+    ///
+    /// The name is passed to the middlewares registered by [`FlurxPlugin::with_middleware`](crate::prelude::FlurxPlugin::with_middleware),
+    /// so that they can identify the action.
+    ///
+    /// Note that the name is not inherited to the actions combined by methods such as [`Pipe::pipe`](crate::prelude::Pipe::pipe),
+    /// so it should be called last.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, delay::frames().with(3).named("wait_3_frames")).await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn named(mut self, name: impl Into<Cow<'static, str>>) -> ActionSeed<I, O> {
+        self.name.replace(name.into());
+        self
+    }
+
+    /// Returns the name given by [`ActionSeed::named`].
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Makes this action keep running even while its reactor is paused by [`ReactorPaused`](crate::prelude::ReactorPaused)
+    /// or [`Time<Virtual>`](bevy::time::Virtual) is paused, while the rest of the reactor stays frozen.
+    ///
+    /// While [`Time<Virtual>`](bevy::time::Virtual) is paused, the time-based actions such as [`delay::time`](crate::prelude::delay::time)
+    /// and [`tween::component`](crate::prelude::tween::component) measure the elapsed time by [`Time<Real>`](bevy::time::Real),
+    /// so they also keep progressing. The global [`Time`](bevy::time::Time) itself is left untouched.
+    ///
+    /// This is useful for the flows that must continue during a pause, such as fading out music or pause menu UI.
+    /// Note that the reactor observes the output of this action after it is resumed.
+    ///
+    /// Like [`ActionSeed::named`], this flag is not inherited to the actions combined by methods such as [`Pipe::pipe`](crate::prelude::Pipe::pipe),
+    /// so it should be called last.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, delay::time().with(Duration::from_secs(1)).run_even_if_paused()).await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn run_even_if_paused(mut self) -> ActionSeed<I, O> {
+        self.run_even_if_paused = true;
+        self
+    }
+
+    #[inline(always)]
+    pub(crate) const fn is_run_even_if_paused(&self) -> bool {
+        self.run_even_if_paused
+    }
+
     /// Into [`Action`] with `input`.
     ///
     /// [`Action`]:  Action
@@ -72,7 +141,7 @@ where
     /// For example, when creating a new runner that extends an existing one.
     #[inline]
     pub fn create_runner(self, input: I, output: Output<O>) -> BoxedRunner {
-        self.0(input, output)
+        (self.create_runner)(input, output).traced(self.name)
     }
 }
 
@@ -82,7 +151,11 @@ where
 {
     #[inline]
     fn from(value: F) -> Self {
-        Self(Box::new(value))
+        Self {
+            create_runner: Box::new(value),
+            name: None,
+            run_even_if_paused: false,
+        }
     }
 }
 
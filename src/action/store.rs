@@ -0,0 +1,91 @@
+use crate::action::once;
+use crate::prelude::Pipe;
+use bevy::prelude::{Commands, In, Resource};
+
+/// Writes the output of the action into a resource when the action is completed.
+///
+/// This is handy when both the reactor and unrelated systems need the result.
+pub trait StoreIn<I, O, A> {
+    /// Inserts `R::from(output)` as a resource when the action is completed,
+    /// and outputs the original output.
+    ///
+    /// The resource is inserted via [`Commands`], so it is available from the systems that run after this action in the same frame.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct HighScore(usize);
+    ///
+    /// impl From<usize> for HighScore {
+    ///     fn from(score: usize) -> Self {
+    ///         Self(score)
+    ///     }
+    /// }
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let score: usize = task.will(Update, once::run(|| 100).store_in::<HighScore>()).await;
+    /// });
+    /// ```
+    fn store_in<R>(self) -> A
+    where
+        R: Resource + From<O>;
+}
+
+impl<I, O, A, ActionOrSeed> StoreIn<I, O, A> for ActionOrSeed
+where
+    ActionOrSeed: Pipe<I, O, O, A>,
+    O: Clone + 'static,
+{
+    #[inline]
+    fn store_in<R>(self) -> A
+    where
+        R: Resource + From<O>,
+    {
+        self.pipe(once::run(|In(output): In<O>, mut commands: Commands| {
+            commands.insert_resource(R::from(output.clone()));
+            output
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Reactor, StoreIn};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Eq, PartialEq)]
+    struct HighScore(usize);
+
+    impl From<usize> for HighScore {
+        fn from(value: usize) -> Self {
+            Self(value)
+        }
+    }
+
+    #[test]
+    fn store_output_in_resource() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let output = task.will(Update, once::run(|| 3).store_in::<HighScore>()).await;
+                task.will(Update, once::run(|In(output): In<usize>, mut count: ResMut<Count>| {
+                    count.0 = output;
+                }).with(output)).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(HighScore(3));
+
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+}
@@ -0,0 +1,195 @@
+//! Provides the actions that synchronize multiple reactors.
+//!
+//! actions
+//!
+//! - [`sync::barrier`](crate::prelude::sync::barrier)
+
+use crate::action::seed::ActionSeed;
+use crate::runner::{CancellationHandlers, CancellationId, Output, Runner, RunnerIs};
+use bevy::core::FrameCount;
+use bevy::prelude::{Resource, World};
+use bevy::utils::HashMap;
+use std::borrow::Cow;
+use std::sync::{Arc, Weak};
+
+#[derive(Default)]
+struct Barrier {
+    /// The tickets of the participants waiting at the barrier.
+    ///
+    /// A ticket is dropped together with its runner, so the participants whose reactor was canceled are not counted.
+    participants: Vec<Weak<()>>,
+    generation: u64,
+    released_frame: u32,
+}
+
+impl Barrier {
+    fn leave_canceled(&mut self) {
+        self.participants.retain(|ticket| ticket.strong_count() != 0);
+    }
+}
+
+/// The states of the barriers created by [`sync::barrier`](crate::prelude::sync::barrier).
+#[derive(Resource, Default)]
+pub(crate) struct Barriers(HashMap<Cow<'static, str>, Barrier>);
+
+/// Waits until `n` reactors have arrived at the barrier identified by `key`.
+///
+/// All participating reactors resolve simultaneously in the frame after the `n`th reactor arrived,
+/// as long as their actions run on the same schedule.
+/// After the release, the barrier is reset so that the same key can be reused for the next beat.
+///
+/// A reactor canceled while waiting at the barrier leaves it, so it no longer counts towards `n`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// // Spawn the same reactor for each actor in the cutscene.
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, sync::barrier("beat_1", 3)).await;
+/// });
+/// ```
+#[inline]
+pub fn barrier(key: impl Into<Cow<'static, str>>, n: usize) -> ActionSeed {
+    let key = key.into();
+    ActionSeed::new(move |_, output| BarrierRunner {
+        key,
+        n,
+        arrived: None,
+        cancellation_id: None,
+        output,
+    })
+}
+
+struct BarrierRunner {
+    key: Cow<'static, str>,
+    n: usize,
+    arrived: Option<(u64, Arc<()>)>,
+    cancellation_id: Option<CancellationId>,
+    output: Output<()>,
+}
+
+impl Runner for BarrierRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let frame = world.resource::<FrameCount>().0;
+        let mut barriers = world.resource_mut::<Barriers>();
+        let barrier = barriers.0.entry(self.key.clone()).or_default();
+        let Some((generation, _)) = self.arrived.as_ref() else {
+            let ticket = Arc::new(());
+            barrier.leave_canceled();
+            barrier.participants.push(Arc::downgrade(&ticket));
+            self.arrived.replace((barrier.generation, ticket));
+            if self.n <= barrier.participants.len() {
+                barrier.participants.clear();
+                barrier.generation += 1;
+                barrier.released_frame = frame;
+            }
+            self.cancellation_id.replace(token.register(leave_canceled_participants));
+            return RunnerIs::Running;
+        };
+        if generation + 1 < barrier.generation || (*generation < barrier.generation && barrier.released_frame != frame) {
+            if let Some(id) = self.cancellation_id.take() {
+                token.unregister(&id);
+            }
+            self.output.set(());
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+fn leave_canceled_participants(world: &mut World) {
+    if let Some(mut barriers) = world.get_resource_mut::<Barriers>() {
+        barriers.0.values_mut().for_each(Barrier::leave_canceled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, sync};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::core::FrameCount;
+    use bevy::prelude::{Commands, Entity, Res, ResMut, Resource};
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Arrived(Vec<usize>);
+
+    fn spawn_participant(commands: &mut Commands, delay_frames: usize) -> Entity {
+        commands.spawn(Reactor::schedule(move |task| async move {
+            task.will(Update, {
+                delay::frames()
+                    .with(delay_frames)
+                    .then(sync::barrier("beat", 3))
+                    .then(once::run(|mut arrived: ResMut<Arrived>, frame: Res<FrameCount>| {
+                        arrived.0.push(frame.0 as usize);
+                    }))
+            }).await;
+        })).id()
+    }
+
+    #[test]
+    fn resolve_all_simultaneously() {
+        let mut app = test_app();
+        app.init_resource::<Arrived>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            spawn_participant(&mut commands, 4);
+            spawn_participant(&mut commands, 1);
+            spawn_participant(&mut commands, 2);
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Arrived(Vec::new()));
+        for _ in 0..3 {
+            app.update();
+        }
+        let arrived = &app.world().resource::<Arrived>().0;
+        assert_eq!(arrived.len(), 3);
+        assert!(arrived.iter().all(|frame| *frame == arrived[0]));
+    }
+
+    #[test]
+    fn wait_until_n_arrived() {
+        let mut app = test_app();
+        app.init_resource::<Arrived>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            spawn_participant(&mut commands, 1);
+            spawn_participant(&mut commands, 1);
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Arrived(Vec::new()));
+    }
+
+    #[test]
+    fn not_count_canceled_participant() {
+        let mut app = test_app();
+        app.init_resource::<Arrived>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            spawn_participant(&mut commands, 1);
+            spawn_participant(&mut commands, 4);
+        });
+        let canceled = spawn_participant(&mut app.world_mut().commands(), 1);
+        for _ in 0..3 {
+            app.update();
+        }
+        app.world_mut().despawn(canceled);
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Arrived(Vec::new()));
+
+        spawn_participant(&mut app.world_mut().commands(), 1);
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Arrived>().0.len(), 3);
+    }
+}
@@ -5,6 +5,7 @@
 //! actions
 //!
 //! - [`through`]
+//! - [`through_with`]
 
 use crate::action::pipe::Pipe;
 use crate::action::seed::ActionSeed;
@@ -48,6 +49,41 @@ where
     })
 }
 
+/// Same as [`through`], except that the action is created from the passed-through value.
+///
+/// This is useful when the side action depends on the value computed earlier in the pipe,
+/// e.g. delaying a number of frames that was output by the previous action.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task|async move{
+///     task.will(Update, once::run(|| 30)
+///         .pipe(through_with(|frames: &usize| delay::frames().with(*frames)))
+///         .pipe(once::run(|In(frames): In<usize>|{
+///               println!("{frames} frames have passed.");
+///         }))
+///     ).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn through_with<V, I, O, A>(f: impl FnOnce(&V) -> A + Send + Sync + 'static) -> ActionSeed<V, V>
+where
+    V: 'static,
+    I: 'static,
+    O: 'static,
+    A: Into<Action<I, O>>,
+{
+    ActionSeed::new(|input, output| ThroughRunner {
+        inner: f(&input).into().create_runner(Output::default()),
+        value: Some(input),
+        output,
+    })
+}
+
 /// Provides a method version of [`through`].
 pub trait Through<I1, O1, O2, ActionOrSeed> {
     ///
@@ -76,6 +112,28 @@ pub trait Through<I1, O1, O2, ActionOrSeed> {
     fn through<I2>(self, action: impl Into<Action<I2, O2>> + Send + Sync + 'static) -> ActionOrSeed
     where
         I2: 'static;
+
+    /// This method is syntax sugar for `self.pipe(through_with(f))`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task|async move{
+    ///     task.will(Update, once::run(|| 30)
+    ///         .through_with(|frames: &usize| delay::frames().with(*frames))
+    ///         .pipe(once::run(|In(frames): In<usize>|{
+    ///               println!("{frames} frames have passed.");
+    ///         }))
+    ///     ).await;
+    /// });
+    /// ```
+    fn through_with<I2, A>(self, f: impl FnOnce(&O1) -> A + Send + Sync + 'static) -> ActionOrSeed
+    where
+        I2: 'static,
+        A: Into<Action<I2, O2>>;
 }
 
 impl<I1, O1, O2> Through<I1, O1, O2, ActionSeed<I1, O1>> for ActionSeed<I1, O1>
@@ -91,6 +149,15 @@ where
     {
         self.pipe(through(action))
     }
+
+    #[inline]
+    fn through_with<I2, A>(self, f: impl FnOnce(&O1) -> A + Send + Sync + 'static) -> ActionSeed<I1, O1>
+    where
+        I2: 'static,
+        A: Into<Action<I2, O2>>,
+    {
+        self.pipe(through_with(f))
+    }
 }
 
 impl<I1, O1, O2> Through<I1, O1, O2, Action<I1, O1>> for Action<I1, O1>
@@ -106,6 +173,15 @@ where
     {
         self.pipe(through(action))
     }
+
+    #[inline]
+    fn through_with<I2, A>(self, f: impl FnOnce(&O1) -> A + Send + Sync + 'static) -> Action<I1, O1>
+    where
+        I2: 'static,
+        A: Into<Action<I2, O2>>,
+    {
+        self.pipe(through_with(f))
+    }
 }
 
 struct ThroughRunner<V> {
@@ -131,8 +207,8 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::action::once;
     use crate::action::pipe::Pipe;
+    use crate::action::{delay, once};
     use crate::action::through::Through;
     use crate::prelude::Reactor;
     use crate::tests::test_app;
@@ -163,4 +239,28 @@ mod tests {
 
         app.assert_resource_eq(Count(1));
     }
+
+    #[test]
+    fn through_with_delay_computed_from_value() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    once::run(|| 2usize)
+                        .through_with(|frames: &usize| delay::frames().with(*frames))
+                        .pipe(once::run(|In(num): In<usize>, mut commands: Commands| {
+                            commands.insert_resource(Count(num));
+                        })),
+                )
+                    .await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert!(app.world().get_resource::<Count>().is_none());
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
 }
@@ -0,0 +1,207 @@
+use crate::action::delay;
+use crate::action::remake::Remake;
+use crate::prelude::CancellationHandlers;
+use crate::runner::{BoxedRunner, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+use std::time::Duration;
+
+/// Limits the execution time of an action.
+pub trait Timeout<I1, O1, ActionOrSeed> {
+    /// Creates an action that waits until either the original action is completed or the `duration` elapses.
+    ///
+    /// The output value is `Some` with the output of the original action if it completes in time,
+    /// otherwise `None`.
+    ///
+    /// When the deadline passes, the runner of the original action is dropped immediately,
+    /// so it will not be run again.
+    ///
+    /// The elapsed time is measured with [`Time`](bevy::prelude::Time), same as [`delay::time`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let pressed: Option<()> = task.will(Update, {
+    ///         wait::input::just_pressed().with(KeyCode::KeyA)
+    ///             .timeout(Duration::from_secs(3))
+    ///     }).await;
+    ///     if pressed.is_none() {
+    ///         info!("timed out");
+    ///     }
+    /// });
+    /// ```
+    fn timeout(self, duration: Duration) -> ActionOrSeed;
+}
+
+impl<I, O, A, Re> Timeout<I, O, A> for Re
+where
+    I: 'static,
+    O: 'static,
+    Re: Remake<I, O, Option<O>, A> + 'static,
+{
+    #[inline]
+    fn timeout(self, duration: Duration) -> A {
+        self.remake(move |r1, o1, output| TimeoutRunner {
+            r1: Some(r1),
+            o1,
+            output,
+            timer: delay::time().with(duration).create_runner(Output::default()),
+        })
+    }
+}
+
+struct TimeoutRunner<O> {
+    r1: Option<BoxedRunner>,
+    o1: Output<O>,
+    output: Output<Option<O>>,
+    timer: BoxedRunner,
+}
+
+impl<O> Runner for TimeoutRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let Some(r1) = self.r1.as_mut() else {
+            return RunnerIs::Completed;
+        };
+        match r1.run(world, token) {
+            RunnerIs::Canceled => return RunnerIs::Canceled,
+            RunnerIs::Completed => {
+                self.output.set(self.o1.take());
+                return RunnerIs::Completed;
+            }
+            RunnerIs::Running => {}
+        }
+        match self.timer.run(world, token) {
+            RunnerIs::Completed => {
+                // Drop the original runner now to free its resources, rather than when this runner is dropped.
+                self.r1 = None;
+                self.output.set(None);
+                RunnerIs::Completed
+            }
+            other => other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Map, Pipe, Reactor, Timeout};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, Local, ResMut, Resource};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Resource, Debug, Eq, PartialEq, Default)]
+    struct Outcome(Option<Option<usize>>);
+
+    #[test]
+    fn output_none_if_timed_out() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::until(|mut count: ResMut<Count>| {
+                        count.increment();
+                        false
+                    })
+                        .overwrite(0)
+                        .timeout(Duration::from_secs(2))
+                        .pipe(once::run(|In(out): In<Option<usize>>, mut result: ResMut<Outcome>| {
+                            result.0 = Some(out);
+                        }))
+                }).await;
+            }));
+        });
+
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Outcome(Some(None)));
+        let count = app.world().resource::<Count>().0;
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(count));
+    }
+
+    #[test]
+    fn output_some_if_completed_in_time() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(10)));
+        app.init_resource::<Outcome>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::output(|mut count: Local<usize>| {
+                        *count += 1;
+                        (*count == 3).then_some(*count)
+                    })
+                        .timeout(Duration::from_secs(10))
+                        .pipe(once::run(|In(out): In<Option<usize>>, mut result: ResMut<Outcome>| {
+                            result.0 = Some(out);
+                        }))
+                }).await;
+            }));
+        });
+
+        app.update();
+        app.update();
+        app.assert_resource_eq(Outcome(None));
+        app.update();
+        app.assert_resource_eq(Outcome(Some(Some(3))));
+    }
+
+    #[test]
+    fn drop_original_runner_on_timeout() {
+        use crate::prelude::{ActionSeed, CancellationHandlers, Runner, RunnerIs, Then};
+        use bevy::prelude::World;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        struct DropRunner(Arc<AtomicBool>);
+
+        impl Runner for DropRunner {
+            fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+                RunnerIs::Running
+            }
+        }
+
+        impl Drop for DropRunner {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let flag = dropped.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let flag = flag.clone();
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, ActionSeed::new(move |_, _: crate::prelude::Output<()>| DropRunner(flag))
+                    .timeout(Duration::from_millis(300))
+                    .then(wait::until(|| false)),
+                ).await;
+            }));
+        });
+        app.update();
+        assert!(!dropped.load(Ordering::Relaxed));
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(dropped.load(Ordering::Relaxed));
+    }
+}
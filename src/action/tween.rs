@@ -0,0 +1,77 @@
+//! Provides the actions that interpolate values of components over time.
+//!
+//! The values are advanced every frame according to [`EaseFunction`], and the actions are completed when the duration has elapsed.
+//!
+//! actions
+//!
+//! - [`tween::component`](crate::prelude::tween::component)
+//! - [`tween::transform::move_to`](crate::prelude::tween::transform::move_to)
+//! - [`tween::transform::scale_to`](crate::prelude::tween::transform::scale_to)
+//! - [`tween::transform::rotate_to`](crate::prelude::tween::transform::rotate_to)
+//! - [`tween::timeline::component`](crate::prelude::tween::timeline::component)
+
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use crate::reactor::time::ReactorDelta;
+pub use bevy::math::curve::EaseFunction;
+use bevy::math::curve::{Curve, Ease, EasingCurve};
+use bevy::prelude::{Component, Entity, In, Local, Query};
+use std::time::Duration;
+
+pub mod timeline;
+pub mod transform;
+
+/// Interpolates the value of the component attached to the passed [`Entity`] to `target` over `duration`.
+///
+/// The value to be interpolated is selected by `lens`, and its value when this action starts is used as the start value.
+/// If the entity or the component does not exist, this action is completed immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Volume(f32);
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Volume(1.)).id()
+///     })).await;
+///     task.will(Update, tween::component(0., Duration::from_secs(1), tween::EaseFunction::QuadraticOut, |volume: &mut Volume| &mut volume.0)
+///         .with(entity)
+///     ).await;
+/// });
+/// ```
+pub fn component<C, T>(
+    target: T,
+    duration: Duration,
+    ease: EaseFunction,
+    lens: fn(&mut C) -> &mut T,
+) -> ActionSeed<Entity>
+where
+    C: Component,
+    T: Ease + Clone + Send + Sync + 'static,
+{
+    wait::until(move |In(entity): In<Entity>,
+                      mut start: Local<Option<T>>,
+                      mut elapsed: Local<Duration>,
+                      time: ReactorDelta,
+                      mut components: Query<&mut C>| {
+        let Ok(mut component) = components.get_mut(entity) else {
+            return true;
+        };
+        let value = lens(&mut component);
+        let start = start.get_or_insert_with(|| value.clone()).clone();
+        *elapsed += time.delta();
+        let t = if duration.is_zero() {
+            1.
+        } else {
+            elapsed.as_secs_f32() / duration.as_secs_f32()
+        };
+        *value = EasingCurve::new(start, target.clone(), ease).sample_clamped(t);
+        1. <= t
+    })
+}
@@ -0,0 +1,252 @@
+//! Provides [`TimelineHandle`], which controls the playback of the running interpolation from outside the reactor.
+
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use crate::reactor::time::ReactorDelta;
+use bevy::math::curve::{Curve, Ease, EaseFunction, EasingCurve};
+use bevy::prelude::{Component, Entity, In, Local, Query};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// The handle that pauses, reverses and scrubs the interpolation created by [`tween::timeline::component`](crate::prelude::tween::timeline::component).
+///
+/// The handle can be cloned and moved into normal systems, e.g. an editor slider, to control the playback.
+/// The action is completed only when the playback reaches the end normally,
+/// that is, seeking to the end or playing in reverse never completes it.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Scrubber(tween::timeline::TimelineHandle);
+///
+/// fn slider(scrubber: Res<Scrubber>, keys: Res<ButtonInput<KeyCode>>){
+///     if keys.just_pressed(KeyCode::Space){
+///         scrubber.0.pause();
+///         scrubber.0.seek(0.5);
+///     }
+/// }
+/// ```
+#[derive(Default, Clone)]
+pub struct TimelineHandle(Arc<RwLock<Playback>>);
+
+#[derive(Default)]
+struct Playback {
+    position: f32,
+    seek: Option<f32>,
+    paused: bool,
+    reversed: bool,
+}
+
+impl TimelineHandle {
+    /// Creates a new [`TimelineHandle`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pauses the playback.
+    #[inline]
+    pub fn pause(&self) {
+        self.write(|playback| playback.paused = true);
+    }
+
+    /// Resumes the playback paused by [`TimelineHandle::pause`].
+    #[inline]
+    pub fn resume(&self) {
+        self.write(|playback| playback.paused = false);
+    }
+
+    /// Returns true if the playback is paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.read(|playback| playback.paused)
+    }
+
+    /// Reverses the direction of the playback.
+    #[inline]
+    pub fn reverse(&self) {
+        self.write(|playback| playback.reversed = !playback.reversed);
+    }
+
+    /// Returns true if the playback is reversed.
+    #[inline]
+    pub fn is_reversed(&self) -> bool {
+        self.read(|playback| playback.reversed)
+    }
+
+    /// Moves the playback position to `position` in the range `0.0..=1.0`.
+    ///
+    /// The position is applied the next time the interpolation runs.
+    #[inline]
+    pub fn seek(&self, position: f32) {
+        self.write(|playback| playback.seek = Some(position.clamp(0., 1.)));
+    }
+
+    /// Returns the current playback position in the range `0.0..=1.0`.
+    #[inline]
+    pub fn position(&self) -> f32 {
+        self.read(|playback| playback.seek.unwrap_or(playback.position))
+    }
+
+    fn read<O>(&self, f: impl FnOnce(&Playback) -> O) -> O {
+        f(&self.0.read().expect("Failed to read the timeline"))
+    }
+
+    fn write(&self, f: impl FnOnce(&mut Playback)) {
+        f(&mut self.0.write().expect("Failed to write the timeline"));
+    }
+
+    /// Advances the playback by `delta`, and returns the position and whether it has reached the end normally.
+    fn advance(&self, delta: f32) -> (f32, bool) {
+        let mut playback = self.0.write().expect("Failed to write the timeline");
+        if let Some(position) = playback.seek.take() {
+            playback.position = position;
+            return (position, false);
+        }
+        if playback.paused {
+            return (playback.position, false);
+        }
+        let delta = if playback.reversed { -delta } else { delta };
+        playback.position = (playback.position + delta).clamp(0., 1.);
+        (playback.position, !playback.reversed && 1. <= playback.position)
+    }
+}
+
+/// Interpolates the value of the component attached to the passed [`Entity`] to `target` over `duration`,
+/// with the playback controlled by `handle`.
+///
+/// This is the same as [`tween::component`](crate::prelude::tween::component),
+/// except that the playback can be paused, reversed and scrubbed via [`TimelineHandle`].
+/// This action is completed only when the playback reaches the end normally.
+/// If the entity or the component does not exist, this action is completed immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     let handle = tween::timeline::TimelineHandle::new();
+///     task.will(Update, once::res::insert().with(Scrubber(handle.clone()))).await;
+///     task.will(Update, tween::timeline::component(
+///         handle,
+///         Vec3::X * 100.,
+///         Duration::from_secs(1),
+///         tween::EaseFunction::Linear,
+///         |transform: &mut Transform| &mut transform.translation,
+///     ).with(entity)).await;
+/// });
+///
+/// #[derive(Resource)]
+/// struct Scrubber(tween::timeline::TimelineHandle);
+/// ```
+pub fn component<C, T>(
+    handle: TimelineHandle,
+    target: T,
+    duration: Duration,
+    ease: EaseFunction,
+    lens: fn(&mut C) -> &mut T,
+) -> ActionSeed<Entity>
+where
+    C: Component,
+    T: Ease + Clone + Send + Sync + 'static,
+{
+    wait::until(move |In(entity): In<Entity>,
+                      mut start: Local<Option<T>>,
+                      time: ReactorDelta,
+                      mut components: Query<&mut C>| {
+        let Ok(mut component) = components.get_mut(entity) else {
+            return true;
+        };
+        let value = lens(&mut component);
+        let start = start.get_or_insert_with(|| value.clone()).clone();
+        let delta = if duration.is_zero() {
+            1.
+        } else {
+            time.delta().as_secs_f32() / duration.as_secs_f32()
+        };
+        let (t, reached_end) = handle.advance(delta);
+        *value = EasingCurve::new(start, target.clone(), ease).sample_clamped(t);
+        reached_end
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, tween};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut, Resource, Transform, Vec3};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Resource)]
+    struct Scrubber(tween::timeline::TimelineHandle);
+
+    fn translation_x(app: &mut bevy::app::App) -> f32 {
+        app.world_mut().query::<&Transform>().single(app.world()).translation.x
+    }
+
+    #[test]
+    fn complete_only_when_reaching_end_normally() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        let handle = tween::timeline::TimelineHandle::new();
+        app.insert_resource(Scrubber(handle.clone()));
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let entity = commands.spawn(Transform::default()).id();
+            let handle = handle.clone();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, tween::timeline::component(
+                    handle,
+                    Vec3::X * 10.,
+                    Duration::from_secs(1),
+                    tween::EaseFunction::Linear,
+                    |transform: &mut Transform| &mut transform.translation,
+                )
+                    .with(entity)
+                    .then(once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+
+        let handle = app.world().resource::<Scrubber>().0.clone();
+        handle.pause();
+        handle.seek(1.);
+        app.update();
+        assert_eq!(translation_x(&mut app), 10.);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        handle.seek(0.5);
+        handle.reverse();
+        handle.resume();
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(translation_x(&mut app), 0.);
+        app.assert_resource_eq(Count(0));
+
+        handle.reverse();
+        for _ in 0..12 {
+            app.update();
+        }
+        assert_eq!(translation_x(&mut app), 10.);
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,142 @@
+//! Provides the actions that interpolate [`Transform`].
+
+use crate::action::tween;
+use crate::prelude::ActionSeed;
+use bevy::math::curve::EaseFunction;
+use bevy::prelude::{Entity, Quat, Transform, Vec3};
+use std::time::Duration;
+
+/// Moves the [`Transform::translation`] of the passed [`Entity`] to `target` over `duration`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     task.will(Update, tween::transform::move_to(Vec3::X * 100., Duration::from_secs(1), tween::EaseFunction::CubicInOut)
+///         .with(entity)
+///     ).await;
+/// });
+/// ```
+#[inline]
+pub fn move_to(target: Vec3, duration: Duration, ease: EaseFunction) -> ActionSeed<Entity> {
+    tween::component(target, duration, ease, |transform: &mut Transform| &mut transform.translation)
+}
+
+/// Scales the [`Transform::scale`] of the passed [`Entity`] to `target` over `duration`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     task.will(Update, tween::transform::scale_to(Vec3::splat(2.), Duration::from_secs(1), tween::EaseFunction::BackOut)
+///         .with(entity)
+///     ).await;
+/// });
+/// ```
+#[inline]
+pub fn scale_to(target: Vec3, duration: Duration, ease: EaseFunction) -> ActionSeed<Entity> {
+    tween::component(target, duration, ease, |transform: &mut Transform| &mut transform.scale)
+}
+
+/// Rotates the [`Transform::rotation`] of the passed [`Entity`] to `target` over `duration`.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     task.will(Update, tween::transform::rotate_to(Quat::from_rotation_z(std::f32::consts::PI), Duration::from_secs(1), tween::EaseFunction::Linear)
+///         .with(entity)
+///     ).await;
+/// });
+/// ```
+#[inline]
+pub fn rotate_to(target: Quat, duration: Duration, ease: EaseFunction) -> ActionSeed<Entity> {
+    tween::component(target, duration, ease, |transform: &mut Transform| &mut transform.rotation)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, tween};
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Quat, ResMut, Transform, Vec3};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    fn transform(app: &mut bevy::app::App) -> Transform {
+        *app.world_mut().query::<&Transform>().single(app.world())
+    }
+
+    #[test]
+    fn move_to_target() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(|mut commands: Commands| commands.spawn(Transform::default()).id())
+                        .pipe(tween::transform::move_to(Vec3::X * 10., Duration::from_secs(1), tween::EaseFunction::Linear))
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        let x = transform(&mut app).translation.x;
+        assert!(0. < x && x < 10.);
+        app.assert_resource_eq(Count(0));
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(transform(&mut app).translation, Vec3::X * 10.);
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn complete_immediately_if_duration_is_zero() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            let entity = commands.spawn(Transform::default()).id();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, {
+                    tween::transform::scale_to(Vec3::splat(2.), Duration::ZERO, tween::EaseFunction::Linear)
+                        .with(entity)
+                        .then(tween::transform::rotate_to(Quat::from_rotation_z(1.), Duration::ZERO, tween::EaseFunction::Linear).with(entity))
+                }).await;
+            }));
+        });
+        app.update();
+        app.update();
+        let transform = transform(&mut app);
+        assert_eq!(transform.scale, Vec3::splat(2.));
+        assert!(transform.rotation.abs_diff_eq(Quat::from_rotation_z(1.), 1e-5));
+    }
+}
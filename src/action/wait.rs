@@ -2,12 +2,19 @@
 
 use crate::action::seed::ActionSeed;
 use crate::prelude::{wait, RunnerIs};
-use crate::runner::{CancellationHandlers, Output, Runner};
+use crate::runner::{system_access, CancellationHandlers, Output, Runner};
 pub use _any::any;
 pub use _both::both;
 pub use _either::*;
-pub use all::{all, private};
-use bevy::prelude::{In, IntoSystem, System, SystemIn, SystemInput, World};
+pub use all::{all, all_outputs, private};
+pub use join::{join3, join4};
+pub use listener::listener;
+pub use race::race;
+pub use sub_reactor::sub_reactor;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::prelude::{Condition, In, IntoSystem, Local, Res, System, SystemIn, SystemInput, Time, World};
+use std::time::Duration;
 
 #[path = "wait/any.rs"]
 mod _any;
@@ -16,19 +23,64 @@ mod _both;
 #[path = "wait/either.rs"]
 mod _either;
 mod all;
+mod join;
+mod listener;
+mod race;
+mod sub_reactor;
+#[cfg(feature = "animation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
+pub mod animation;
+pub mod app;
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod asset;
 #[cfg(feature = "audio")]
 #[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
 pub mod audio;
+pub mod component;
+#[cfg(feature = "cutscene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cutscene")))]
+pub mod dialogue;
+pub mod entity;
 pub mod event;
+#[cfg(all(feature = "notify", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "notify")))]
+pub mod fs;
 pub mod input;
+#[cfg(feature = "input")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+pub mod logical;
+pub mod mark;
+#[cfg(feature = "menu")]
+#[cfg_attr(docsrs, doc(cfg(feature = "menu")))]
+pub mod menu;
+#[cfg(all(feature = "signal", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "signal")))]
+pub mod os;
+pub mod query;
+pub mod reactor;
+#[cfg(feature = "render")]
+#[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+pub mod render;
+pub mod res;
+#[cfg(feature = "scene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scene")))]
+pub mod scene;
 #[cfg(feature = "state")]
 #[cfg_attr(docsrs, doc(cfg(feature = "state")))]
 pub mod state;
 pub mod switch;
+pub mod tick;
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+pub mod ui;
 
 /// Run until it returns [`Option::Some`].
 /// The contents of Some will be return value of the task.
 ///
+/// The system is polled every frame, and the action is completed in the same frame the system returns `Some`,
+/// so there is no gap between the condition being met and the value being fetched.
+///
 /// ## Examples
 ///
 /// ```
@@ -60,6 +112,9 @@ where
 
 /// Run until it returns true.
 ///
+/// If you need the value that you were waiting for, use [`wait::output`](crate::prelude::wait::output) instead
+/// rather than fetching it with another action, which would run in the next frame.
+///
 /// ## Examples
 ///
 /// ```
@@ -84,6 +139,75 @@ where
     wait::output(system.pipe(|In(finish): In<bool>| if finish { Some(()) } else { None }))
 }
 
+/// Run until the system has continuously returned true for the given `duration`.
+///
+/// The elapsed time is reset each time the system returns false,
+/// so this is useful for debouncing flaky conditions such as "network is idle" or "physics settled".
+/// The duration is measured by [`Time`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use core::time::Duration;
+///
+/// #[derive(Component)]
+/// struct Velocity(Vec3);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::stable(|velocities: Query<&Velocity>|{
+///         velocities.iter().all(|v| v.0.length() < 0.01)
+///     }, Duration::from_secs(1))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn stable<I, Sys, M>(system: Sys, duration: Duration) -> ActionSeed<I::Inner<'static>>
+where
+    Sys: IntoSystem<I, bool, M> + Send + Sync + 'static,
+    I: SystemInput + 'static,
+    I::Inner<'static>: Clone,
+{
+    wait::until(system.pipe(move |In(satisfied): In<bool>,
+                                  mut since: Local<Option<Duration>>,
+                                  time: Res<Time>| {
+        if !satisfied {
+            *since = None;
+            return false;
+        }
+        let now = time.elapsed();
+        let since = since.get_or_insert(now);
+        duration <= now - *since
+    }))
+}
+
+/// Run until the run condition returns true.
+///
+/// This is the same as [`wait::until`](crate::prelude::wait::until), but accepts the run conditions,
+/// so that the bevy's built-in conditions such as [`resource_changed`](bevy::prelude::resource_changed) and [`on_event`](bevy::prelude::on_event),
+/// and their combinations, can be reused as the actions.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Score(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::condition(resource_exists::<Score>.and(resource_changed::<Score>))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn condition<C, M>(condition: C) -> ActionSeed
+where
+    C: Condition<M> + Send + Sync + 'static,
+{
+    wait::until(condition)
+}
+
 struct WaitRunner<Sys, O>
 where
     Sys: System,
@@ -116,6 +240,11 @@ where
             RunnerIs::Running
         }
     }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.init.then(|| system_access(&self.system))
+    }
 }
 
 #[cfg(test)]
@@ -126,8 +255,36 @@ mod tests {
     use crate::tests::test_app;
     use bevy::app::{AppExit, PreUpdate, Startup};
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{Commands, EventWriter, In, Local, Update};
+    use bevy::prelude::{resource_exists, Commands, EventWriter, In, Local, Res, ResMut, Resource, Update};
+    use bevy::time::TimeUpdateStrategy;
     use bevy_test_helper::event::{TestEvent1, TestEvent2};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Resource)]
+    struct Settled(bool);
+
+    #[test]
+    fn wait_condition() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::condition(resource_exists::<Settled>)).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Settled(true));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
 
     #[test]
     fn count_up() {
@@ -244,4 +401,32 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
+
+    #[test]
+    fn stable_resets_on_false() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.insert_resource(Settled(true));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::stable(|settled: Res<Settled>| settled.0, Duration::from_millis(300))).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.world_mut().resource_mut::<Settled>().0 = false;
+        app.update();
+        app.world_mut().resource_mut::<Settled>().0 = true;
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
 }
@@ -1,11 +1,22 @@
 use crate::prelude::{ActionSeed, Output, Runner};
 use crate::runner::{BoxedRunner, CancellationHandlers, RunnerIs};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::{Access, AccessConflicts};
+use bevy::log::warn;
 use bevy::prelude::World;
 
 /// Wait until all the actions are completed.
 ///
+/// The actions run in the order they are passed each frame.
+/// If the actions accessing the same data, at least one of them mutably, run in the same frame,
+/// a warning is logged once since the result may depend on that order.
+/// See [`Runner::access`] for details.
+///
 /// The output value of this function is `()`.
-/// If you need the outputs, consider using [`wait_all!`](crate::wait_all) instead.
+/// If you need the outputs, consider using [`wait::all_outputs`](crate::prelude::wait::all_outputs)
+/// or [`wait_all!`](crate::wait_all) instead.
+/// For a fixed number of actions, [`wait::both`](crate::prelude::wait::both), [`wait::join3`](crate::prelude::wait::join3)
+/// and [`wait::join4`](crate::prelude::wait::join4) output them as a typed tuple without erasing them into a `Vec`.
 ///
 /// # Examples
 ///
@@ -33,19 +44,21 @@ where
             .map(|seed| seed.with(()).create_runner(Output::default()))
             .collect(),
         output,
+        conflicts: ConflictDetector::default(),
     })
 }
 
 struct AllRunner {
     output: Output<()>,
     runners: Vec<BoxedRunner>,
+    conflicts: ConflictDetector,
 }
 
 impl Runner for AllRunner {
     fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> crate::prelude::RunnerIs {
         let runners = std::mem::take(&mut self.runners);
         for mut runner in runners {
-            match runner.run(world, token) {
+            match self.conflicts.run(&mut runner, world, token) {
                 RunnerIs::Canceled => return RunnerIs::Canceled,
                 RunnerIs::Completed => {}
                 RunnerIs::Running => {
@@ -53,6 +66,7 @@ impl Runner for AllRunner {
                 }
             }
         }
+        self.conflicts.warn(world);
         if self.runners.is_empty() {
             self.output.set(());
             RunnerIs::Completed
@@ -62,12 +76,149 @@ impl Runner for AllRunner {
     }
 }
 
+/// Wait until all the actions are completed, and then collects their outputs.
+///
+/// All actions are run every frame, and the output value is a [`Vec`]
+/// containing the outputs in the same order as the passed actions.
+///
+/// If the actions have different output types, consider using [`wait_all!`](crate::wait_all) instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Ready(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let events: Vec<Ready> = task.will(Update, wait::all_outputs().with([
+///         wait::event::read::<Ready>(),
+///         wait::event::read::<Ready>(),
+///     ])).await;
+/// });
+/// ```
+pub fn all_outputs<Actions, O>() -> ActionSeed<Actions, Vec<O>>
+where
+    Actions: IntoIterator<Item=ActionSeed<(), O>> + 'static,
+    O: 'static,
+{
+    ActionSeed::new(|actions: Actions, output| {
+        let (runners, outputs): (Vec<_>, Vec<_>) = actions
+            .into_iter()
+            .map(|seed| {
+                let o = Output::default();
+                (Some(seed.with(()).create_runner(o.clone())), o)
+            })
+            .unzip();
+        AllOutputsRunner {
+            runners,
+            outputs,
+            output,
+            conflicts: ConflictDetector::default(),
+        }
+    })
+}
+
+struct AllOutputsRunner<O> {
+    runners: Vec<Option<BoxedRunner>>,
+    outputs: Vec<Output<O>>,
+    output: Output<Vec<O>>,
+    conflicts: ConflictDetector,
+}
+
+impl<O> Runner for AllOutputsRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        for slot in self.runners.iter_mut() {
+            let Some(runner) = slot.as_mut() else {
+                continue;
+            };
+            match self.conflicts.run(runner, world, token) {
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Completed => {
+                    slot.take();
+                }
+                RunnerIs::Running => {}
+            }
+        }
+        self.conflicts.warn(world);
+        if self.runners.iter().all(Option::is_none) {
+            self.output.set(self
+                .outputs
+                .iter()
+                .map(|o| o.take().expect("The output value has not been set!!!"))
+                .collect());
+            RunnerIs::Completed
+        } else {
+            RunnerIs::Running
+        }
+    }
+}
+
+/// Collects the access of the actions running in the same frame, and warns once if they conflict.
+#[derive(Default)]
+struct ConflictDetector {
+    accesses: Vec<Access<ComponentId>>,
+    warned: bool,
+}
+
+impl ConflictDetector {
+    fn run(&mut self, runner: &mut BoxedRunner, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if self.warned {
+            return runner.run(world, token);
+        }
+        let (status, access) = runner.run_with_access(world, token);
+        self.accesses.extend(access);
+        status
+    }
+
+    fn warn(&mut self, world: &World) {
+        let accesses = std::mem::take(&mut self.accesses);
+        if self.warned {
+            return;
+        }
+        let conflicts = conflicts(world, &accesses);
+        if !conflicts.is_empty() {
+            warn!(
+                "The actions running concurrently access [{}], and at least one of them mutably, so the result may depend on the order in which they run.",
+                conflicts.join(", ")
+            );
+            self.warned = true;
+        }
+    }
+}
+
+/// Returns the names of the data accessed by multiple actions, at least one of them mutably.
+fn conflicts(world: &World, accesses: &[Access<ComponentId>]) -> Vec<String> {
+    let mut names = Vec::new();
+    for (i, lhs) in accesses.iter().enumerate() {
+        for rhs in &accesses[i + 1..] {
+            match lhs.get_conflicts(rhs) {
+                AccessConflicts::All => names.push("World".to_string()),
+                AccessConflicts::Individual(ids) => names.extend(ids
+                    .ones()
+                    .filter_map(|id| world.components().get_name(ComponentId::new(id)))
+                    .map(|name| name.to_string())),
+            }
+        }
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
 /// Wait until all tasks done.
 ///
 /// The return value type is tuple, its length is equal to the number of as passed tasks.
 ///
 /// If you don't need the outputs of the actions or want to pass a collection of actions,
 /// consider using [`wait::all`](crate::prelude::wait::all()) instead.
+/// [`wait::join3`](crate::prelude::wait::join3) and [`wait::join4`](crate::prelude::wait::join4)
+/// are the function forms of this macro for three and four actions.
 ///
 /// ## Examples
 ///
@@ -212,7 +363,7 @@ pub mod private {
 mod tests {
     use crate::action::delay;
     use crate::actions;
-    use crate::prelude::{once, wait, Pipe, Then};
+    use crate::prelude::{once, wait, OmitInput, Pipe, Then};
     use crate::reactor::Reactor;
     use crate::tests::{decrement_count, exit_reader, increment_count, test_app};
     use bevy::app::{AppExit, Startup, Update};
@@ -222,6 +373,63 @@ mod tests {
     use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
+    #[test]
+    fn detect_conflicting_access() {
+        use crate::prelude::{ActionSeed, CancellationHandlers, Output};
+        use bevy::ecs::component::ComponentId;
+        use bevy::ecs::query::Access;
+        use bevy::prelude::{Res, ResMut, World};
+
+        fn accesses(world: &mut World, seeds: Vec<ActionSeed>) -> Vec<Access<ComponentId>> {
+            seeds
+                .into_iter()
+                .filter_map(|seed| {
+                    let mut runner = seed.with(()).create_runner(Output::default());
+                    runner.run_with_access(world, &mut CancellationHandlers::default()).1
+                })
+                .collect()
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Count>();
+        let read = accesses(&mut world, vec![
+            once::run(|_: Res<Count>| {}),
+            once::run(|_: Res<Count>| {}),
+        ]);
+        assert!(super::conflicts(&world, &read).is_empty());
+
+        let write = accesses(&mut world, vec![
+            once::run(|_: Res<Count>| {}),
+            once::run(|_: ResMut<Count>| {}),
+        ]);
+        assert_eq!(super::conflicts(&world, &write).len(), 1);
+        assert!(super::conflicts(&world, &write)[0].contains("Count"));
+    }
+
+    #[test]
+    #[cfg(feature = "budget")]
+    fn detect_conflicting_access_through_wrappers() {
+        use crate::budget::ActionTimeBudget;
+        use crate::prelude::{CancellationHandlers, Output};
+        use bevy::prelude::{Entity, Res, ResMut, World};
+        use std::time::Duration;
+
+        let mut world = World::new();
+        world.init_resource::<Count>();
+        world.insert_resource(ActionTimeBudget(Duration::from_secs(1)));
+        let accesses = [
+            once::run(|_: Res<Count>| {}),
+            once::run(|_: ResMut<Count>| {}),
+        ]
+            .into_iter()
+            .filter_map(|seed| {
+                let mut runner = crate::middleware::create_runner(&world, Entity::PLACEHOLDER, seed.with(()), Output::default());
+                runner.run_with_access(&mut world, &mut CancellationHandlers::default()).1
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(super::conflicts(&world, &accesses).len(), 1);
+    }
+
     #[test]
     fn wai_all_actions() {
         let mut app = test_app();
@@ -266,6 +474,28 @@ mod tests {
         app.assert_event_comes(&mut er);
     }
 
+    #[test]
+    fn collect_all_outputs() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let outputs = task.will(Update, wait::all_outputs().with([
+                    delay::frames().with(2).then(once::run(|| 1)).omit_input(),
+                    once::run(|| 2),
+                    delay::frames().with(1).then(once::run(|| 3)).omit_input(),
+                ])).await;
+                assert_eq!(outputs, vec![1, 2, 3]);
+                task.will(Update, once::non_send::insert().with(AppExit::Success)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert!(app.world().get_non_send_resource::<AppExit>().is_none());
+        app.update();
+        app.update();
+        assert!(app.world().get_non_send_resource::<AppExit>().is_some());
+    }
+
     #[test]
     fn wait_all() {
         let mut app = test_app();
@@ -0,0 +1,125 @@
+//! [`wait::animation`] creates a task related to waiting to [`AnimationPlayer`].
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::animation::AnimationPlayer;
+use bevy::prelude::{Entity, In, Query};
+
+/// Waits until all the animations played by the [`AnimationPlayer`] on the passed [`Entity`] have finished.
+///
+/// Animations that repeat forever never finish.
+/// If the entity has no [`AnimationPlayer`], or has already been despawned, this action is also completed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Hp(u32);
+///
+/// Reactor::schedule(|task| async move{
+///     let enemy = Entity::PLACEHOLDER;
+///     let attack = AnimationNodeIndex::new(1);
+///     task.will(Update, once::run(move |mut players: Query<&mut AnimationPlayer>|{
+///         players.get_mut(enemy).unwrap().start(attack);
+///     })).await;
+///     task.will(Update, wait::animation::finished().with(enemy)).await;
+///     task.will(Update, once::run(|mut hp: Query<&mut Hp>|{
+///         for mut hp in hp.iter_mut(){
+///             hp.0 = hp.0.saturating_sub(10);
+///         }
+///     })).await;
+/// });
+/// ```
+pub fn finished() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, players: Query<&AnimationPlayer>| {
+        players.get(entity).map_or(true, AnimationPlayer::all_finished)
+    })
+}
+
+/// Waits until any animation played by the [`AnimationPlayer`] on the passed [`Entity`] has reached `seconds`.
+///
+/// The time is measured by [`ActiveAnimation::seek_time`](bevy::animation::ActiveAnimation::seek_time),
+/// so it is reset each time the animation repeats.
+/// If the entity has no [`AnimationPlayer`], or has already been despawned, this action is also completed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let player = Entity::PLACEHOLDER;
+///     // Waits until the sword hits.
+///     task.will(Update, wait::animation::reached_time(0.4).with(player)).await;
+/// });
+/// ```
+pub fn reached_time(seconds: f32) -> ActionSeed<Entity> {
+    wait::until(move |In(entity): In<Entity>, players: Query<&AnimationPlayer>| {
+        players.get(entity).map_or(true, |player| {
+            player
+                .playing_animations()
+                .any(|(_, animation)| seconds <= animation.seek_time())
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::animation::graph::AnimationNodeIndex;
+    use bevy::animation::AnimationPlayer;
+    use bevy::app::Update;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_until_all_animations_finished() {
+        let mut app = test_app();
+        let mut player = AnimationPlayer::default();
+        player.play(AnimationNodeIndex::new(1));
+        let entity = app.world_mut().spawn(player).id();
+        app.world_mut().spawn(Reactor::schedule(move |task| async move {
+            task.will(Update, wait::animation::finished().with(entity).then(increment_count())).await;
+        }));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().get_mut::<AnimationPlayer>(entity).unwrap().stop_all();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_reached_time() {
+        let mut app = test_app();
+        let mut player = AnimationPlayer::default();
+        player.play(AnimationNodeIndex::new(1));
+        let entity = app.world_mut().spawn(player).id();
+        app.world_mut().spawn(Reactor::schedule(move |task| async move {
+            task.will(Update, wait::animation::reached_time(0.5).with(entity).then(increment_count())).await;
+        }));
+        app.update();
+        app.world_mut()
+            .get_mut::<AnimationPlayer>(entity)
+            .unwrap()
+            .play(AnimationNodeIndex::new(1))
+            .seek_to(0.3);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut()
+            .get_mut::<AnimationPlayer>(entity)
+            .unwrap()
+            .play(AnimationNodeIndex::new(1))
+            .seek_to(0.5);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,116 @@
+//! [`wait::app`] creates a task related to waiting for the app to be usable.
+//!
+//! The plugins that complete their setup asynchronously, such as compiling pipelines or connecting to servers,
+//! can hold [`AppReadiness`] until they are ready, so that boot reactors don't start work before the app is actually usable.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::prelude::{Res, Resource};
+use bevy::utils::HashSet;
+use std::borrow::Cow;
+
+/// The resource that tracks the setups holding the app from being ready.
+///
+/// This is initialized by [`FlurxPlugin`](crate::prelude::FlurxPlugin).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn start_connecting(mut readiness: ResMut<AppReadiness>){
+///     readiness.hold("server_connection");
+/// }
+///
+/// fn on_connected(mut readiness: ResMut<AppReadiness>){
+///     readiness.release("server_connection");
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct AppReadiness(HashSet<Cow<'static, str>>);
+
+impl AppReadiness {
+    /// Holds the app from being ready until [`AppReadiness::release`] is called with the same name.
+    #[inline]
+    pub fn hold(&mut self, name: impl Into<Cow<'static, str>>) {
+        self.0.insert(name.into());
+    }
+
+    /// Releases the hold added by [`AppReadiness::hold`].
+    #[inline]
+    pub fn release(&mut self, name: &str) {
+        self.0.remove(name);
+    }
+
+    /// Returns the names of the setups holding the app.
+    #[inline]
+    pub fn holds(&self) -> impl Iterator<Item=&str> {
+        self.0.iter().map(|name| name.as_ref())
+    }
+
+    /// Returns whether no setup is holding the app.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Waits until the app is ready to use.
+///
+/// The schedules run after all plugins have finished building,
+/// including the plugins whose [`Plugin::ready`](bevy::app::Plugin::ready) waits for asynchronous initialization,
+/// so this action waits until all holds of [`AppReadiness`] are released.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::app::ready()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn ready() -> ActionSeed {
+    wait::until(|readiness: Option<Res<AppReadiness>>| {
+        readiness.is_none_or(|readiness| readiness.is_ready())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{AppReadiness, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_until_all_holds_released() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands, mut readiness: ResMut<AppReadiness>| {
+            readiness.hold("a");
+            readiness.hold("b");
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::app::ready().then(once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.resource_mut::<AppReadiness>().release("a");
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.resource_mut::<AppReadiness>().release("b");
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,229 @@
+//! [`wait::asset`] creates a task related to waiting to load [`Asset`].
+
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use bevy::asset::{Asset, AssetEvent, AssetLoadError, AssetServer, Assets, Handle, LoadState};
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::{Events, In, Local, Res};
+use std::sync::Arc;
+
+/// Waits until the asset of the passed [`Handle`] has been loaded.
+///
+/// The output value is the passed [`Handle`], or the error if failed to load the asset.
+/// The assets not loaded by [`AssetServer`], such as ones added by [`Assets::add`], are treated as loaded if they exist.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, {
+///         once::asset::load::<Image, _>().with("texture.png")
+///             .pipe(wait::asset::loaded())
+///     }).await;
+///     if let Err(e) = result {
+///         error!("{e}");
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn loaded<A>() -> ActionSeed<Handle<A>, Result<Handle<A>, Arc<AssetLoadError>>>
+where
+    A: Asset,
+{
+    wait::output(|In(handle): In<Handle<A>>, asset_server: Res<AssetServer>, assets: Res<Assets<A>>| {
+        load_result(&asset_server, &assets, &handle).map(|result| result.map(|_| handle))
+    })
+}
+
+/// Waits until all assets of the passed [`Handle`]s have been loaded.
+///
+/// The output value is the passed [`Handle`]s, or the first error found if failed to load any of the assets.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let result = task.will(Update, {
+///         once::run(|asset_server: Res<AssetServer>| {
+///             vec![
+///                 asset_server.load::<Image>("texture1.png"),
+///                 asset_server.load::<Image>("texture2.png"),
+///             ]
+///         })
+///             .pipe(wait::asset::all_loaded())
+///     }).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn all_loaded<A>() -> ActionSeed<Vec<Handle<A>>, Result<Vec<Handle<A>>, Arc<AssetLoadError>>>
+where
+    A: Asset,
+{
+    wait::output(|In(handles): In<Vec<Handle<A>>>, asset_server: Res<AssetServer>, assets: Res<Assets<A>>| {
+        let mut loaded = true;
+        for handle in handles.iter() {
+            match load_result(&asset_server, &assets, handle) {
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Some(Err(e)),
+                None => loaded = false,
+            }
+        }
+        loaded.then_some(Ok(handles))
+    })
+}
+
+/// Waits until the content of the asset of the passed [`Handle`] is modified.
+///
+/// This is completed when the asset is mutated via [`Assets::get_mut`] as well as when it is reloaded from disk,
+/// and only the modifications made after this action has started are observed.
+/// The output value is the passed [`Handle`], so the actions that recompute something from the asset can be piped.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct EditingMaterial(Handle<StandardMaterial>);
+///
+/// Reactor::schedule(|task| async move{
+///     loop{
+///         task.will(Update, {
+///             once::run(|material: Res<EditingMaterial>| material.0.clone())
+///                 .pipe(wait::asset::changed())
+///                 .pipe(once::run(|In(handle): In<Handle<StandardMaterial>>|{
+///                     info!("{handle:?} was edited");
+///                 }))
+///         }).await;
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn changed<A>() -> ActionSeed<Handle<A>, Handle<A>>
+where
+    A: Asset,
+{
+    wait::output(|In(handle): In<Handle<A>>,
+                  mut cursor: Local<Option<EventCursor<AssetEvent<A>>>>,
+                  events: Res<Events<AssetEvent<A>>>| {
+        let cursor = cursor.get_or_insert_with(|| events.get_cursor_current());
+        cursor
+            .read(&events)
+            .any(|event| event.is_modified(&handle))
+            .then_some(handle)
+    })
+}
+
+fn load_result<A: Asset>(
+    asset_server: &AssetServer,
+    assets: &Assets<A>,
+    handle: &Handle<A>,
+) -> Option<Result<(), Arc<AssetLoadError>>> {
+    match asset_server.get_load_state(handle) {
+        Some(LoadState::Loaded) => Some(Ok(())),
+        Some(LoadState::Failed(e)) => Some(Err(e)),
+        Some(_) => None,
+        None => assets.contains(handle).then_some(Ok(())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{App, Startup, Update};
+    use bevy::asset::{Asset, AssetApp, AssetPlugin, Assets, Handle};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, In, ResMut, TypePath};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[derive(Asset, TypePath)]
+    struct TestAsset;
+
+    fn asset_app() -> App {
+        let mut app = test_app();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()));
+        app.init_asset::<TestAsset>();
+        app
+    }
+
+    #[test]
+    fn loaded_if_added_to_assets() {
+        let mut app = asset_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(|mut assets: ResMut<Assets<TestAsset>>| vec![assets.add(TestAsset), assets.add(TestAsset)])
+                        .pipe(wait::asset::all_loaded())
+                        .pipe(once::run(|In(result): In<Result<Vec<Handle<TestAsset>>, _>>, mut count: ResMut<Count>| {
+                            count.0 = result.unwrap().len();
+                        }))
+                }).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn output_err_if_failed() {
+        let mut app = asset_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::asset::load::<TestAsset, _>().with("not_exists.flurx")
+                        .pipe(wait::asset::loaded())
+                        .pipe(once::run(|In(result): In<Result<Handle<TestAsset>, _>>, mut count: ResMut<Count>| {
+                            assert!(result.is_err());
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..100 {
+            app.update();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn changed_if_mutated() {
+        let mut app = asset_app();
+        app.add_systems(Startup, |mut commands: Commands, mut assets: ResMut<Assets<TestAsset>>| {
+            let handle = assets.add(TestAsset);
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::asset::changed().with(handle)
+                        .pipe(once::run(|In(_): In<Handle<TestAsset>>, mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut().run_system_once(|mut assets: ResMut<Assets<TestAsset>>| {
+            let id = assets.ids().next().unwrap();
+            assets.get_mut(id);
+        }).expect("Failed to run system");
+        for _ in 0..2 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+}
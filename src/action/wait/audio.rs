@@ -7,6 +7,10 @@ use crate::prelude::seed::ActionSeed;
 
 /// Waits until the audio associated with the passed [`Entity`] has finished playing.
 ///
+/// The entity is despawned after the audio has finished.
+/// If the entity has already been despawned (e.g. by [`PlaybackSettings::DESPAWN`](bevy::audio::PlaybackSettings::DESPAWN)),
+/// this action is also completed.
+///
 /// ## Examples
 ///
 /// ```no_run
@@ -23,11 +27,11 @@ use crate::prelude::seed::ActionSeed;
 /// ```
 pub fn finished() -> ActionSeed<Entity, ()> {
     wait::until(
-        |In(entity): In<Entity>, mut commands: Commands, audio: Query<(Entity, &AudioSink)>| {
-            let Ok((entity, audio)) = audio.get(entity) else {
-                return false;
+        |In(entity): In<Entity>, mut commands: Commands, audio: Query<Option<&AudioSink>>| {
+            let Ok(sink) = audio.get(entity) else {
+                return true;
             };
-            if audio.empty() {
+            if sink.is_some_and(|sink| sink.empty()) {
                 commands.entity(entity).despawn();
                 true
             } else {
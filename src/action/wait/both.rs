@@ -25,8 +25,8 @@ pub fn both<LI, LO, RI, RO>(
     rhs: impl Into<Action<RI, RO>> + 'static,
 ) -> Action<(LI, RI), (LO, RO)>
     where
-        RI: Clone + 'static,
-        LI: Clone + 'static,
+        RI: 'static,
+        LI: 'static,
         LO: Send + 'static,
         RO: Send + 'static,
 {
@@ -0,0 +1,70 @@
+//! [`wait::component`] creates a task related to waiting for components to be added.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::prelude::{Added, Component, Entity, Local, Query};
+
+/// Waits until the component `C` is added to any entity after this action started, and returns the entity.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// Reactor::schedule(|task| async move{
+///     let player: Entity = task.will(Update, wait::component::added::<Player>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn added<C>() -> ActionSeed<(), Entity>
+where
+    C: Component,
+{
+    wait::output(|mut started: Local<bool>, components: Query<Entity, Added<C>>| {
+        if !*started {
+            *started = true;
+            return None;
+        }
+        components.iter().next()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Entity, In, Resource};
+
+    #[derive(Component)]
+    struct Player;
+
+    #[derive(Resource, Debug, Eq, PartialEq)]
+    struct Found(Entity);
+
+    #[test]
+    fn ignore_components_added_before_start() {
+        let mut app = test_app();
+        app.world_mut().spawn(Player);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::component::added::<Player>()
+                    .pipe(once::run(|In(entity): In<Entity>, mut commands: Commands| {
+                        commands.insert_resource(Found(entity));
+                    }))).await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert!(app.world().get_resource::<Found>().is_none());
+
+        let player = app.world_mut().spawn(Player).id();
+        app.update();
+        assert_eq!(app.world().resource::<Found>(), &Found(player));
+    }
+}
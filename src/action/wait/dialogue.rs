@@ -0,0 +1,71 @@
+//! [`wait::dialogue`] creates a task related to showing the dialogue text via [`CutsceneDialog`].
+
+use crate::action::once::locale::{localize, Localization};
+use crate::action::wait;
+use crate::prelude::{ActionSeed, CutsceneDialog};
+use bevy::prelude::{In, Local, World};
+use std::borrow::Cow;
+
+/// Shows the passed text via [`CutsceneDialog`], and waits until it is dismissed.
+///
+/// If [`Localization`] exists, the text is treated as a key and resolved when this action starts.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::dialogue::say().with("greeting")).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn say<K>() -> ActionSeed<K>
+where
+    K: Into<Cow<'static, str>> + Clone + Send + Sync + 'static,
+{
+    wait::until(|In(key): In<K>, world: &mut World, mut said: Local<bool>| {
+        if !*said {
+            *said = true;
+            let text = localize(world.get_resource::<Localization>(), key.into());
+            world.insert_resource(CutsceneDialog(Some(text)));
+            return false;
+        }
+        world.get_resource::<CutsceneDialog>().is_none_or(|dialog| dialog.text().is_none())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{CutsceneDialog, Localization, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, World};
+    use bevy::utils::HashMap;
+    use bevy_test_helper::resource::bool::BoolExtension;
+
+    #[test]
+    fn say_localized_text_until_dismissed() {
+        let mut app = test_app();
+        app.insert_resource(Localization::new(HashMap::from_iter([("greeting", "こんにちは")])));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::dialogue::say().with("greeting")).await;
+                task.will(Update, once::run(|world: &mut World| {
+                    world.set_bool(true);
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<CutsceneDialog>().text(), Some("こんにちは"));
+        assert!(app.is_bool_false());
+
+        app.world_mut().resource_mut::<CutsceneDialog>().dismiss();
+        app.update();
+        app.update();
+        assert!(app.is_bool_true());
+    }
+}
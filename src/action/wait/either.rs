@@ -76,6 +76,42 @@ where
         .with((li, ri))
 }
 
+/// Waits until either of the two triggers fires, and outputs the output of the fired one.
+///
+/// This is a convenience of [`wait::either`](crate::prelude::wait::either) for the actions without inputs,
+/// which is common when a subsystem may signal either via an event or by setting a flag resource depending on the code path.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Loaded;
+///
+/// #[derive(Resource)]
+/// struct LoadedFlag;
+///
+/// Reactor::schedule(|task| async move{
+///     let trigger: Either<Loaded, ()> = task.will(Update, wait::either_trigger(
+///         wait::event::read::<Loaded>(),
+///         wait::until(|flag: Option<Res<LoadedFlag>>| flag.is_some()),
+///     )).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn either_trigger<L, R>(
+    lhs: ActionSeed<(), L>,
+    rhs: ActionSeed<(), R>,
+) -> ActionSeed<(), Either<L, R>>
+where
+    L: 'static,
+    R: 'static,
+{
+    ActionSeed::new(move |_, output| either(lhs, rhs).create_runner(output))
+}
+
 struct EitherRunner<O1, O2> {
     r1: BoxedRunner,
     r2: BoxedRunner,
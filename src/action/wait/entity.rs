@@ -0,0 +1,121 @@
+//! [`wait::entity`] creates a task related to waiting for the state of [`Entity`].
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::{Component, DetectChanges, Entity, In, Local, Query, Ref};
+
+/// Waits until the component `C` of the passed [`Entity`] is changed after this action started.
+///
+/// The component being added is also treated as a change.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Transform::default()).id()
+///     })).await;
+///     task.will(Update, wait::entity::component_changed::<Transform>().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn component_changed<C>() -> ActionSeed<Entity>
+where
+    C: Component,
+{
+    wait::until(|In(entity): In<Entity>, mut started: Local<bool>, components: Query<Ref<C>>| {
+        if !*started {
+            *started = true;
+            return false;
+        }
+        components
+            .get(entity)
+            .is_ok_and(|component| component.is_changed())
+    })
+}
+
+/// Waits until the passed [`Entity`] is despawned.
+///
+/// If the entity has already been despawned, this action completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let entity = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, wait::entity::despawned().with(entity)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn despawned() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, entities: &Entities| {
+        !entities.contains(entity)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Entity, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component)]
+    struct Hp(usize);
+
+    fn spawn_reactor(app: &mut bevy::app::App, action: fn(Entity) -> crate::prelude::Action<Entity>) {
+        let entity = app.world_mut().spawn(Hp(10)).id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, action(entity).then(once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                }))).await;
+            }));
+        });
+    }
+
+    fn hp(app: &mut bevy::app::App) -> Entity {
+        let world = app.world_mut();
+        world.query_filtered::<Entity, With<Hp>>().single(world)
+    }
+
+    #[test]
+    fn wait_component_changed() {
+        let mut app = test_app();
+        spawn_reactor(&mut app, |entity| wait::entity::component_changed::<Hp>().with(entity));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        let entity = hp(&mut app);
+        app.world_mut().get_mut::<Hp>(entity).unwrap().0 = 5;
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_despawned() {
+        let mut app = test_app();
+        spawn_reactor(&mut app, |entity| wait::entity::despawned().with(entity));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        let entity = hp(&mut app);
+        app.world_mut().despawn(entity);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
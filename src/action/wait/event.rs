@@ -1,9 +1,35 @@
 //! [`wait::event`] creates a task related to waiting to receive events.
 
 use crate::prelude::seed::ActionSeed;
-use crate::prelude::wait;
+use crate::prelude::{wait, Either};
 use bevy::ecs::event::EventCursor;
-use bevy::prelude::{Event, Events, Local, ResMut};
+use bevy::prelude::{Entity, Event, Events, In, Local, Res, ResMut, Resource};
+
+/// The event that targets an entity, used by [`wait::event::for_entity`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::action::wait::event::EntityEvent;
+///
+/// #[derive(Event, Clone)]
+/// struct Damaged {
+///     target: Entity,
+///     amount: usize,
+/// }
+///
+/// impl EntityEvent for Damaged {
+///     fn target(&self) -> Entity {
+///         self.target
+///     }
+/// }
+/// ```
+pub trait EntityEvent: Event {
+    /// Returns the entity targeted by this event.
+    fn target(&self) -> Entity;
+}
 
 /// Waits until the specified event is sent
 ///
@@ -84,14 +110,244 @@ where
     )
 }
 
+/// Waits until the specified event has been sent `n` times.
+///
+/// The events sent in the same frame are counted individually, and consumed same as [`wait::event::comes`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event)]
+/// struct PlayerReady;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::event::count::<PlayerReady>(4)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn count<E>(n: usize) -> ActionSeed
+where
+    E: Event,
+{
+    wait::until(
+        move |mut observed: Local<usize>,
+              mut er: Local<Option<EventCursor<E>>>,
+              mut events: ResMut<Events<E>>| {
+            if er.is_none() {
+                *observed += events.iter_current_update_events().count();
+            }
+            let er = er.get_or_insert_with(|| events.get_cursor_current());
+            *observed += er.read(&events).count();
+            events.clear();
+            n <= *observed
+        },
+    )
+}
+
+/// Waits until the specified event has been sent `n` times, and returns the collected events.
+///
+/// This is similar to [`wait::event::count`], except that it returns the events in the order they were sent.
+/// If more than `n` events are sent in the last frame, the extra events are discarded.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct PlayerReady(usize);
+///
+/// Reactor::schedule(|task| async move{
+///     let players: Vec<PlayerReady> = task.will(Update, wait::event::collect::<PlayerReady>(4)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn collect<E>(n: usize) -> ActionSeed<(), Vec<E>>
+where
+    E: Event + Clone,
+{
+    wait::output(
+        move |mut collected: Local<Vec<E>>,
+              mut er: Local<Option<EventCursor<E>>>,
+              mut events: ResMut<Events<E>>| {
+            if er.is_none() {
+                collected.extend(events.iter_current_update_events().cloned());
+            }
+            let er = er.get_or_insert_with(|| events.get_cursor_current());
+            collected.extend(er.read(&events).cloned());
+            events.clear();
+            if n <= collected.len() {
+                let mut collected = std::mem::take(&mut *collected);
+                collected.truncate(n);
+                Some(collected)
+            } else {
+                None
+            }
+        },
+    )
+}
+
+/// Waits until the event `B` is sent after the event `A` has been observed,
+/// and returns both events.
+///
+/// This is useful for protocols such as "request sent → ack received",
+/// where awaiting the two events separately may miss `B` or observe it before `A`.
+///
+/// `B` events sent before `A` has been observed, including those in the same frame as `A`, are ignored.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Request;
+///
+/// #[derive(Event, Clone)]
+/// struct Ack;
+///
+/// Reactor::schedule(|task| async move{
+///     let (_request, _ack): (Request, Ack) = task.will(Update, wait::event::sequence::<Request, Ack>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn sequence<A, B>() -> ActionSeed<(), (A, B)>
+where
+    A: Event + Clone,
+    B: Event + Clone,
+{
+    wait::output(
+        |mut observed: Local<Option<A>>,
+         mut er_a: Local<Option<EventCursor<A>>>,
+         mut er_b: Local<Option<EventCursor<B>>>,
+         mut events_a: ResMut<Events<A>>,
+         mut events_b: ResMut<Events<B>>| {
+            if observed.is_none() {
+                let a = if er_a.is_none() {
+                    events_a.iter_current_update_events().last().cloned()
+                } else {
+                    None
+                };
+                let er_a = er_a.get_or_insert_with(|| events_a.get_cursor_current());
+                let a = a.or_else(|| er_a.read(&events_a).last().cloned())?;
+                events_a.clear();
+                observed.replace(a);
+                er_b.replace(events_b.get_cursor_current());
+                return None;
+            }
+            let b = er_b.as_mut()?.read(&events_b).last().cloned()?;
+            events_b.clear();
+            Some((observed.take()?, b))
+        },
+    )
+}
+
+/// Waits until the event `E` targeting the passed [`Entity`] is sent, and returns the event.
+///
+/// If multiple events targeting the entity are sent in the same frame, the first one is returned.
+/// Unlike [`wait::event::read`], the events are not consumed,
+/// so that the other reactors can wait for the events targeting the other entities.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::action::wait::event::EntityEvent;
+///
+/// #[derive(Event, Clone)]
+/// struct Damaged(Entity);
+///
+/// impl EntityEvent for Damaged {
+///     fn target(&self) -> Entity {
+///         self.0
+///     }
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     let enemy = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, wait::event::for_entity::<Damaged>().with(enemy)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn for_entity<E>() -> ActionSeed<Entity, E>
+where
+    E: EntityEvent + Clone,
+{
+    wait::output(
+        |In(entity): In<Entity>, mut er: Local<Option<EventCursor<E>>>, events: Res<Events<E>>| {
+            if er.is_none() {
+                let event = events
+                    .iter_current_update_events()
+                    .find(|event| event.target() == entity)
+                    .cloned();
+                er.replace(events.get_cursor_current());
+                if event.is_some() {
+                    return event;
+                }
+            }
+            er
+                .as_mut()?
+                .read(&events)
+                .find(|event| event.target() == entity)
+                .cloned()
+        },
+    )
+}
+
+/// Waits until either the event `E` is sent or the resource `R` is set, and outputs whichever comes first.
+///
+/// This is useful when a subsystem may signal via an event or by setting a flag resource depending on the code path.
+/// If the resource already exists when this action starts, it completes immediately with [`Either::Right`](crate::prelude::Either::Right).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Event, Clone)]
+/// struct Loaded;
+///
+/// #[derive(Resource, Clone)]
+/// struct LoadedFlag;
+///
+/// Reactor::schedule(|task| async move{
+///     match task.will(Update, wait::event::comes_or_resource_set::<Loaded, LoadedFlag>()).await {
+///         Either::Left(_event) => {}
+///         Either::Right(_flag) => {}
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn comes_or_resource_set<E, R>() -> ActionSeed<(), Either<E, R>>
+where
+    E: Event + Clone,
+    R: Resource + Clone,
+{
+    wait::either_trigger(
+        read::<E>(),
+        wait::res::acquire::<R>(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::action::wait::event::EntityEvent;
     use crate::action::{once, wait};
     use crate::prelude::{Either, Pipe, Reactor, Then};
     use crate::tests::test_app;
     use bevy::app::{Startup, Update};
-    use bevy::prelude::{Commands, EventWriter, Events, In};
+    use bevy::prelude::{Commands, Entity, Event, EventWriter, Events, In, ResMut};
     use bevy_test_helper::event::{DirectEvents, TestEvent1, TestEvent2};
+    use bevy_test_helper::resource::count::Count;
     use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
@@ -128,6 +384,30 @@ mod tests {
         app.assert_event_comes(&mut er);
     }
 
+    #[test]
+    fn collect_events_until_count_reached() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let events = task.will(Update, wait::event::collect::<TestEvent1>(3)).await;
+                task.will(Update, once::run(move |mut count: ResMut<Count>| {
+                    count.0 = events.len();
+                })).await;
+            }));
+        });
+        app.update();
+        app.send(TestEvent1);
+        app.send(TestEvent1);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.send(TestEvent1);
+        app.send(TestEvent1);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
     #[test]
     fn wait_read_event_consumed_events() {
         let mut app = test_app();
@@ -162,4 +442,127 @@ mod tests {
         let mut er = app.resource_mut::<Events<TestEvent2>>().get_cursor();
         app.assert_event_comes(&mut er);
     }
+
+    #[derive(Event, Clone, Debug, Eq, PartialEq)]
+    struct Hit(Entity);
+
+    impl EntityEvent for Hit {
+        fn target(&self) -> Entity {
+            self.0
+        }
+    }
+
+    #[test]
+    fn for_entity_ignore_other_targets() {
+        let mut app = test_app();
+        app.add_event::<Hit>();
+        let target = app.world_mut().spawn_empty().id();
+        let other = app.world_mut().spawn_empty().id();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, {
+                    wait::event::for_entity::<Hit>()
+                        .with(target)
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        app.update();
+        app.send(Hit(other));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.send(Hit(target));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    fn spawn_sequence_reactor(app: &mut bevy::app::App) {
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::event::sequence::<TestEvent1, TestEvent2>()
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                })
+                    .await;
+            }));
+        });
+    }
+
+    #[test]
+    fn sequence_a_then_b() {
+        let mut app = test_app();
+        spawn_sequence_reactor(&mut app);
+        app.update();
+        app.send(TestEvent1);
+        app.update();
+        app.send(TestEvent2);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn sequence_ignore_b_before_a() {
+        let mut app = test_app();
+        spawn_sequence_reactor(&mut app);
+        app.update();
+        app.send(TestEvent2);
+        app.update();
+        app.send(TestEvent1);
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+
+        app.send(TestEvent2);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn sequence_ignore_b_in_same_frame_as_a() {
+        let mut app = test_app();
+        spawn_sequence_reactor(&mut app);
+        app.update();
+        app.send(TestEvent2);
+        app.send(TestEvent1);
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+    }
+
+    #[test]
+    fn comes_or_resource_set() {
+        #[derive(bevy::prelude::Resource, Clone, Debug, Eq, PartialEq)]
+        struct Flag;
+
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let first = task.will(Update, wait::event::comes_or_resource_set::<TestEvent1, Flag>()).await;
+                let second = task.will(Update, wait::event::comes_or_resource_set::<TestEvent1, Flag>()).await;
+                if first == Either::Left(TestEvent1) && second == Either::Right(Flag) {
+                    task.will(Update, once::run(|mut count: ResMut<Count>| count.increment())).await;
+                }
+            }));
+        });
+        app.update();
+        app.send(TestEvent1);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Flag);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
 }
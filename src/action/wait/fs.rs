@@ -0,0 +1,151 @@
+//! [`wait::fs`] creates a task related to waiting for changes on the file system.
+
+use crate::prelude::{ActionSeed, CancellationHandlers, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+
+/// Waits until the file or the directory at the input path is created, modified or removed.
+///
+/// If the path is a directory, its contents are watched recursively.
+///
+/// The watcher is started when the runner is executed for the first time,
+/// and it is stopped when the runner is dropped.
+/// The output value is the [`Event`] of the change, or the error if failed to watch the path.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     loop {
+///         task.will(Update, wait::fs::changed().with("assets/config.ron")).await;
+///         info!("config changed");
+///     }
+/// });
+/// ```
+pub fn changed<P>() -> ActionSeed<P, notify::Result<Event>>
+where
+    P: AsRef<Path> + 'static,
+{
+    ActionSeed::new(|path: P, output| FsChangedRunner {
+        path: Some(path),
+        output,
+        watcher: None,
+    })
+}
+
+struct FsChangedRunner<P> {
+    path: Option<P>,
+    output: Output<notify::Result<Event>>,
+    watcher: Option<(RecommendedWatcher, Receiver<notify::Result<Event>>)>,
+}
+
+impl<P> FsChangedRunner<P>
+where
+    P: AsRef<Path>,
+{
+    fn watch(path: P) -> notify::Result<(RecommendedWatcher, Receiver<notify::Result<Event>>)> {
+        let (tx, rx) = channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        watcher.watch(path.as_ref(), RecursiveMode::Recursive)?;
+        Ok((watcher, rx))
+    }
+}
+
+impl<P> Runner for FsChangedRunner<P>
+where
+    P: AsRef<Path>,
+{
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        if let Some(path) = self.path.take() {
+            match Self::watch(path) {
+                Ok(watcher) => {
+                    self.watcher.replace(watcher);
+                }
+                Err(e) => {
+                    self.output.set(Err(e));
+                    return RunnerIs::Completed;
+                }
+            }
+        }
+        let Some((_, rx)) = self.watcher.as_ref() else {
+            return RunnerIs::Completed;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok(Ok(event)) if !(event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove()) => continue,
+                Ok(result) => {
+                    self.output.set(result);
+                    return RunnerIs::Completed;
+                }
+                Err(TryRecvError::Empty) => return RunnerIs::Running,
+                Err(TryRecvError::Disconnected) => {
+                    self.output.set(Err(notify::Error::generic("the watcher has been disconnected")));
+                    return RunnerIs::Completed;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn wait_until_file_created() {
+        let dir = std::env::temp_dir().join(format!("bevy_flurx_fs_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut app = test_app();
+        let watch_dir = dir.clone();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let dir = watch_dir.clone();
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::fs::changed().with(dir)
+                    .pipe(crate::action::once::run(|In(result): In<notify::Result<notify::Event>>, mut count: ResMut<Count>| {
+                        assert!(result.is_ok());
+                        count.increment();
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        std::fs::write(dir.join("file.txt"), "flurx").unwrap();
+        for _ in 0..100 {
+            std::thread::sleep(Duration::from_millis(5));
+            app.update();
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn output_error_if_path_not_exists() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, wait::fs::changed().with("__bevy_flurx_not_exists__")).await;
+                assert!(result.is_err());
+                task.will(Update, crate::action::once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
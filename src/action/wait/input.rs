@@ -1,12 +1,22 @@
 //! [`wait::input`] creates a task related to waiting to keycode inputs.
+//!
+//! For gamepads, see [`wait::input::gamepad`](crate::prelude::wait::input::gamepad) and [`wait::input::axis_beyond`](crate::prelude::wait::input::axis_beyond).
 
 use std::hash::Hash;
 
 use bevy::input::ButtonInput;
-use bevy::prelude::{In, Res};
+use bevy::prelude::{Entity, Gamepad, GamepadAxis, In, Query, Res};
 use crate::action::seed::ActionSeed;
 use crate::action::wait;
 
+#[cfg(feature = "input")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+pub use sequence::{sequence, InputStep};
+
+pub mod gamepad;
+#[cfg(feature = "input")]
+mod sequence;
+
 /// Waits until item has just been pressed.
 ///
 /// ## Examples
@@ -145,17 +155,51 @@ where
     })
 }
 
+/// Waits until the value of the axis goes beyond `threshold` on any gamepad, and outputs the gamepad entity.
+///
+/// If `threshold` is negative, it waits until the value becomes less than or equal to it,
+/// otherwise greater than or equal to it,
+/// so both directions of the sticks and the triggers can be observed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     // Waits until the left stick is tilted to the left.
+///     task.will(Update, wait::input::axis_beyond(GamepadAxis::LeftStickX, -0.5)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn axis_beyond(axis: GamepadAxis, threshold: f32) -> ActionSeed<(), Entity> {
+    wait::output(move |gamepads: Query<(Entity, &Gamepad)>| {
+        gamepads.iter().find_map(|(entity, gamepad)| {
+            let value = gamepad.get(axis)?;
+            let beyond = if threshold < 0. {
+                value <= threshold
+            } else {
+                threshold <= value
+            };
+            beyond.then_some(entity)
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::action::pipe::Pipe;
     use crate::action::sequence::Then;
     use crate::action::{once, wait};
     use crate::prelude::Reactor;
     use crate::sequence;
     use crate::tests::test_app;
-    use bevy::app::{First, Startup};
+    use bevy::app::{First, Startup, Update};
+    use bevy::core::Name;
     use bevy::input::ButtonInput;
     use bevy::prelude::KeyCode::{KeyA, KeyB, KeyC, KeyD};
-    use bevy::prelude::{Commands, KeyCode, World};
+    use bevy::prelude::{Commands, Entity, Gamepad, GamepadAxis, GamepadButton, In, KeyCode, World};
     use bevy_test_helper::resource::bool::BoolExtension;
     use bevy_test_helper::resource::DirectResourceControl;
 
@@ -295,4 +339,47 @@ mod tests {
         app.update();
         assert!(app.is_bool_true());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn wait_until_gamepad_pressed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::input::gamepad::pressed().with(GamepadButton::South)
+                    .pipe(once::run(|In(gamepad): In<Entity>, mut commands: Commands| {
+                        commands.entity(gamepad).insert(Name::new("pressed"));
+                    })),
+                ).await;
+            }));
+        });
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.update();
+        assert!(app.world().get::<Name>(gamepad).is_none());
+
+        app.world_mut().get_mut::<Gamepad>(gamepad).unwrap().digital_mut().press(GamepadButton::South);
+        app.update();
+        assert!(app.world().get::<Name>(gamepad).is_some());
+    }
+
+    #[test]
+    fn wait_until_axis_beyond_threshold() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::input::axis_beyond(GamepadAxis::LeftStickX, -0.5)
+                    .then(once::run(|world: &mut World| {
+                        world.set_bool(true);
+                    })),
+                ).await;
+            }));
+        });
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.world_mut().get_mut::<Gamepad>(gamepad).unwrap().analog_mut().set(GamepadAxis::LeftStickX, 0.8);
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.world_mut().get_mut::<Gamepad>(gamepad).unwrap().analog_mut().set(GamepadAxis::LeftStickX, -0.6);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+}
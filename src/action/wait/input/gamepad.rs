@@ -0,0 +1,104 @@
+//! [`wait::input::gamepad`] creates a task related to waiting to [`GamepadButton`] inputs.
+//!
+//! Since the state of each gamepad is stored in its [`Gamepad`] component,
+//! these actions observe all connected gamepads and output the gamepad entity that satisfied the condition.
+
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use bevy::prelude::{Entity, Gamepad, GamepadButton, In, Query};
+
+/// Waits until the button has just been pressed on any gamepad.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let gamepad: Entity = task.will(Update, wait::input::gamepad::just_pressed().with(GamepadButton::South)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn just_pressed() -> ActionSeed<GamepadButton, Entity> {
+    find_gamepad(|gamepad, button| gamepad.just_pressed(*button))
+}
+
+/// Waits until the button has been pressed on any gamepad.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let gamepad: Entity = task.will(Update, wait::input::gamepad::pressed().with(GamepadButton::South)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn pressed() -> ActionSeed<GamepadButton, Entity> {
+    find_gamepad(|gamepad, button| gamepad.pressed(*button))
+}
+
+/// Waits until the button has just been released on any gamepad.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let gamepad: Entity = task.will(Update, wait::input::gamepad::just_released().with(GamepadButton::South)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn just_released() -> ActionSeed<GamepadButton, Entity> {
+    find_gamepad(|gamepad, button| gamepad.just_released(*button))
+}
+
+/// Waits until any button in inputs has been pressed on any gamepad.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::input::gamepad::any_pressed().with(vec![GamepadButton::South, GamepadButton::East])).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn any_pressed() -> ActionSeed<Vec<GamepadButton>, Entity> {
+    find_gamepad(|gamepad, buttons: &Vec<GamepadButton>| gamepad.any_pressed(buttons.iter().copied()))
+}
+
+/// Waits until any button in inputs has just been pressed on any gamepad.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::input::gamepad::any_just_pressed().with(vec![GamepadButton::South, GamepadButton::East])).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn any_just_pressed() -> ActionSeed<Vec<GamepadButton>, Entity> {
+    find_gamepad(|gamepad, buttons: &Vec<GamepadButton>| gamepad.any_just_pressed(buttons.iter().copied()))
+}
+
+fn find_gamepad<I>(satisfied: fn(&Gamepad, &I) -> bool) -> ActionSeed<I, Entity>
+where
+    I: Clone + Send + Sync + 'static,
+{
+    wait::output(move |In(input): In<I>, gamepads: Query<(Entity, &Gamepad)>| {
+        gamepads
+            .iter()
+            .find_map(|(entity, gamepad)| satisfied(gamepad, &input).then_some(entity))
+    })
+}
@@ -0,0 +1,201 @@
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use crate::action::wait::logical::{ButtonCheck, InputBinding, PhysicalButtons};
+use bevy::prelude::{In, Local};
+
+/// A step of [`wait::input::sequence`].
+///
+/// The buttons of a step can be keys, mouse buttons and gamepad buttons,
+/// so keyboard and gamepad steps can be mixed in one sequence.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputStep {
+    buttons: Vec<InputBinding>,
+    window: u32,
+}
+
+impl InputStep {
+    /// Creates the step that is entered when `button` has just been pressed
+    /// within `window` frames after the previous step.
+    ///
+    /// The window of the first step is ignored.
+    #[inline]
+    pub fn new(button: impl Into<InputBinding>, window: u32) -> Self {
+        Self {
+            buttons: vec![button.into()],
+            window,
+        }
+    }
+
+    /// Creates the step that is entered when all `buttons` are pressed at the same time
+    /// within `window` frames after the previous step, e.g. `Forward + Punch`.
+    ///
+    /// The window of the first step is ignored.
+    #[inline]
+    pub fn all<B: Into<InputBinding>>(buttons: impl IntoIterator<Item=B>, window: u32) -> Self {
+        Self {
+            buttons: buttons.into_iter().map(Into::into).collect(),
+            window,
+        }
+    }
+
+    fn entered(&self, buttons: &PhysicalButtons) -> bool {
+        self.buttons.iter().all(|button| buttons.check(*button, ButtonCheck::Pressed))
+            && self.buttons.iter().any(|button| buttons.check(*button, ButtonCheck::JustPressed))
+    }
+}
+
+/// Waits until the ordered steps have been entered, such as fighting-game style command inputs.
+///
+/// Each step must be entered within its frame window after the previous step;
+/// otherwise the sequence restarts from the first step.
+/// Buttons that are not part of the next step are ignored.
+/// Gamepad buttons are read from all [`Gamepad`](bevy::prelude::Gamepad)s.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{GamepadButton, KeyCode, Update};
+/// use bevy_flurx::prelude::*;
+/// use bevy_flurx::prelude::wait::input::InputStep;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::input::sequence().with(vec![
+///         InputStep::new(KeyCode::ArrowDown, 0),
+///         InputStep::new(GamepadButton::DPadRight, 10),
+///         InputStep::all([KeyCode::ArrowRight, KeyCode::KeyP], 10),
+///     ])).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn sequence() -> ActionSeed<Vec<InputStep>> {
+    wait::until(|In(steps): In<Vec<InputStep>>,
+                 mut progress: Local<(usize, u32)>,
+                 buttons: PhysicalButtons| {
+        let (next, elapsed) = &mut *progress;
+        if steps.len() <= *next {
+            return true;
+        }
+        if 0 < *next {
+            *elapsed += 1;
+            if steps[*next].window < *elapsed {
+                *next = 0;
+            }
+        }
+        if steps[*next].entered(&buttons) {
+            *next += 1;
+            *elapsed = 0;
+        }
+        steps.len() <= *next
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait::input::InputStep;
+    use crate::action::{once, wait};
+    use crate::prelude::{InputBinding, Reactor};
+    use crate::sequence;
+    use crate::tests::test_app;
+    use bevy::app::{First, Startup};
+    use bevy::input::ButtonInput;
+    use bevy::prelude::KeyCode::{KeyA, KeyB, KeyC, KeyD};
+    use bevy::prelude::{Commands, Gamepad, GamepadButton, KeyCode, World};
+    use bevy_test_helper::resource::bool::BoolExtension;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn spawn_sequence_reactor(app: &mut bevy::app::App) {
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::sequence().with(vec![
+                        InputStep::new(KeyA, 0),
+                        InputStep::new(KeyB, 2),
+                        InputStep::all([KeyC, KeyD], 2),
+                    ]),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+        app.update();
+    }
+
+    #[test]
+    fn wait_until_sequence_entered() {
+        let mut app = test_app();
+        spawn_sequence_reactor(&mut app);
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyC);
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyD);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn restart_sequence_on_timeout() {
+        let mut app = test_app();
+        spawn_sequence_reactor(&mut app);
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        for _ in 0..3 {
+            app.update();
+        }
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyC);
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyD);
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().release_all();
+        app.update();
+        for key in [KeyA, KeyB, KeyC, KeyD] {
+            app.resource_mut::<ButtonInput<KeyCode>>().press(key);
+            app.update();
+        }
+        assert!(app.is_bool_true());
+    }
+
+    #[test]
+    fn wait_until_mixed_sequence_entered() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, sequence! {
+                    wait::input::sequence().with(vec![
+                        InputStep::new(KeyA, 0),
+                        InputStep::new(GamepadButton::South, 2),
+                        InputStep::all([InputBinding::Key(KeyB), InputBinding::Gamepad(GamepadButton::East)], 2),
+                    ]),
+                    once::run(|world: &mut World|{
+                        world.set_bool(true);
+                    })
+                }).await;
+            }));
+        });
+        let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+        app.update();
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyA);
+        app.update();
+        app.world_mut().get_mut::<Gamepad>(gamepad).unwrap().digital_mut().press(GamepadButton::South);
+        app.update();
+        app.world_mut().get_mut::<Gamepad>(gamepad).unwrap().digital_mut().press(GamepadButton::East);
+        app.update();
+        assert!(app.is_bool_false());
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyB);
+        app.update();
+        assert!(app.is_bool_true());
+    }
+}
@@ -0,0 +1,116 @@
+use crate::action::Action;
+use crate::wait_all;
+
+/// Waits until all three actions are completed, and outputs their outputs as a tuple.
+///
+/// The actions are run concurrently and can have arbitrary output types.
+/// Unlike [`wait::all`](crate::prelude::wait::all()), the outputs are neither erased nor collected into a `Vec`.
+///
+/// This is the function form of [`wait_all!`](crate::wait_all) for three actions,
+/// and together with [`wait::both`](crate::prelude::wait::both) and [`wait::join4`](crate::prelude::wait::join4)
+/// it forms the fixed-arity family of [`wait::all`](crate::prelude::wait::all()).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let (num, text, flag): (usize, String, bool) = task.will(Update, wait::join3(
+///         once::run(|| 1),
+///         once::run(|| "hello".to_string()),
+///         once::run(|| true),
+///     )).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn join3<I1, O1, I2, O2, I3, O3>(
+    a1: impl Into<Action<I1, O1>> + 'static,
+    a2: impl Into<Action<I2, O2>> + 'static,
+    a3: impl Into<Action<I3, O3>> + 'static,
+) -> Action<((I1, I2), I3), (O1, O2, O3)>
+where
+    I1: 'static,
+    O1: Send + 'static,
+    I2: 'static,
+    O2: Send + 'static,
+    I3: 'static,
+    O3: 'static,
+{
+    wait_all!(a1, a2, a3)
+}
+
+/// Waits until all four actions are completed, and outputs their outputs as a tuple.
+///
+/// Please see [`wait::join3`](crate::prelude::wait::join3) for details.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let (_, _, _, _): (usize, String, bool, ()) = task.will(Update, wait::join4(
+///         once::run(|| 1),
+///         once::run(|| "hello".to_string()),
+///         once::run(|| true),
+///         delay::frames().with(3),
+///     )).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn join4<I1, O1, I2, O2, I3, O3, I4, O4>(
+    a1: impl Into<Action<I1, O1>> + 'static,
+    a2: impl Into<Action<I2, O2>> + 'static,
+    a3: impl Into<Action<I3, O3>> + 'static,
+    a4: impl Into<Action<I4, O4>> + 'static,
+) -> Action<(((I1, I2), I3), I4), (O1, O2, O3, O4)>
+where
+    I1: 'static,
+    O1: Send + 'static,
+    I2: 'static,
+    O2: Send + 'static,
+    I3: 'static,
+    O3: 'static,
+    I4: 'static,
+    O4: 'static,
+{
+    wait_all!(a1, a2, a3, a4)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{Map, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut, Resource};
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Outputs(Option<(usize, String, bool)>);
+
+    #[test]
+    fn join3_heterogeneous_outputs() {
+        let mut app = test_app();
+        app.init_resource::<Outputs>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let outputs = task.will(Update, wait::join3(
+                    once::run(|| 1),
+                    delay::frames().with(2).map(|_| "hello".to_string()),
+                    once::run(|| true),
+                )).await;
+                task.will(Update, once::run(move |mut o: ResMut<Outputs>| {
+                    o.0 = Some(outputs.clone());
+                })).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Outputs(Some((1, "hello".to_string(), true))));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::action::seed::ActionSeed;
+use crate::prelude::{CancellationHandlers, Output, Runner, RunnerIs};
+use crate::runner::CurrentReactor;
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy::prelude::{Entity, Event, Observer, Trigger, World};
+use std::sync::{Arc, Mutex};
+
+/// Waits until the event `E` is triggered on the passed [`Entity`], and returns the event.
+///
+/// This installs a temporary [`Observer`] on the entity, so the events bubbled up from its descendants
+/// via [`Event::Traversal`] are also received.
+/// This is useful for the UI trees that use bubbling rather than global events.
+/// The observer is despawned when this action is completed or the reactor is removed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component, Clone)]
+/// struct Clicked;
+///
+/// impl Event for Clicked {
+///     type Traversal = &'static Parent;
+///     const AUTO_PROPAGATE: bool = true;
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     let menu = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn_empty().id()
+///     })).await;
+///     task.will(Update, wait::listener::<Clicked>().with(menu)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn listener<E>() -> ActionSeed<Entity, E>
+where
+    E: Event + Clone,
+{
+    ActionSeed::new(|entity, output| ListenerRunner {
+        entity,
+        observer: None,
+        received: Arc::new(Mutex::new(None)),
+        output,
+    })
+}
+
+struct ListenerRunner<E> {
+    entity: Entity,
+    observer: Option<Entity>,
+    received: Arc<Mutex<Option<E>>>,
+    output: Output<E>,
+}
+
+impl<E> Runner for ListenerRunner<E>
+where
+    E: Event + Clone,
+{
+    fn run(&mut self, world: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        let Some(observer) = self.observer else {
+            self.observe(world);
+            return RunnerIs::Running;
+        };
+        let Some(event) = self.received.lock().expect("Failed to lock the received event").take() else {
+            return RunnerIs::Running;
+        };
+        if let Ok(observer) = world.get_entity_mut(observer) {
+            observer.despawn_recursive();
+        }
+        self.output.set(event);
+        RunnerIs::Completed
+    }
+}
+
+impl<E> ListenerRunner<E>
+where
+    E: Event + Clone,
+{
+    fn observe(&mut self, world: &mut World) {
+        let received = self.received.clone();
+        let observer = Observer::new(move |trigger: Trigger<E>| {
+            received
+                .lock()
+                .expect("Failed to lock the received event")
+                .get_or_insert_with(|| trigger.event().clone());
+        })
+            .with_entity(self.entity);
+        let observer = world.spawn(observer).id();
+        // The observer is despawned together with the reactor if it is removed before the event comes.
+        if let Some(reactor) = world.get_resource::<CurrentReactor>().map(|reactor| reactor.0) {
+            if let Ok(mut reactor) = world.get_entity_mut(reactor) {
+                reactor.add_child(observer);
+            }
+        }
+        self.observer.replace(observer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::hierarchy::BuildChildren;
+    use bevy::prelude::{Commands, Component, Entity, Event, In, Observer, Parent, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component, Clone)]
+    struct Clicked(usize);
+
+    impl Event for Clicked {
+        type Traversal = &'static Parent;
+        const AUTO_PROPAGATE: bool = true;
+    }
+
+    #[derive(Component)]
+    struct Menu;
+
+    #[test]
+    fn receive_bubbled_event() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            let menu = commands.spawn(Menu).id();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::listener::<Clicked>()
+                    .with(menu)
+                    .pipe(once::run(|In(clicked): In<Clicked>, mut count: ResMut<Count>| {
+                        count.0 = clicked.0;
+                    })),
+                ).await;
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        let observers = app.world_mut().query::<&Observer>().iter(app.world()).count();
+        let menu = app.world_mut().query_filtered::<Entity, With<Menu>>().single(app.world());
+        let button = app.world_mut().spawn_empty().set_parent(menu).id();
+        app.world_mut().trigger_targets(Clicked(3), button);
+        app.update();
+        app.assert_resource_eq(Count(3));
+        assert_eq!(app.world_mut().query::<&Observer>().iter(app.world()).count(), observers - 1);
+    }
+}
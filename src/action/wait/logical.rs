@@ -0,0 +1,339 @@
+//! [`wait::logical`] creates a task related to waiting to logical actions bound in [`InputMap`].
+//!
+//! The reactor flows can wait for logical actions such as `Confirm` instead of hardcoding the physical buttons,
+//! so they respect the user's rebinding.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::ecs::system::SystemParam;
+use bevy::input::ButtonInput;
+use bevy::prelude::{Gamepad, GamepadButton, In, KeyCode, MouseButton, Query, Res, Resource};
+use bevy::utils::HashMap;
+use std::hash::Hash;
+
+/// The physical button bound to a logical action in [`InputMap`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum InputBinding {
+    /// The key of the keyboard.
+    Key(KeyCode),
+    /// The button of the mouse.
+    Mouse(MouseButton),
+    /// The button of any gamepad.
+    Gamepad(GamepadButton),
+}
+
+impl From<KeyCode> for InputBinding {
+    #[inline]
+    fn from(key: KeyCode) -> Self {
+        Self::Key(key)
+    }
+}
+
+impl From<MouseButton> for InputBinding {
+    #[inline]
+    fn from(button: MouseButton) -> Self {
+        Self::Mouse(button)
+    }
+}
+
+impl From<GamepadButton> for InputBinding {
+    #[inline]
+    fn from(button: GamepadButton) -> Self {
+        Self::Gamepad(button)
+    }
+}
+
+/// The resource that maps the logical actions `A` to the physical buttons.
+///
+/// A logical action can be bound to multiple buttons, and it is triggered by any of them.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// enum Menu {
+///     Confirm,
+///     Cancel,
+/// }
+///
+/// let mut input_map = InputMap::default();
+/// input_map
+///     .bind(Menu::Confirm, KeyCode::Enter)
+///     .bind(Menu::Confirm, GamepadButton::South)
+///     .bind(Menu::Cancel, KeyCode::Escape);
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin))
+///     .insert_resource(input_map);
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct InputMap<A> {
+    bindings: HashMap<A, Vec<InputBinding>>,
+}
+
+impl<A> Default for InputMap<A> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            bindings: HashMap::default(),
+        }
+    }
+}
+
+impl<A> InputMap<A>
+where
+    A: Eq + Hash,
+{
+    /// Binds the `binding` to the `action` in addition to the existing bindings.
+    #[inline]
+    pub fn bind(&mut self, action: A, binding: impl Into<InputBinding>) -> &mut Self {
+        let binding = binding.into();
+        let bindings = self.bindings.entry(action).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+        self
+    }
+
+    /// Unbinds the `binding` from the `action`.
+    #[inline]
+    pub fn unbind(&mut self, action: &A, binding: impl Into<InputBinding>) -> &mut Self {
+        let binding = binding.into();
+        if let Some(bindings) = self.bindings.get_mut(action) {
+            bindings.retain(|b| b != &binding);
+        }
+        self
+    }
+
+    /// Replaces all bindings of the `action` with the `binding`.
+    #[inline]
+    pub fn rebind(&mut self, action: A, binding: impl Into<InputBinding>) -> &mut Self {
+        self.bindings.insert(action, vec![binding.into()]);
+        self
+    }
+
+    /// Returns the bindings of the `action`.
+    #[inline]
+    pub fn bindings(&self, action: &A) -> &[InputBinding] {
+        self.bindings.get(action).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// Waits until the logical action has just been pressed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// enum Menu {
+///     Confirm,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::logical::just_pressed().with(Menu::Confirm)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn just_pressed<A>() -> ActionSeed<A>
+where
+    A: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    wait::until(|In(action): In<A>, inputs: PhysicalInputs<A>| {
+        inputs.any(&action, ButtonCheck::JustPressed)
+    })
+}
+
+/// Waits until the logical action has been pressed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// enum Player {
+///     Dash,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::logical::pressed().with(Player::Dash)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn pressed<A>() -> ActionSeed<A>
+where
+    A: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    wait::until(|In(action): In<A>, inputs: PhysicalInputs<A>| {
+        inputs.any(&action, ButtonCheck::Pressed)
+    })
+}
+
+/// Waits until the logical action has just been released.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// enum Player {
+///     Charge,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::logical::just_released().with(Player::Charge)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn just_released<A>() -> ActionSeed<A>
+where
+    A: Clone + Eq + Hash + Send + Sync + 'static,
+{
+    wait::until(|In(action): In<A>, inputs: PhysicalInputs<A>| {
+        inputs.any(&action, ButtonCheck::JustReleased)
+    })
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum ButtonCheck {
+    JustPressed,
+    Pressed,
+    JustReleased,
+}
+
+impl ButtonCheck {
+    fn button<T>(self, input: &ButtonInput<T>, button: T) -> bool
+    where
+        T: Copy + Eq + Hash + Send + Sync + 'static,
+    {
+        match self {
+            Self::JustPressed => input.just_pressed(button),
+            Self::Pressed => input.pressed(button),
+            Self::JustReleased => input.just_released(button),
+        }
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct PhysicalInputs<'w, 's, A: Send + Sync + 'static> {
+    input_map: Option<Res<'w, InputMap<A>>>,
+    buttons: PhysicalButtons<'w, 's>,
+}
+
+impl<A> PhysicalInputs<'_, '_, A>
+where
+    A: Eq + Hash + Send + Sync + 'static,
+{
+    /// Returns [`InputMap`] of the logical actions `A` if it exists.
+    #[inline]
+    pub(crate) fn input_map(&self) -> Option<&InputMap<A>> {
+        self.input_map.as_deref()
+    }
+
+    fn any(&self, action: &A, check: ButtonCheck) -> bool {
+        self.input_map.as_ref().is_some_and(|input_map| self.any_in(input_map, action, check))
+    }
+
+    /// Returns whether any button bound to the `action` in `input_map` satisfies the `check`.
+    pub(crate) fn any_in(&self, input_map: &InputMap<A>, action: &A, check: ButtonCheck) -> bool {
+        input_map.bindings(action).iter().any(|binding| self.buttons.check(*binding, check))
+    }
+}
+
+/// Reads the physical buttons of the keyboard, the mouse and the gamepads.
+#[derive(SystemParam)]
+pub(crate) struct PhysicalButtons<'w, 's> {
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    mouse: Option<Res<'w, ButtonInput<MouseButton>>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl PhysicalButtons<'_, '_> {
+    /// Returns whether the `binding` satisfies the `check`.
+    ///
+    /// A gamepad button satisfies it if it does on any gamepad.
+    pub(crate) fn check(&self, binding: InputBinding, check: ButtonCheck) -> bool {
+        match binding {
+            InputBinding::Key(key) => self.keys.as_ref().is_some_and(|keys| check.button(keys, key)),
+            InputBinding::Mouse(button) => self.mouse.as_ref().is_some_and(|mouse| check.button(mouse, button)),
+            InputBinding::Gamepad(button) => self.gamepads.iter().any(|gamepad| check.button(gamepad.digital(), button)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{InputMap, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{First, Startup, Update};
+    use bevy::input::ButtonInput;
+    use bevy::prelude::{Commands, GamepadButton, KeyCode, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+    enum Menu {
+        Confirm,
+    }
+
+    #[test]
+    fn wait_bound_key() {
+        let mut app = test_app();
+        let mut input_map = InputMap::default();
+        input_map
+            .bind(Menu::Confirm, KeyCode::Enter)
+            .bind(Menu::Confirm, GamepadButton::South);
+        app.insert_resource(input_map);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                loop {
+                    task.will(First, wait::logical::just_pressed().with(Menu::Confirm).then(once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    }))).await;
+                }
+            }));
+        });
+        app.update();
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn respect_rebinding() {
+        let mut app = test_app();
+        let mut input_map = InputMap::default();
+        input_map.bind(Menu::Confirm, KeyCode::Enter);
+        app.insert_resource(input_map);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::logical::pressed().with(Menu::Confirm).then(once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                }))).await;
+            }));
+        });
+        app.update();
+        app.resource_mut::<InputMap<Menu>>().rebind(Menu::Confirm, KeyCode::KeyZ);
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyZ);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
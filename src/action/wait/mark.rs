@@ -0,0 +1,97 @@
+//! [`wait::mark`] creates a task related to waiting for the times marked by [`mark::set`](crate::prelude::mark::set).
+
+use crate::action::mark::Marks;
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use crate::runner::CurrentReactor;
+use bevy::prelude::{Query, Res, Time};
+use std::borrow::Cow;
+use std::time::Duration;
+
+/// Waits until `duration` has elapsed since the time marked with `name` on the reactor that runs this action.
+///
+/// If the mark does not exist yet, this action waits until it is set and then `duration` elapses.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, mark::set("combat_start")).await;
+///     task.will(Update, wait::mark::elapsed("combat_start", Duration::from_secs(30))).await;
+/// });
+/// ```
+#[inline]
+pub fn elapsed(name: impl Into<Cow<'static, str>>, duration: Duration) -> ActionSeed {
+    let name = name.into();
+    wait::until(move |reactor: Res<CurrentReactor>, marks: Query<&Marks>, time: Res<Time>| {
+        marks
+            .get(reactor.0)
+            .ok()
+            .and_then(|marks| marks.0.get(&name))
+            .is_some_and(|marked| duration <= time.elapsed().saturating_sub(*marked))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{mark, once, wait};
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn wait_elapsed_since_mark() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, mark::set("start")).await;
+                task.will(Update, {
+                    wait::mark::elapsed("start", Duration::from_millis(500))
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn marks_are_per_reactor() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, mark::set("start")).await;
+            }));
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::mark::elapsed("start", Duration::ZERO)
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+    }
+}
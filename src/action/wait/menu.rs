@@ -0,0 +1,176 @@
+//! [`wait::menu`] creates a task related to waiting to the menu navigation.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use crate::action::wait::logical::{ButtonCheck, PhysicalInputs};
+use crate::prelude::{InputMap, UiFocus};
+use bevy::input::gamepad::GamepadButton;
+use bevy::prelude::{Entity, KeyCode, Local, ResMut};
+
+/// The logical actions used by [`wait::menu::navigated`](crate::prelude::wait::menu::navigated).
+///
+/// Insert [`InputMap<MenuAction>`] as a resource to customize the bindings;
+/// otherwise [`MenuAction::input_map`] is used.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum MenuAction {
+    /// Moves the cursor to the previous item.
+    Up,
+    /// Moves the cursor to the next item.
+    Down,
+    /// Moves the cursor to the previous item.
+    Left,
+    /// Moves the cursor to the next item.
+    Right,
+    /// Confirms the item under the cursor.
+    Confirm,
+    /// Cancels the menu.
+    Cancel,
+}
+
+impl MenuAction {
+    /// Returns the default bindings: arrow keys and the D-pad to move,
+    /// `Enter`, `Space` and the south button to confirm, `Escape` and the east button to cancel.
+    pub fn input_map() -> InputMap<MenuAction> {
+        let mut input_map = InputMap::default();
+        input_map
+            .bind(MenuAction::Up, KeyCode::ArrowUp)
+            .bind(MenuAction::Up, GamepadButton::DPadUp)
+            .bind(MenuAction::Down, KeyCode::ArrowDown)
+            .bind(MenuAction::Down, GamepadButton::DPadDown)
+            .bind(MenuAction::Left, KeyCode::ArrowLeft)
+            .bind(MenuAction::Left, GamepadButton::DPadLeft)
+            .bind(MenuAction::Right, KeyCode::ArrowRight)
+            .bind(MenuAction::Right, GamepadButton::DPadRight)
+            .bind(MenuAction::Confirm, KeyCode::Enter)
+            .bind(MenuAction::Confirm, KeyCode::Space)
+            .bind(MenuAction::Confirm, GamepadButton::South)
+            .bind(MenuAction::Cancel, KeyCode::Escape)
+            .bind(MenuAction::Cancel, GamepadButton::East);
+        input_map
+    }
+}
+
+/// Waits until an item of the menu is confirmed, and outputs its index, or `None` if the menu is cancelled.
+///
+/// The cursor starts at `initial`, moves to the previous item by [`MenuAction::Up`] and [`MenuAction::Left`],
+/// and to the next item by [`MenuAction::Down`] and [`MenuAction::Right`], wrapping around at both ends.
+/// The entity under the cursor is set to [`UiFocus`], so the menu can be highlighted by observing it.
+///
+/// If `entities` is empty, this action outputs `None` immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let buttons = vec![Entity::PLACEHOLDER; 3];
+///     match task.will(First, wait::menu::navigated(buttons, 0)).await {
+///         Some(0) => info!("start"),
+///         Some(_) => info!("options"),
+///         None => info!("back"),
+///     }
+/// });
+/// ```
+pub fn navigated(entities: impl IntoIterator<Item=Entity>, initial: usize) -> ActionSeed<(), Option<usize>> {
+    let entities = entities.into_iter().collect::<Vec<_>>();
+    wait::output(move |mut cursor: Local<Option<usize>>,
+                       mut default_input_map: Local<Option<InputMap<MenuAction>>>,
+                       mut focus: ResMut<UiFocus>,
+                       inputs: PhysicalInputs<MenuAction>| {
+        let len = entities.len();
+        if len == 0 {
+            return Some(None);
+        }
+        let input_map = match inputs.input_map() {
+            Some(input_map) => input_map,
+            None => default_input_map.get_or_insert_with(MenuAction::input_map),
+        };
+        let just_pressed = |action| inputs.any_in(input_map, &action, ButtonCheck::JustPressed);
+        let cursor = cursor.get_or_insert(initial.min(len - 1));
+        if just_pressed(MenuAction::Cancel) {
+            return Some(None);
+        }
+        if just_pressed(MenuAction::Confirm) {
+            return Some(Some(*cursor));
+        }
+        if just_pressed(MenuAction::Up) || just_pressed(MenuAction::Left) {
+            *cursor = (*cursor + len - 1) % len;
+        } else if just_pressed(MenuAction::Down) || just_pressed(MenuAction::Right) {
+            *cursor = (*cursor + 1) % len;
+        }
+        let entity = Some(entities[*cursor]);
+        if focus.0 != entity {
+            focus.0 = entity;
+        }
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor, UiFocus};
+    use crate::tests::test_app;
+    use bevy::app::{First, Startup};
+    use bevy::input::ButtonInput;
+    use bevy::prelude::{Commands, Entity, In, KeyCode, ResMut, Resource, World};
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Chosen(Option<Option<usize>>);
+
+    fn spawn_menu(app: &mut bevy::app::App) -> Vec<Entity> {
+        let buttons = (0..3).map(|_| app.world_mut().spawn_empty().id()).collect::<Vec<_>>();
+        let entities = buttons.clone();
+        app.init_resource::<Chosen>();
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let entities = entities.clone();
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(First, wait::menu::navigated(entities, 1).pipe(once::run(|In(chosen): In<Option<usize>>, mut c: ResMut<Chosen>| {
+                    c.0 = Some(chosen);
+                }))).await;
+            }));
+        });
+        buttons
+    }
+
+    fn press(world: &mut World, key: KeyCode) {
+        let mut input = world.resource_mut::<ButtonInput<KeyCode>>();
+        input.release_all();
+        input.press(key);
+    }
+
+    #[test]
+    fn move_cursor_and_confirm() {
+        let mut app = test_app();
+        let buttons = spawn_menu(&mut app);
+        app.update();
+        app.assert_resource_eq(UiFocus(Some(buttons[1])));
+
+        press(app.world_mut(), KeyCode::ArrowDown);
+        app.update();
+        app.assert_resource_eq(UiFocus(Some(buttons[2])));
+
+        press(app.world_mut(), KeyCode::ArrowDown);
+        app.update();
+        app.assert_resource_eq(UiFocus(Some(buttons[0])));
+
+        press(app.world_mut(), KeyCode::Enter);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Chosen(Some(Some(0))));
+    }
+
+    #[test]
+    fn cancel_menu() {
+        let mut app = test_app();
+        spawn_menu(&mut app);
+        app.update();
+        press(app.world_mut(), KeyCode::Escape);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Chosen(Some(None)));
+    }
+}
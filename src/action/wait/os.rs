@@ -0,0 +1,80 @@
+//! [`wait::os`] creates a task related to waiting for the signals from the operating system.
+
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use bevy::log::error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+/// Set when the process receives the termination request.
+static TERMINATE_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Guards the handler from being installed twice, which [`ctrlc`] does not allow.
+static INSTALL_HANDLER: Once = Once::new();
+
+/// Waits until the process is requested to terminate by `SIGINT`, `SIGTERM` or `SIGHUP`,
+/// or by console `Ctrl-C` on Windows.
+///
+/// The signal handler is installed when this action runs for the first time, and remains for the lifetime of the process,
+/// so once a termination has been requested, this action completes immediately.
+/// Since only one handler can be installed per process, this action never completes
+/// if another handler has already been installed with [`ctrlc`].
+///
+/// This is useful for headless servers to shut down gracefully.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::os::terminate_requested()).await;
+///     info!("saving the world before shutting down");
+///     task.will(Update, once::event::app_exit_success()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn terminate_requested() -> ActionSeed {
+    wait::until(|| {
+        INSTALL_HANDLER.call_once(|| {
+            if let Err(e) = ctrlc::set_handler(request_terminate) {
+                error!("failed to install the termination signal handler: {e}");
+            }
+        });
+        TERMINATE_REQUESTED.load(Ordering::Relaxed)
+    })
+}
+
+fn request_terminate() {
+    TERMINATE_REQUESTED.store(true, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::action::wait::os::request_terminate;
+    use crate::prelude::{Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wait_until_terminate_requested() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::os::terminate_requested().then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        request_terminate();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
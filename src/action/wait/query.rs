@@ -0,0 +1,125 @@
+//! [`wait::query`] creates a task related to waiting for entities matching a query.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::ecs::query::{QueryFilter, QueryItem, ReadOnlyQueryData};
+use bevy::prelude::{Component, Entity, Query};
+
+/// The query data whose items can be cloned into owned values,
+/// used as the output of [`wait::query::matches`](crate::prelude::wait::query::matches).
+///
+/// This is implemented for [`Entity`], `&C` where `C` is a [`Component`] that implements [`Clone`], and tuples of them.
+pub trait CloneQueryData: ReadOnlyQueryData + 'static {
+    /// The owned value of the query item.
+    type Owned: 'static;
+
+    /// Clones the query item into the owned value.
+    fn clone_item(item: QueryItem<'_, Self>) -> Self::Owned;
+}
+
+impl CloneQueryData for Entity {
+    type Owned = Entity;
+
+    #[inline(always)]
+    fn clone_item(item: QueryItem<'_, Self>) -> Self::Owned {
+        item
+    }
+}
+
+impl<C> CloneQueryData for &'static C
+where
+    C: Component + Clone,
+{
+    type Owned = C;
+
+    #[inline(always)]
+    fn clone_item(item: QueryItem<'_, Self>) -> Self::Owned {
+        item.clone()
+    }
+}
+
+macro_rules! impl_clone_query_data {
+    ($($d: ident),*) => {
+        impl<$($d,)*> CloneQueryData for ($($d,)*)
+        where
+            $($d: CloneQueryData,)*
+        {
+            type Owned = ($($d::Owned,)*);
+
+            #[inline(always)]
+            #[allow(non_snake_case)]
+            fn clone_item(($($d,)*): QueryItem<'_, Self>) -> Self::Owned {
+                ($($d::clone_item($d),)*)
+            }
+        }
+    };
+}
+
+impl_clone_query_data!(D1, D2);
+impl_clone_query_data!(D1, D2, D3);
+impl_clone_query_data!(D1, D2, D3, D4);
+
+/// Waits until any entity matches the query with the data `D` and the filter `F`,
+/// and returns the clone of the first matching query item.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// Reactor::schedule(|task| async move{
+///     let (enemy, transform): (Entity, Transform) = task.will(Update, wait::query::matches::<(Entity, &Transform), With<Enemy>>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn matches<D, F>() -> ActionSeed<(), D::Owned>
+where
+    D: CloneQueryData,
+    F: QueryFilter + 'static,
+{
+    wait::output(|query: Query<D, F>| {
+        query.iter().next().map(D::clone_item)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Component, Entity, In, Resource, With};
+
+    #[derive(Component)]
+    struct Enemy;
+
+    #[derive(Component, Clone, Debug, Eq, PartialEq)]
+    struct Hp(usize);
+
+    #[derive(Resource, Debug, Eq, PartialEq)]
+    struct Found(Entity, Hp);
+
+    #[test]
+    fn wait_until_query_matches() {
+        let mut app = test_app();
+        app.world_mut().spawn(Hp(3));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::query::matches::<(Entity, &Hp), With<Enemy>>()
+                    .pipe(once::run(|In((entity, hp)): In<(Entity, Hp)>, mut commands: Commands| {
+                        commands.insert_resource(Found(entity, hp));
+                    }))).await;
+            }));
+        });
+        app.update();
+        assert!(app.world().get_resource::<Found>().is_none());
+
+        let enemy = app.world_mut().spawn((Enemy, Hp(10))).id();
+        app.update();
+        assert_eq!(app.world().resource::<Found>(), &Found(enemy, Hp(10)));
+    }
+}
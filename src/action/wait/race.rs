@@ -0,0 +1,173 @@
+use crate::prelude::ActionSeed;
+use crate::runner::{BoxedRunner, CancellationHandlers, CancellationId, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+
+/// Waits until one of the actions is completed, and cancels the others.
+///
+/// The output value is the index of the completed action.
+///
+/// Unlike [`wait::any`](crate::prelude::wait::any), the semantics for the losing actions are guaranteed:
+///
+/// - The actions are run in the order they are passed, and the actions after the winner are not run in the frame the winner is completed.
+/// - The losing actions are dropped in the same frame the winner is completed, so their states such as [`Local`](bevy::prelude::Local) are released immediately.
+/// - The cancellation handlers registered by the losing actions are called immediately, before any further runner executes.
+///
+/// # Panics
+///
+/// Panicked if actions is empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::actions;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let index: usize = task.will(Update, wait::race().with(actions![
+///         wait::input::just_pressed().with(KeyCode::Space),
+///         delay::time().with(Duration::from_secs(5)),
+///     ])).await;
+/// });
+/// ```
+pub fn race<Actions>() -> ActionSeed<Actions, usize>
+where
+    Actions: IntoIterator<Item=ActionSeed> + 'static,
+{
+    ActionSeed::new(move |actions: Actions, output| {
+        let runners = actions
+            .into_iter()
+            .map(|action| (action.with(()).create_runner(Output::default()), Vec::new()))
+            .collect::<Vec<_>>();
+        if runners.is_empty() {
+            panic!("The length of actions passed to `wait::race` must be greater than 0.")
+        }
+
+        RaceRunner { output, runners }
+    })
+}
+
+struct RaceRunner {
+    output: Output<usize>,
+    /// The runners and the ids of the cancellation handlers registered by them.
+    runners: Vec<(BoxedRunner, Vec<CancellationId>)>,
+}
+
+impl Runner for RaceRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let mut winner = None;
+        for (i, (runner, ids)) in self.runners.iter_mut().enumerate() {
+            let status = token.record(ids, |token| runner.run(world, token));
+            match status {
+                RunnerIs::Completed => {
+                    winner.replace(i);
+                    break;
+                }
+                RunnerIs::Canceled => return RunnerIs::Canceled,
+                RunnerIs::Running => {}
+            }
+        }
+        let Some(winner) = winner else {
+            return RunnerIs::Running;
+        };
+        let mut handlers = Vec::new();
+        for (i, (runner, ids)) in core::mem::take(&mut self.runners).into_iter().enumerate() {
+            drop(runner);
+            if i == winner {
+                continue;
+            }
+            handlers.extend(ids.iter().filter_map(|id| token.handlers.remove(id)));
+        }
+        for handler in handlers {
+            handler(world);
+        }
+        self.output.set(winner);
+        RunnerIs::Completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::actions;
+    use crate::prelude::{ActionSeed, Pipe, Reactor};
+    use crate::runner::{CancellationHandlers, Runner, RunnerIs};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Default, Debug, Eq, PartialEq)]
+    struct Canceled(usize);
+
+    struct CancellableRunner;
+
+    impl Runner for CancellableRunner {
+        fn run(&mut self, _: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+            if token.handlers.is_empty() {
+                token.register(|world| {
+                    world.resource_mut::<Canceled>().0 += 1;
+                });
+            }
+            RunnerIs::Running
+        }
+    }
+
+    fn cancellable() -> ActionSeed {
+        ActionSeed::new(|_, _| CancellableRunner)
+    }
+
+    #[test]
+    fn output_winner_index_and_cancel_losers() {
+        let mut app = test_app();
+        app.init_resource::<Canceled>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::race()
+                        .with(actions![
+                            cancellable(),
+                            wait::until(|mut count: ResMut<Count>| {
+                                count.increment();
+                                count.0 == 2
+                            }),
+                        ])
+                        .pipe(once::run(|In(index): In<usize>, mut count: ResMut<Count>| {
+                            count.0 = index * 10;
+                        }))
+                }).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.assert_resource_eq(Canceled(0));
+
+        app.update();
+        app.assert_resource_eq(Count(10));
+        app.assert_resource_eq(Canceled(1));
+    }
+
+    #[test]
+    fn not_run_losers_after_winner() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    wait::race().with(actions![
+                        once::run(|| {}),
+                        wait::until(|mut count: ResMut<Count>| {
+                            count.increment();
+                            false
+                        }),
+                    ])
+                }).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+    }
+}
@@ -0,0 +1,130 @@
+//! [`wait::reactor`] creates a task related to waiting for other reactors.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use crate::reactor::output::ReactorOutputs;
+use crate::reactor::NativeReactor;
+use bevy::prelude::{Entity, In, Local, Query, ResMut, With};
+use std::sync::Arc;
+
+/// Waits until the reactor attached to the passed [`Entity`] has finished, and returns the output of its future.
+///
+/// The output is kept only for the reactors awaited by this action before they finish,
+/// so the output is `None` if the reactor has been cancelled or had already finished when this action started,
+/// the output type is not `O`, or the output has been taken by another waiter.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let child = task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Reactor::schedule(|task| async move{
+///             task.will(Update, delay::frames().with(30)).await;
+///             "done".to_string()
+///         })).id()
+///     })).await;
+///     let output: Option<String> = task.will(Update, wait::reactor::finished().with(child)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn finished<O>() -> ActionSeed<Entity, Option<O>>
+where
+    O: Send + 'static,
+{
+    wait::output(|In(entity): In<Entity>,
+                  mut waiter: Local<Option<Arc<()>>>,
+                  reactors: Query<(), With<NativeReactor>>,
+                  mut outputs: ResMut<ReactorOutputs>| {
+        if reactors.contains(entity) {
+            if waiter.is_none() {
+                outputs.register(entity, waiter.insert(Arc::new(())));
+            }
+            return None;
+        }
+        Some(outputs.take::<O>(entity))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once, wait};
+    use crate::prelude::{Pipe, Reactor};
+    use crate::reactor::output::ReactorOutputs;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Finished(Option<usize>);
+
+    #[test]
+    fn output_of_finished_reactor() {
+        let mut app = test_app();
+        app.init_resource::<Finished>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            let child = commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(2)).await;
+                3_usize
+            })).id();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::reactor::finished::<usize>()
+                    .with(child)
+                    .pipe(once::run(|In(output): In<Option<usize>>, mut finished: ResMut<Finished>| {
+                        finished.0 = output;
+                    })),
+                ).await;
+            }));
+        });
+        for _ in 0..6 {
+            app.update();
+        }
+        app.assert_resource_eq(Finished(Some(3)));
+    }
+
+    #[derive(Debug, Eq, PartialEq)]
+    struct Done;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct FinishedDone(Option<Done>);
+
+    #[test]
+    fn output_of_unit_struct() {
+        let mut app = test_app();
+        app.init_resource::<FinishedDone>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            let child = commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(1)).await;
+                Done
+            })).id();
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(Update, wait::reactor::finished::<Done>()
+                    .with(child)
+                    .pipe(once::run(|In(output): In<Option<Done>>, mut finished: ResMut<FinishedDone>| {
+                        finished.0 = output;
+                    })),
+                ).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(FinishedDone(Some(Done)));
+    }
+
+    #[test]
+    fn not_keep_output_without_waiters() {
+        let mut app = test_app();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, delay::frames().with(1)).await;
+            3_usize
+        }));
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(app.world().resource::<ReactorOutputs>().is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! [`wait::render`] creates a task related to waiting for the renderer.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::app::App;
+use bevy::core::FrameCount;
+use bevy::prelude::{IntoSystemConfigs, Local, Res, Resource};
+use bevy::render::render_resource::{CachedPipelineState, PipelineCache};
+use bevy::render::{Render, RenderApp, RenderSet};
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The number of the pipelines that have not finished compiling, reported from the render world.
+///
+/// It is [`usize::MAX`] until the render world reports it for the first time.
+#[derive(Resource, Clone)]
+pub(crate) struct PendingPipelines(Arc<AtomicUsize>);
+
+/// The [`FrameCount`] of the main world extracted into the last frame that the render world has presented.
+///
+/// The main world increments [`FrameCount`] in [`Last`](bevy::app::Last) before the extraction,
+/// so the frame in which the main world read `n` is presented when this becomes `n + 1`.
+#[derive(Resource, Clone)]
+pub(crate) struct PresentedFrame(Arc<AtomicU32>);
+
+/// Shares [`PendingPipelines`] and [`PresentedFrame`] between the main world and the render world.
+///
+/// This does nothing if the app does not have [`RenderApp`].
+pub(crate) fn setup(app: &mut App) {
+    let pending = PendingPipelines(Arc::new(AtomicUsize::new(usize::MAX)));
+    let presented = PresentedFrame(Arc::new(AtomicU32::new(0)));
+    let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+        return;
+    };
+    render_app
+        .insert_resource(pending.clone())
+        .insert_resource(presented.clone())
+        .add_systems(Render, (
+            report_pending_pipelines,
+            report_presented_frame,
+        ).in_set(RenderSet::Cleanup));
+    app
+        .insert_resource(pending)
+        .insert_resource(presented);
+}
+
+fn report_pending_pipelines(
+    pending: Res<PendingPipelines>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    let count = pipeline_cache
+        .pipelines()
+        .filter(|pipeline| matches!(pipeline.state, CachedPipelineState::Queued | CachedPipelineState::Creating(_)))
+        .count();
+    pending.0.store(count, Ordering::Relaxed);
+}
+
+/// The windows are presented in [`RenderSet::Render`], so the frame extracted into the render world has been presented by the cleanup.
+fn report_presented_frame(
+    presented: Res<PresentedFrame>,
+    frame_count: Option<Res<FrameCount>>,
+) {
+    if let Some(frame_count) = frame_count {
+        presented.0.store(frame_count.0, Ordering::Relaxed);
+    }
+}
+
+/// Waits until all queued render and compute pipelines have finished compiling.
+///
+/// This is useful for holding loading screens until the first frame won't hitch from shader compilation.
+/// Note that the pipelines are queued when the entities using them are extracted,
+/// so spawn them before this action starts.
+///
+/// The pipelines that failed to compile are also treated as finished.
+/// If the app does not have [`RenderApp`], this action completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::render::pipelines_ready()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn pipelines_ready() -> ActionSeed {
+    wait::until(|pending: Option<Res<PendingPipelines>>| {
+        pending.is_none_or(|pending| pending.0.load(Ordering::Relaxed) == 0)
+    })
+}
+
+/// Waits until the frame that was current when this action started has actually been presented.
+///
+/// This is useful for the flows such as screenshots and screen transitions,
+/// which must guarantee that the visual state hit the screen before proceeding.
+/// With pipelined rendering, the frame is presented during the next frame of the main world at the earliest.
+/// If the app does not have [`RenderApp`], this action completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, once::run(|mut commands: Commands|{
+///         commands.spawn(Text::new("Loaded!"));
+///     })).await;
+///     task.will(Update, wait::render::frame_presented()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn frame_presented() -> ActionSeed {
+    wait::until(|mut target: Local<Option<u32>>,
+                 frame_count: Res<FrameCount>,
+                 presented: Option<Res<PresentedFrame>>| {
+        let Some(presented) = presented else {
+            return true;
+        };
+        let target = *target.get_or_insert(frame_count.0.wrapping_add(1));
+        target <= presented.0.load(Ordering::Relaxed)
+    })
+}
@@ -0,0 +1,243 @@
+//! [`wait::res`] creates a task related to waiting for resources.
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::prelude::{DetectChanges, In, Local, Res, Resource};
+
+/// Waits until the resource `R` exists, and returns its clone.
+///
+/// The existence check and the clone are done in the same system run,
+/// so unlike checking existence and then reading the resource in separate actions,
+/// the resource cannot be removed in between.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Config {
+///     volume: f32,
+/// }
+///
+/// Reactor::schedule(|task| async move{
+///     let config: Config = task.will(Update, wait::res::acquire::<Config>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn acquire<R>() -> ActionSeed<(), R>
+where
+    R: Resource + Clone,
+{
+    wait::output(|resource: Option<Res<R>>| resource.map(|resource| resource.clone()))
+}
+
+/// Waits until the resource `R` exists.
+///
+/// Use [`wait::res::acquire`](crate::prelude::wait::res::acquire) instead if you need the value of the resource.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Level;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::res::exists::<Level>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn exists<R>() -> ActionSeed
+where
+    R: Resource,
+{
+    wait::until(|resource: Option<Res<R>>| resource.is_some())
+}
+
+/// Waits until the resource `R` does not exist.
+///
+/// If the resource does not exist when this action starts, it completes immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource)]
+/// struct Loading;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::res::removed::<Loading>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn removed<R>() -> ActionSeed
+where
+    R: Resource,
+{
+    wait::until(|resource: Option<Res<R>>| resource.is_none())
+}
+
+/// Waits until the resource `R` is changed after this action starts, and returns its clone.
+///
+/// The change is detected by [`Res::is_changed`], so it also completes when the resource is inserted.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone)]
+/// struct Score(u32);
+///
+/// Reactor::schedule(|task| async move{
+///     let score: Score = task.will(Update, wait::res::changed::<Score>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn changed<R>() -> ActionSeed<(), R>
+where
+    R: Resource + Clone,
+{
+    wait::output(|mut started: Local<bool>, resource: Option<Res<R>>| {
+        // The first run of a system regards all resources as changed, so skip it.
+        if !std::mem::replace(&mut *started, true) {
+            return None;
+        }
+        resource
+            .filter(|resource| resource.is_changed())
+            .map(|resource| resource.clone())
+    })
+}
+
+/// Waits until the resource `R` becomes equal to the passed value.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Clone, PartialEq)]
+/// struct Wave(u32);
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::res::becomes().with(Wave(3))).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn becomes<R>() -> ActionSeed<R>
+where
+    R: Resource + PartialEq + Clone,
+{
+    wait::until(|In(expect): In<R>, resource: Option<Res<R>>| {
+        resource.is_some_and(|resource| *resource == expect)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::increment_count;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, In, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Clone, PartialEq)]
+    struct Config(usize);
+
+    #[test]
+    fn acquire_after_inserted() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::acquire::<Config>()
+                    .pipe(once::run(|In(config): In<Config>, mut count: ResMut<Count>| {
+                        count.0 = config.0;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Config(3));
+        app.update();
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn wait_exists_and_removed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::exists::<Config>().then(increment_count())).await;
+                task.will(Update, wait::res::removed::<Config>().then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Config(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        app.world_mut().remove_resource::<Config>();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn wait_changed_after_started() {
+        let mut app = test_app();
+        app.insert_resource(Config(1));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::changed::<Config>()
+                    .pipe(once::run(|In(config): In<Config>, mut count: ResMut<Count>| {
+                        count.0 = config.0;
+                    })),
+                ).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Config(5));
+        app.update();
+        app.assert_resource_eq(Count(5));
+    }
+
+    #[test]
+    fn wait_becomes() {
+        let mut app = test_app();
+        app.insert_resource(Config(0));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::res::becomes().with(Config(2)).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.insert_resource(Config(1));
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.insert_resource(Config(2));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,43 @@
+//! [`wait::scene`] creates a task related to waiting to scenes.
+
+use crate::action::wait;
+use crate::prelude::seed::ActionSeed;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::{In, Res};
+use bevy::scene::{InstanceId, SceneSpawner};
+
+/// Waits until all entities of the scene instance associated with the passed [`InstanceId`] have been despawned.
+///
+/// This is useful for level-switch reactors to guarantee that the old scene has gone before spawning the new one.
+///
+/// Note that this action is completed immediately if the instance has not been spawned yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::scene::InstanceId;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let old_level: InstanceId = task.will(Update, once::run(|asset_server: Res<AssetServer>, mut spawner: ResMut<SceneSpawner>|{
+///         spawner.spawn(asset_server.load("<old_level_path>"))
+///     })).await;
+///     task.will(Update, {
+///         once::scene::despawn()
+///             .with(old_level)
+///             .then(wait::scene::unloaded().with(old_level))
+///             .then(once::run(|asset_server: Res<AssetServer>, mut spawner: ResMut<SceneSpawner>|{
+///                 spawner.spawn(asset_server.load("<new_level_path>"));
+///             }))
+///     }).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn unloaded() -> ActionSeed<InstanceId> {
+    wait::until(|In(instance): In<InstanceId>, spawner: Res<SceneSpawner>, entities: &Entities| {
+        spawner
+            .iter_instance_entities(instance)
+            .all(|entity| !entities.contains(entity))
+    })
+}
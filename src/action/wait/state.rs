@@ -1,6 +1,7 @@
 //! [`wait::state`] creates a task related to waiting to state update.
 
-use bevy::prelude::{In, Res, State, States};
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::{Events, In, Local, Res, State, StateTransitionEvent, States};
 use crate::action::wait;
 use crate::prelude::ActionSeed;
 
@@ -34,11 +35,85 @@ where
     })
 }
 
+/// Waits until the state `S` transitions, and returns the newly entered state.
+///
+/// Unlike [`wait::state::becomes`], this reads [`StateTransitionEvent`],
+/// so the transitions that happen and revert within one frame are not missed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{States, World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(States, Eq, PartialEq, Copy, Clone, Hash, Default, Debug)]
+/// enum Status{
+///     #[default]
+///     First,
+///     Second
+/// }
+///
+/// Reactor::schedule(|task| async move {
+///     let entered: Status = task.will(Update, wait::state::enters::<Status>()).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn enters<S>() -> ActionSeed<(), S>
+where
+    S: States + 'static,
+{
+    wait::output(|mut cursor: Local<Option<EventCursor<StateTransitionEvent<S>>>>,
+                  events: Res<Events<StateTransitionEvent<S>>>| {
+        cursor
+            .get_or_insert_with(|| events.get_cursor_current())
+            .read(&events)
+            .find_map(|event| event.entered.clone())
+    })
+}
+
+/// Waits until the state leaves the specified.
+///
+/// Like [`wait::state::enters`], this reads [`StateTransitionEvent`],
+/// so the transitions that happen and revert within one frame are not missed.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::{States, World, Update};
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(States, Eq, PartialEq, Copy, Clone, Hash, Default, Debug)]
+/// enum Status{
+///     #[default]
+///     First,
+///     Second
+/// }
+///
+/// Reactor::schedule(|task| async move {
+///     task.will(Update, wait::state::exits().with(Status::First)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn exits<S>() -> ActionSeed<S>
+where
+    S: States + 'static,
+{
+    wait::until(|In(expect): In<S>,
+                 mut cursor: Local<Option<EventCursor<StateTransitionEvent<S>>>>,
+                 events: Res<Events<StateTransitionEvent<S>>>| {
+        cursor
+            .get_or_insert_with(|| events.get_cursor_current())
+            .read(&events)
+            .any(|event| event.exited.as_ref() == Some(&expect))
+    })
+}
 
 #[cfg(test)]
 mod tests {
     use bevy::app::{AppExit, First, Startup, Update};
-    use bevy::prelude::{AppExtStates, Commands, States};
+    use bevy::prelude::{AppExtStates, Commands, In, NextState, ResMut, States, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
 
     use crate::prelude::*;
     use crate::tests::test_app;
@@ -68,4 +143,56 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn wait_until_enters() {
+        let mut app = test_app();
+        app
+            .init_state::<TestState>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, wait::state::enters::<TestState>()
+                        .pipe(once::run(|In(state): In<TestState>, mut count: ResMut<Count>| {
+                            assert_eq!(state, TestState::Phase2);
+                            count.increment();
+                        })),
+                    ).await;
+                }));
+            });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.world_mut().resource_mut::<NextState<TestState>>().set(TestState::Phase2);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn not_miss_exit_reverted_within_one_frame() {
+        let mut app = test_app();
+        app
+            .init_state::<TestState>()
+            .add_systems(Startup, |mut commands: Commands| {
+                commands.spawn(Reactor::schedule(|task| async move {
+                    task.will(Update, wait::state::exits().with(TestState::Phase1)
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        })),
+                    ).await;
+                }));
+            });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        let world: &mut World = app.world_mut();
+        world.resource_mut::<NextState<TestState>>().set(TestState::Phase2);
+        world.run_schedule(bevy::state::state::StateTransition);
+        world.resource_mut::<NextState<TestState>>().set(TestState::Phase1);
+        app.update();
+        assert_eq!(app.world().resource::<bevy::prelude::State<TestState>>().get(), &TestState::Phase1);
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::action::wait;
+use crate::prelude::ActionSeed;
+use crate::reactor::sub::SubReactorHandle;
+use crate::reactor::NativeReactor;
+use bevy::prelude::{In, Query, With};
+
+/// Waits until the child reactor forked by [`ReactorTask::spawn`](crate::prelude::ReactorTask::spawn) is completed or cancelled.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let child = task.spawn(|task| async move{
+///         task.will(Update, delay::frames().with(30)).await;
+///     }).await;
+///     task.will(Update, wait::sub_reactor().with(child)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn sub_reactor() -> ActionSeed<SubReactorHandle> {
+    wait::until(|In(handle): In<SubReactorHandle>, reactors: Query<(), With<NativeReactor>>| {
+        !reactors.contains(handle.0)
+    })
+}
@@ -0,0 +1,116 @@
+//! [`wait::tick`] creates a task related to waiting for the app ticks.
+//!
+//! These actions only depend on [`FrameCount`], so they can be used in headless apps such as dedicated servers
+//! that are built with [`MinimalPlugins`](bevy::prelude::MinimalPlugins).
+
+use crate::action::seed::ActionSeed;
+use crate::action::wait;
+use bevy::core::FrameCount;
+use bevy::prelude::{In, Local, Res};
+
+/// Waits until the app has ticked the specified number of times since this action started.
+///
+/// The ticks are counted with [`FrameCount`], so unlike [`delay::frames`](crate::prelude::delay::frames),
+/// the count does not depend on how many times the schedule running this action is executed per tick.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::tick::count().with(60)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn count() -> ActionSeed<u32> {
+    wait::until(|In(ticks): In<u32>, mut start: Local<Option<u32>>, frame_count: Res<FrameCount>| {
+        let start = *start.get_or_insert(frame_count.0);
+        ticks <= frame_count.0.wrapping_sub(start)
+    })
+}
+
+/// Waits until [`FrameCount`] reaches the specified absolute tick.
+///
+/// Unlike [`wait::tick::count`](count), the tick does not depend on when this action started,
+/// so the steps of deterministic lockstep simulations can be scheduled at absolute ticks.
+/// If the tick has already passed, this action is completed immediately.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     task.will(Update, wait::tick::reach().with(120)).await;
+/// });
+/// ```
+#[inline(always)]
+pub fn reach() -> ActionSeed<u32> {
+    wait::until(|In(tick): In<u32>, frame_count: Res<FrameCount>| {
+        tick <= frame_count.0
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor, Then};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{FixedUpdate, Startup, Update};
+    use bevy::core::FrameCount;
+    use bevy::prelude::{Commands, ResMut};
+    use bevy::time::{Fixed, Time, TimeUpdateStrategy};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn wait_ticks_regardless_of_schedule_runs() {
+        let mut app = test_app();
+        app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(10)));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(30)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(FixedUpdate, {
+                    once::run(|| 2)
+                        .pipe(wait::tick::count())
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))
+                }).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn wait_until_reach_absolute_tick() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let start = task.frame().await;
+                task.will(Update, wait::tick::reach().with(start + 3).then(increment_count())).await;
+                task.will(Update, wait::tick::reach().with(start + 1).then(increment_count())).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.update();
+        assert_eq!(app.world().resource::<FrameCount>().0, 4);
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+}
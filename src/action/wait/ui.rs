@@ -0,0 +1,265 @@
+//! [`wait::ui`] creates a task related to waiting to the UI.
+
+use crate::action::wait;
+use crate::prelude::{ActionSeed, UiFocus};
+use bevy::prelude::{Entity, Local, Res};
+#[cfg(feature = "bevy_ui")]
+use bevy::{
+    prelude::{Component, In, Query, With},
+    ui::Interaction,
+    utils::HashSet,
+};
+
+/// Waits until [`UiFocus`] changes, and outputs the newly focused entity.
+///
+/// The focus at the time this action starts is used as the baseline,
+/// so setting the same entity again is not regarded as a change.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     loop {
+///         let focused: Option<Entity> = task.will(Update, wait::ui::focus_changed()).await;
+///         info!("focused: {focused:?}");
+///     }
+/// });
+/// ```
+#[inline(always)]
+pub fn focus_changed() -> ActionSeed<(), Option<Entity>> {
+    wait::output(|mut baseline: Local<Option<Option<Entity>>>, focus: Res<UiFocus>| {
+        let baseline = *baseline.get_or_insert(focus.get());
+        (baseline != focus.get()).then_some(focus.get())
+    })
+}
+
+/// Waits until the UI node on the passed [`Entity`] is clicked.
+///
+/// A click is regarded as completed when [`Interaction`] returns from [`Interaction::Pressed`] to [`Interaction::Hovered`],
+/// that is, the button is released while the cursor is still over the node.
+/// If the entity has no [`Interaction`], this action keeps waiting.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let start_button = Entity::PLACEHOLDER;
+///     task.will(Update, wait::ui::clicked().with(start_button)).await;
+/// });
+/// ```
+#[cfg(feature = "bevy_ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_ui")))]
+#[inline(always)]
+pub fn clicked() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, mut was_pressed: Local<bool>, interactions: Query<&Interaction>| {
+        interactions
+            .get(entity)
+            .is_ok_and(|interaction| track_click(&mut was_pressed, interaction))
+    })
+}
+
+/// Waits until the UI node on the passed [`Entity`] is pressed.
+///
+/// Unlike [`wait::ui::clicked`](crate::prelude::wait::ui::clicked), this action completes as soon as the button goes down.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let fire_button = Entity::PLACEHOLDER;
+///     task.will(Update, wait::ui::pressed().with(fire_button)).await;
+/// });
+/// ```
+#[cfg(feature = "bevy_ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_ui")))]
+#[inline(always)]
+pub fn pressed() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, interactions: Query<&Interaction>| {
+        interactions
+            .get(entity)
+            .is_ok_and(|interaction| interaction == &Interaction::Pressed)
+    })
+}
+
+/// Waits until the cursor hovers over the UI node on the passed [`Entity`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let help_button = Entity::PLACEHOLDER;
+///     task.will(Update, wait::ui::hovered().with(help_button)).await;
+/// });
+/// ```
+#[cfg(feature = "bevy_ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_ui")))]
+#[inline(always)]
+pub fn hovered() -> ActionSeed<Entity> {
+    wait::until(|In(entity): In<Entity>, interactions: Query<&Interaction>| {
+        interactions
+            .get(entity)
+            .is_ok_and(|interaction| interaction == &Interaction::Hovered)
+    })
+}
+
+/// Waits until any UI node with the component `M` is clicked, and outputs its entity.
+///
+/// A click is determined in the same way as [`wait::ui::clicked`](crate::prelude::wait::ui::clicked).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct MenuButton;
+///
+/// Reactor::schedule(|task| async move{
+///     let choice: Entity = task.will(Update, wait::ui::any_clicked::<MenuButton>()).await;
+/// });
+/// ```
+#[cfg(feature = "bevy_ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bevy_ui")))]
+#[inline(always)]
+pub fn any_clicked<M>() -> ActionSeed<(), Entity>
+where
+    M: Component,
+{
+    wait::output(|mut pressed: Local<HashSet<Entity>>, interactions: Query<(Entity, &Interaction), With<M>>| {
+        let mut clicked = None;
+        for (entity, interaction) in interactions.iter() {
+            let mut was_pressed = pressed.contains(&entity);
+            if track_click(&mut was_pressed, interaction) {
+                clicked.get_or_insert(entity);
+            }
+            if was_pressed {
+                pressed.insert(entity);
+            } else {
+                pressed.remove(&entity);
+            }
+        }
+        clicked
+    })
+}
+
+/// Updates whether the node has been pressed, and returns true if it has just been clicked.
+#[cfg(feature = "bevy_ui")]
+fn track_click(was_pressed: &mut bool, interaction: &Interaction) -> bool {
+    match interaction {
+        Interaction::Pressed => {
+            *was_pressed = true;
+            false
+        }
+        Interaction::Hovered => core::mem::take(was_pressed),
+        Interaction::None => {
+            *was_pressed = false;
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Pipe, Reactor, UiFocus};
+    use crate::tests::test_app;
+    use bevy::app::Update;
+    use bevy::prelude::{Entity, In, ResMut, Resource};
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource, Debug, Default, Eq, PartialEq)]
+    struct Focused(Option<Option<Entity>>);
+
+    #[test]
+    fn wait_focus_changed() {
+        let mut app = test_app();
+        app.init_resource::<Focused>();
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, wait::ui::focus_changed().pipe(once::run(|In(focus): In<Option<Entity>>, mut focused: ResMut<Focused>| {
+                focused.0 = Some(focus);
+            }))).await;
+        }));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Focused(None));
+
+        let button = app.world_mut().spawn_empty().id();
+        app.world_mut().resource_mut::<UiFocus>().0 = Some(button);
+        app.update();
+        app.assert_resource_eq(Focused(Some(Some(button))));
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn wait_clicked() {
+        use crate::prelude::Then;
+        use crate::tests::increment_count;
+        use bevy::ui::Interaction;
+        use bevy_test_helper::resource::count::Count;
+
+        let mut app = test_app();
+        let button = app.world_mut().spawn(Interaction::None).id();
+        app.world_mut().spawn(Reactor::schedule(move |task| async move {
+            task.will(Update, wait::ui::clicked().with(button).then(increment_count())).await;
+        }));
+        app.update();
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[cfg(feature = "bevy_ui")]
+    #[test]
+    fn wait_any_clicked() {
+        use bevy::prelude::Component;
+        use bevy::ui::Interaction;
+
+        #[derive(Component)]
+        struct MenuButton;
+
+        let mut app = test_app();
+        app.init_resource::<Focused>();
+        let buttons = [
+            app.world_mut().spawn((MenuButton, Interaction::None)).id(),
+            app.world_mut().spawn((MenuButton, Interaction::None)).id(),
+        ];
+        app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, wait::ui::any_clicked::<MenuButton>().pipe(once::run(|In(button): In<Entity>, mut focused: ResMut<Focused>| {
+                focused.0 = Some(Some(button));
+            }))).await;
+        }));
+        app.update();
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(buttons[0]).unwrap() = Interaction::Pressed;
+        app.update();
+        *app.world_mut().get_mut::<Interaction>(buttons[0]).unwrap() = Interaction::None;
+        *app.world_mut().get_mut::<Interaction>(buttons[1]).unwrap() = Interaction::Pressed;
+        app.update();
+        app.assert_resource_eq(Focused(None));
+
+        *app.world_mut().get_mut::<Interaction>(buttons[1]).unwrap() = Interaction::Hovered;
+        app.update();
+        app.update();
+        app.assert_resource_eq(Focused(Some(Some(buttons[1]))));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::action::remake::Remake;
+use crate::prelude::{CancellationHandlers, ReactorProgress};
+use crate::runner::progress::{self, CurrentProgressStage, Progress, ProgressReceiver};
+use crate::runner::{BoxedRunner, CurrentReactor, Output, Runner, RunnerIs};
+use bevy::prelude::World;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Aggregates the progress of an action into [`ReactorProgress`].
+pub trait Weighted<I1, O1, ActionOrSeed> {
+    /// Creates an action that contributes `weight` of the whole to [`ReactorProgress`] of the reactor.
+    ///
+    /// While the action is running, [`Progress<f32>`] is inserted as a resource,
+    /// so the systems of the action can report its own progress in the range `0.0..=1.0`.
+    /// Instead of reporting it directly, the action can also be composed of weighted actions,
+    /// in which case their weights are the fractions of this action.
+    /// When the action is completed, its whole weight is counted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, {
+    ///         wait::until(|mut frames: Local<u32>, progress: Res<Progress<f32>>|{
+    ///             *frames += 1;
+    ///             progress.set(*frames as f32 / 60.);
+    ///             *frames == 60
+    ///         })
+    ///             .weighted(0.6)
+    ///             .then(once::run(|| {}).weighted(0.3))
+    ///             .then(delay::frames().with(10).weighted(0.1))
+    ///     }).await;
+    /// });
+    ///
+    /// fn loading_bar(reactors: Query<&ReactorProgress>){
+    ///     for progress in reactors.iter(){
+    ///         info!("loading: {:.0}%", progress.get() * 100.);
+    ///     }
+    /// }
+    /// ```
+    fn weighted(self, weight: f32) -> ActionOrSeed;
+}
+
+impl<I, O, A, Re> Weighted<I, O, A> for Re
+where
+    I: 'static,
+    O: 'static,
+    Re: Remake<I, O, O, A> + 'static,
+{
+    #[inline]
+    fn weighted(self, weight: f32) -> A {
+        static ID: AtomicU64 = AtomicU64::new(0);
+        self.remake(move |runner, o1, output| {
+            let (progress, receiver) = progress::channel();
+            WeightedRunner {
+                runner,
+                o1,
+                output,
+                id: ID.fetch_add(1, Ordering::Relaxed),
+                weight,
+                parent: None,
+                progress,
+                receiver,
+            }
+        })
+    }
+}
+
+struct WeightedRunner<O> {
+    runner: BoxedRunner,
+    o1: Output<O>,
+    output: Output<O>,
+    id: u64,
+    weight: f32,
+    parent: Option<Option<u64>>,
+    progress: Progress<f32>,
+    receiver: ProgressReceiver<f32>,
+}
+
+impl<O> Runner for WeightedRunner<O>
+where
+    O: 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let outer_stage = world.remove_resource::<CurrentProgressStage>();
+        let parent = *self.parent.get_or_insert(outer_stage.map(|stage| stage.0));
+        let outer_progress = world.remove_resource::<Progress<f32>>();
+        world.insert_resource(CurrentProgressStage(self.id));
+        world.insert_resource(self.progress.clone());
+
+        let status = self.runner.run(world, token);
+
+        world.remove_resource::<CurrentProgressStage>();
+        world.remove_resource::<Progress<f32>>();
+        if let Some(outer_stage) = outer_stage {
+            world.insert_resource(outer_stage);
+        }
+        if let Some(outer_progress) = outer_progress {
+            world.insert_resource(outer_progress);
+        }
+
+        let completed = matches!(status, RunnerIs::Completed);
+        if let Some(reactor) = world.get_resource::<CurrentReactor>().map(|reactor| reactor.0) {
+            if let Ok(mut entity) = world.get_entity_mut(reactor) {
+                entity.entry::<ReactorProgress>().or_default().report(
+                    self.id,
+                    parent,
+                    self.weight,
+                    self.receiver.take(),
+                    completed,
+                );
+            }
+        }
+        if completed {
+            self.output.set(self.o1.take().expect("Failed to take the output of the weighted action"));
+        }
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Progress, Reactor, ReactorProgress, Then, Weighted};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Local, Res};
+
+    fn assert_progress(app: &mut bevy::app::App, expect: f32) {
+        let progress = app.world_mut().query::<&ReactorProgress>().single(app.world()).get();
+        assert!((progress - expect).abs() < 1e-5, "progress: {progress}, expect: {expect}");
+    }
+
+    fn report_in_frames(frames: u32) -> crate::prelude::ActionSeed {
+        wait::until(move |mut count: Local<u32>, progress: Res<Progress<f32>>| {
+            *count += 1;
+            progress.set(*count as f32 / frames as f32);
+            *count == frames
+        })
+    }
+
+    #[test]
+    fn aggregate_weighted_progress() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    report_in_frames(2).weighted(0.6)
+                        .then(once::run(|| {}).weighted(0.3))
+                        .then(wait::until(|| false).weighted(0.1))
+                }).await;
+            }));
+        });
+        app.update();
+        assert_progress(&mut app, 0.3);
+        app.update();
+        assert_progress(&mut app, 0.9);
+        app.update();
+        assert_progress(&mut app, 0.9);
+    }
+
+    #[test]
+    fn aggregate_nested_progress() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, {
+                    once::run(|| {}).weighted(0.5)
+                        .then(report_in_frames(4).weighted(0.5))
+                        .weighted(0.5)
+                        .then(wait::until(|| false).weighted(0.5))
+                }).await;
+            }));
+        });
+        app.update();
+        assert_progress(&mut app, 0.3125);
+        app.update();
+        assert_progress(&mut app, 0.375);
+        app.update();
+        app.update();
+        assert_progress(&mut app, 0.5);
+    }
+}
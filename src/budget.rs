@@ -0,0 +1,160 @@
+//! Provides the instrumentation that warns when an action takes too long in a frame.
+//!
+//! Insert [`ActionTimeBudget`] as a resource to find the reactor step that is blowing the frame budget.
+//! Each overrun is also sent as [`ActionOverBudget`], so tools can collect them.
+
+use crate::prelude::{CancellationHandlers, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use bevy::core::Name;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::log::warn;
+use bevy::prelude::{Entity, Event, Resource, World};
+use bevy::utils::Instant;
+use std::time::Duration;
+
+/// The resource that enables measuring the time each action takes to run per frame.
+///
+/// While this resource exists, the actions passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will) are measured,
+/// and a warning naming the action and the reactor is logged and [`ActionOverBudget`] is sent when a single run exceeds the threshold.
+/// The name of the action is given by [`ActionSeed::named`](crate::prelude::ActionSeed::named),
+/// and the reactor is shown by its [`Name`] if the entity has it.
+///
+/// Note that only the actions created while this resource exists are measured.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         FlurxPlugin,
+///     ))
+///     .insert_resource(ActionTimeBudget(Duration::from_millis(2)));
+/// ```
+#[derive(Resource, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ActionTimeBudget(pub Duration);
+
+/// The event sent when a single run of an action exceeds [`ActionTimeBudget`].
+#[derive(Event, Debug, Clone, Eq, PartialEq)]
+pub struct ActionOverBudget {
+    /// The reactor entity running the action.
+    pub reactor: Entity,
+    /// The name of the action given by [`ActionSeed::named`](crate::prelude::ActionSeed::named).
+    pub action: Option<String>,
+    /// The time the run took.
+    pub elapsed: Duration,
+}
+
+/// Wraps the runner to measure its run if [`ActionTimeBudget`] exists.
+pub(crate) fn create_runner(
+    world: &World,
+    reactor: Entity,
+    name: Option<String>,
+    runner: BoxedRunner,
+) -> BoxedRunner {
+    if world.contains_resource::<ActionTimeBudget>() {
+        BoxedRunner::new(BudgetRunner {
+            runner,
+            reactor,
+            name,
+        })
+    } else {
+        runner
+    }
+}
+
+struct BudgetRunner {
+    runner: BoxedRunner,
+    reactor: Entity,
+    name: Option<String>,
+}
+
+impl Runner for BudgetRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let start = Instant::now();
+        let status = self.runner.run(world, token);
+        let elapsed = start.elapsed();
+        if let Some(ActionTimeBudget(threshold)) = world.get_resource::<ActionTimeBudget>().copied() {
+            if threshold < elapsed {
+                let reactor = world
+                    .get::<Name>(self.reactor)
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| self.reactor.to_string());
+                warn!(
+                    "The action `{}` of the reactor `{reactor}` took {elapsed:?}, which exceeds the budget {threshold:?}.",
+                    self.name.as_deref().unwrap_or("<unnamed>"),
+                );
+                world.send_event(ActionOverBudget {
+                    reactor: self.reactor,
+                    action: self.name.clone(),
+                    elapsed,
+                });
+            }
+        }
+        status
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.runner.access()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once};
+    use crate::prelude::{ActionOverBudget, ActionTimeBudget, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Events, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn run_measured_actions() {
+        let mut app = test_app();
+        app.insert_resource(ActionTimeBudget(Duration::ZERO));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let num = task.will(Update, {
+                    delay::frames().with(1)
+                        .then(once::run(|| 3))
+                        .named("three")
+                }).await;
+                task.will(Update, once::run(move |mut count: ResMut<Count>| {
+                    count.0 = num;
+                })).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    fn send_over_budget_only_for_slow_action() {
+        let mut app = test_app();
+        app.insert_resource(ActionTimeBudget(Duration::from_millis(10)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {}).named("fast")).await;
+                task.will(Update, once::run(|| std::thread::sleep(Duration::from_millis(20))).named("slow")).await;
+            }));
+        });
+        let mut overruns = Vec::new();
+        for _ in 0..3 {
+            app.update();
+            overruns.extend(app.world_mut().resource_mut::<Events<ActionOverBudget>>().drain());
+        }
+        assert_eq!(overruns.len(), 1);
+        assert_eq!(overruns[0].action.as_deref(), Some("slow"));
+        assert!(Duration::from_millis(10) < overruns[0].elapsed);
+    }
+}
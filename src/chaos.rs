@@ -0,0 +1,234 @@
+//! Provides the fault injection for testing the error paths of reactors deterministically.
+//!
+//! Insert [`Chaos`] as a resource to make the actions named by [`ActionSeed::named`](crate::prelude::ActionSeed::named)
+//! stall, cancel or fail.
+
+use crate::action::Action;
+use crate::prelude::{CancellationHandlers, Runner, RunnerIs};
+use crate::runner::{BoxedRunner, Output};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::log::warn;
+use bevy::prelude::{Resource, World};
+use bevy::utils::HashMap;
+use std::any::Any;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// The resource that configures the faults injected into the named actions.
+///
+/// The faults are applied to the actions passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will)
+/// while this resource exists, and the middlewares registered by [`FlurxPlugin::with_middleware`](crate::prelude::FlurxPlugin::with_middleware)
+/// wrap the faulty actions as well as the normal ones.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(FlurxPlugin);
+/// app.insert_resource(Chaos::default()
+///     .stall("load_level", 30)
+///     .fail("save", || Err::<(), _>("disk full"))
+/// );
+/// app.add_systems(Startup, |mut commands: Commands|{
+///     commands.spawn(Reactor::schedule(|task| async move{
+///         let result = task.will(Update, once::run(|| Ok::<(), &str>(())).named("save")).await;
+///         assert!(result.is_err());
+///     }));
+/// });
+/// ```
+#[derive(Resource, Default)]
+pub struct Chaos(HashMap<Cow<'static, str>, Fault>);
+
+enum Fault {
+    Stall(usize),
+    Cancel,
+    Fail(Arc<dyn Fn() -> Box<dyn Any> + Send + Sync>),
+}
+
+impl Chaos {
+    /// Makes the action named `name` wait for the specified number of extra frames before it starts.
+    #[inline]
+    pub fn stall(mut self, name: impl Into<Cow<'static, str>>, frames: usize) -> Self {
+        self.0.insert(name.into(), Fault::Stall(frames));
+        self
+    }
+
+    /// Makes the action named `name` cancel the reactor instead of running.
+    #[inline]
+    pub fn cancel(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.0.insert(name.into(), Fault::Cancel);
+        self
+    }
+
+    /// Makes the action named `name` output the value returned from `f` immediately instead of running.
+    ///
+    /// This is mainly used to return `Err` from the actions that output [`Result`].
+    /// If the type of the value differs from the output type of the action, the fault is ignored with a warning.
+    #[inline]
+    pub fn fail<O>(mut self, name: impl Into<Cow<'static, str>>, f: impl Fn() -> O + Send + Sync + 'static) -> Self
+    where
+        O: 'static,
+    {
+        self.0.insert(name.into(), Fault::Fail(Arc::new(move || Box::new(f()))));
+        self
+    }
+
+    /// Removes the fault of the action named `name`.
+    #[inline]
+    pub fn remove(&mut self, name: &str) {
+        self.0.remove(name);
+    }
+}
+
+/// Creates the runner of the action with the fault configured in [`Chaos`].
+pub(crate) fn create_runner<I, O>(
+    world: &World,
+    name: Option<&str>,
+    action: Action<I, O>,
+    output: Output<O>,
+) -> BoxedRunner
+where
+    I: 'static,
+    O: 'static,
+{
+    let Some(fault) = name.and_then(|name| world.get_resource::<Chaos>()?.0.get(name)) else {
+        return action.create_runner(output);
+    };
+    match fault {
+        Fault::Stall(frames) => BoxedRunner::new(StallRunner {
+            frames: *frames,
+            runner: action.create_runner(output),
+        }),
+        Fault::Cancel => BoxedRunner::new(CancelRunner),
+        Fault::Fail(f) => match f().downcast::<O>() {
+            Ok(out) => {
+                output.set(*out);
+                BoxedRunner::new(CompletedRunner)
+            }
+            Err(_) => {
+                warn!("The fault of `{}` was ignored because its output type differs from the action.", name.unwrap_or_default());
+                action.create_runner(output)
+            }
+        },
+    }
+}
+
+struct StallRunner {
+    frames: usize,
+    runner: BoxedRunner,
+}
+
+impl Runner for StallRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        if 0 < self.frames {
+            self.frames -= 1;
+            RunnerIs::Running
+        } else {
+            self.runner.run(world, token)
+        }
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.runner.access()
+    }
+}
+
+struct CancelRunner;
+
+impl Runner for CancelRunner {
+    #[inline(always)]
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        RunnerIs::Canceled
+    }
+}
+
+struct CompletedRunner;
+
+impl Runner for CompletedRunner {
+    #[inline(always)]
+    fn run(&mut self, _: &mut World, _: &mut CancellationHandlers) -> RunnerIs {
+        RunnerIs::Completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Chaos, Reactor};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn stall_named_action() {
+        let mut app = test_app();
+        app.insert_resource(Chaos::default().stall("count", 2));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, increment_count().named("count")).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn cancel_named_action() {
+        let mut app = test_app();
+        app.insert_resource(Chaos::default().cancel("cancel"));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {}).named("cancel")).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+    }
+
+    #[test]
+    fn fail_named_action() {
+        let mut app = test_app();
+        app.insert_resource(Chaos::default().fail("save", || Err::<usize, _>("failed")));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let result = task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                    Ok::<usize, &str>(1)
+                }).named("save")).await;
+                assert_eq!(result, Err("failed"));
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 = 10;
+                })).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn ignore_fault_if_output_type_differs() {
+        let mut app = test_app();
+        app.insert_resource(Chaos::default().fail("count", || "failed"));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, increment_count().named("count")).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
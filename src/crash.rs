@@ -0,0 +1,251 @@
+//! Provides the crash dumps of reactors for bug reports.
+//!
+//! Insert [`CrashDumps`] as a resource to catch panics in actions and capture the world context at that time.
+
+use crate::prelude::{CancellationHandlers, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::prelude::{AppTypeRegistry, Component, Entity, ReflectResource, Resource, World};
+use std::any::{Any, TypeId};
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// The resource that enables catching panics in actions and stores the crash dumps.
+///
+/// While this resource exists, if an action passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will) panics,
+/// the panic is caught, the reactor is canceled, and [`CrashDump`] is pushed into this resource instead of aborting the app.
+///
+/// Note that only the panics in actions are caught, not the ones in the async block of [`Reactor`](crate::prelude::Reactor) itself,
+/// and only the actions created while this resource exists are guarded.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Resource, Reflect, Debug)]
+/// #[reflect(Resource)]
+/// struct Score(u32);
+///
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         FlurxPlugin,
+///     ))
+///     .register_type::<Score>()
+///     .insert_resource(CrashDumps::default().with_resource::<Score>())
+///     .add_systems(Last, |mut dumps: ResMut<CrashDumps>|{
+///         for dump in dumps.take(){
+///             error!("{dump:?}");
+///         }
+///     });
+/// ```
+#[derive(Resource, Debug)]
+pub struct CrashDumps {
+    dumps: Vec<CrashDump>,
+    resources: Vec<TypeId>,
+    history_len: usize,
+}
+
+impl Default for CrashDumps {
+    fn default() -> Self {
+        Self {
+            dumps: Vec::new(),
+            resources: Vec::new(),
+            history_len: 8,
+        }
+    }
+}
+
+impl CrashDumps {
+    /// Captures the debug string of the resource `R` in the crash dumps.
+    ///
+    /// `R` must be registered in [`AppTypeRegistry`] with `#[reflect(Resource)]`.
+    #[inline]
+    pub fn with_resource<R: Resource>(mut self) -> Self {
+        self.resources.push(TypeId::of::<R>());
+        self
+    }
+
+    /// Sets the number of recent named actions captured in the crash dumps.
+    ///
+    /// The default is 8.
+    #[inline]
+    pub fn with_history_len(mut self, len: usize) -> Self {
+        self.history_len = len;
+        self
+    }
+
+    /// Returns the captured crash dumps.
+    #[inline(always)]
+    pub fn dumps(&self) -> &[CrashDump] {
+        &self.dumps
+    }
+
+    /// Takes the captured crash dumps.
+    #[inline]
+    pub fn take(&mut self) -> Vec<CrashDump> {
+        std::mem::take(&mut self.dumps)
+    }
+}
+
+/// The world context captured when an action panicked.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CrashDump {
+    /// The entity to which the crashed reactor was attached.
+    pub reactor: Entity,
+    /// The panic message.
+    pub message: String,
+    /// The names of the recent actions of the reactor given by [`ActionSeed::named`](crate::prelude::ActionSeed::named), from oldest to newest.
+    pub recent_actions: Vec<String>,
+    /// The type paths and debug strings of the resources specified by [`CrashDumps::with_resource`].
+    pub resources: Vec<(String, String)>,
+}
+
+#[derive(Component, Default)]
+struct ActionHistory(VecDeque<String>);
+
+/// Wraps the runner to catch its panic if [`CrashDumps`] exists.
+pub(crate) fn create_runner(
+    world: &World,
+    reactor: Entity,
+    name: Option<String>,
+    runner: BoxedRunner,
+) -> BoxedRunner {
+    if world.contains_resource::<CrashDumps>() {
+        BoxedRunner::new(CrashGuardRunner {
+            runner,
+            reactor,
+            name,
+        })
+    } else {
+        runner
+    }
+}
+
+struct CrashGuardRunner {
+    runner: BoxedRunner,
+    reactor: Entity,
+    name: Option<String>,
+}
+
+impl CrashGuardRunner {
+    fn record_history(&mut self, world: &mut World) {
+        let Some(name) = self.name.take() else {
+            return;
+        };
+        let Some(len) = world.get_resource::<CrashDumps>().map(|dumps| dumps.history_len) else {
+            return;
+        };
+        let Ok(mut entity) = world.get_entity_mut(self.reactor) else {
+            return;
+        };
+        let mut history = entity.entry::<ActionHistory>().or_default();
+        history.0.push_back(name);
+        while len < history.0.len() {
+            history.0.pop_front();
+        }
+    }
+}
+
+impl Runner for CrashGuardRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        self.record_history(world);
+        match catch_unwind(AssertUnwindSafe(|| self.runner.run(world, token))) {
+            Ok(status) => status,
+            Err(payload) => {
+                let dump = capture(world, self.reactor, payload);
+                if let Some(mut dumps) = world.get_resource_mut::<CrashDumps>() {
+                    dumps.dumps.push(dump);
+                }
+                RunnerIs::Canceled
+            }
+        }
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.runner.access()
+    }
+}
+
+fn capture(world: &World, reactor: Entity, payload: Box<dyn Any + Send>) -> CrashDump {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default();
+    let recent_actions = world
+        .get::<ActionHistory>(reactor)
+        .map(|history| history.0.iter().cloned().collect())
+        .unwrap_or_default();
+    let mut resources = Vec::new();
+    if let (Some(dumps), Some(registry)) = (world.get_resource::<CrashDumps>(), world.get_resource::<AppTypeRegistry>()) {
+        let registry = registry.read();
+        for type_id in dumps.resources.iter() {
+            let Some(registration) = registry.get(*type_id) else {
+                continue;
+            };
+            let Some(resource) = registration
+                .data::<ReflectResource>()
+                .and_then(|reflect| reflect.reflect(world)) else {
+                continue;
+            };
+            resources.push((registration.type_info().type_path().to_string(), format!("{resource:?}")));
+        }
+    }
+    CrashDump {
+        reactor,
+        message,
+        recent_actions,
+        resources,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{CrashDumps, Reactor};
+    use crate::reactor::NativeReactor;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ReflectResource, Reflect, Resource};
+
+    #[derive(Resource, Reflect, Debug)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[test]
+    fn capture_panic_of_action() {
+        let mut app = test_app();
+        app.register_type::<Score>();
+        app.insert_resource(Score(3));
+        app.insert_resource(CrashDumps::default().with_resource::<Score>().with_history_len(1));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {}).named("first")).await;
+                task.will(Update, once::run(|| {}).named("second")).await;
+                task.will(Update, once::run(|| {
+                    panic!("crashed");
+                })).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let dumps = app.world_mut().resource_mut::<CrashDumps>().take();
+        assert_eq!(dumps.len(), 1);
+        assert_eq!(dumps[0].message, "crashed");
+        assert_eq!(dumps[0].recent_actions, vec!["second".to_string()]);
+        assert_eq!(dumps[0].resources.len(), 1);
+        assert!(dumps[0].resources[0].1.contains('3'));
+        assert!(app
+            .world_mut()
+            .query::<&NativeReactor>()
+            .get_single(app.world())
+            .is_err());
+    }
+}
@@ -0,0 +1,216 @@
+//! Provides the tools for debugging complex flows of reactors.
+//!
+//! Insert [`FlurxDebug`] as a resource to advance reactors one step at a time,
+//! and call [`debug::reactors`](reactors) to list the running reactors and their pending actions.
+
+use crate::diagnostics::ReactorDiagnostics;
+use crate::prelude::ReactorPaused;
+use crate::reactor::NativeReactor;
+use bevy::core::Name;
+use bevy::prelude::{Entity, Has, Resource, With, World};
+
+/// The resource that controls how reactors are advanced.
+///
+/// In step mode, each reactor is polled at most once per [`FlurxDebug::step`],
+/// so that the flows advance one logical step, i.e. until their next `await`, at a time.
+/// Note that the actions that are already awaited keep running on their schedules.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn debug_console(
+///     mut debug: ResMut<FlurxDebug>,
+///     input: Res<ButtonInput<KeyCode>>,
+/// ){
+///     if input.just_pressed(KeyCode::F10) {
+///         debug.step();
+///     }
+///     if input.just_pressed(KeyCode::F5) {
+///         debug.run_free();
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin))
+///     .init_resource::<FlurxDebug>()
+///     .add_systems(Update, debug_console);
+/// ```
+#[derive(Resource, Debug, Default, Clone, Eq, PartialEq)]
+pub struct FlurxDebug {
+    stepping: bool,
+    steps: usize,
+}
+
+impl FlurxDebug {
+    /// Enters step mode without advancing the reactors.
+    #[inline]
+    pub fn step_mode(&mut self) {
+        self.stepping = true;
+    }
+
+    /// Enters step mode and advances the reactors one step.
+    ///
+    /// If this is called multiple times before the reactors are polled, the steps are accumulated.
+    #[inline]
+    pub fn step(&mut self) {
+        self.stepping = true;
+        self.steps += 1;
+    }
+
+    /// Leaves step mode, and advances the reactors every frame as usual.
+    #[inline]
+    pub fn run_free(&mut self) {
+        self.stepping = false;
+        self.steps = 0;
+    }
+
+    /// Returns whether it is in step mode.
+    #[inline(always)]
+    pub const fn is_stepping(&self) -> bool {
+        self.stepping
+    }
+
+    /// Returns whether the reactors can be polled now.
+    #[inline(always)]
+    pub(crate) const fn can_poll(&self) -> bool {
+        !self.stepping || 0 < self.steps
+    }
+
+    /// Consumes a step after the reactors have been polled.
+    #[inline(always)]
+    pub(crate) fn consume_step(&mut self) {
+        self.steps = self.steps.saturating_sub(1);
+    }
+}
+
+/// The information of a running reactor returned by [`debug::reactors`](reactors).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReactorInfo {
+    /// The entity to which the reactor is attached.
+    pub entity: Entity,
+    /// The [`Name`] of the reactor entity.
+    pub name: Option<String>,
+    /// Whether the reactor is paused by [`ReactorPaused`].
+    pub paused: bool,
+    /// The actions the reactor is currently awaiting.
+    ///
+    /// This is recorded only while [`FlurxDiagnosticsPlugin`](crate::prelude::FlurxDiagnosticsPlugin) is added.
+    pub pending: Vec<String>,
+}
+
+/// Lists the running reactors and their pending actions.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn dump(world: &mut World){
+///     for reactor in debug::reactors(world){
+///         info!("{:?}: {:?}", reactor.entity, reactor.pending);
+///     }
+/// }
+/// ```
+pub fn reactors(world: &mut World) -> Vec<ReactorInfo> {
+    world
+        .query_filtered::<(Entity, Option<&Name>, Has<ReactorPaused>, Option<&ReactorDiagnostics>), With<NativeReactor>>()
+        .iter(world)
+        .map(|(entity, name, paused, diagnostics)| ReactorInfo {
+            entity,
+            name: name.map(ToString::to_string),
+            paused,
+            pending: diagnostics
+                .map(|diagnostics| diagnostics.actions().iter().map(|action| action.action.clone()).collect())
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::debug;
+    use crate::prelude::{FlurxDebug, FlurxDiagnosticsPlugin, Reactor};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::core::Name;
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn advance_one_step_per_step_call() {
+        let mut app = test_app();
+        let mut flurx_debug = FlurxDebug::default();
+        flurx_debug.step_mode();
+        app.insert_resource(flurx_debug);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, increment_count()).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+
+        app.resource_mut::<FlurxDebug>().step();
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+
+        app.resource_mut::<FlurxDebug>().step();
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn run_free_after_step_mode() {
+        let mut app = test_app();
+        let mut flurx_debug = FlurxDebug::default();
+        flurx_debug.step_mode();
+        app.insert_resource(flurx_debug);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, increment_count()).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        app.resource_mut::<FlurxDebug>().run_free();
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn list_reactors_and_pending_actions() {
+        let mut app = test_app();
+        app.add_plugins(FlurxDiagnosticsPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((
+                Name::new("boss"),
+                Reactor::schedule(|task| async move {
+                    task.will(Update, crate::prelude::wait::until(|mut count: ResMut<Count>| {
+                        count.increment();
+                        false
+                    }).named("forever")).await;
+                }),
+            ));
+        });
+        app.update();
+        let reactors = debug::reactors(app.world_mut());
+        assert_eq!(reactors.len(), 1);
+        assert_eq!(reactors[0].name.as_deref(), Some("boss"));
+        assert!(!reactors[0].paused);
+        assert_eq!(reactors[0].pending, vec!["forever".to_string()]);
+    }
+}
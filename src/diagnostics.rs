@@ -0,0 +1,308 @@
+//! Provides the diagnostics of reactors for debugging why a reactor is stuck.
+//!
+//! Add [`FlurxDiagnosticsPlugin`] to record [`ReactorDiagnostics`] on each reactor entity
+//! and emit the `tracing` spans for each action.
+
+use crate::action::Action;
+use crate::prelude::{CancellationHandlers, Runner, RunnerIs};
+use crate::runner::{trace, BoxedRunner};
+use crate::plugins::FlurxCorePlugin;
+use crate::FlurxPlugin;
+use bevy::app::{App, Last, Plugin};
+use bevy::core::{update_frame_count, FrameCount};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::log::info_span;
+use bevy::prelude::{Component, Entity, IntoSystemConfigs, Query, Reflect, ReflectDefault, Res, Resource, World};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The plugin that records the diagnostics of reactors.
+///
+/// While this plugin is added, [`ReactorDiagnostics`] is inserted into each reactor entity,
+/// and each run of the actions passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will) is wrapped in `task.will` span,
+/// so they show up in bevy's span-based profiling.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         FlurxDiagnosticsPlugin,
+///     ))
+///     .add_systems(Update, |reactors: Query<&ReactorDiagnostics>|{
+///         for action in reactors.iter().flat_map(|diagnostics| diagnostics.actions()){
+///             if 600 < action.frames {
+///                 warn!("{} on {} seems to be stuck", action.action, action.schedule);
+///             }
+///         }
+///     });
+/// ```
+pub struct FlurxDiagnosticsPlugin;
+
+impl Plugin for FlurxDiagnosticsPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FlurxCorePlugin>() {
+            app.add_plugins(FlurxPlugin);
+        }
+        app
+            .init_resource::<DiagnosticsEnabled>()
+            .add_systems(Last, update_frames.after(update_frame_count));
+    }
+}
+
+/// The diagnostics of the reactor attached to the same entity.
+#[derive(Component, Debug, Default, Clone)]
+pub struct ReactorDiagnostics(Vec<AwaitingAction>);
+
+impl ReactorDiagnostics {
+    /// Returns the actions the reactor is currently awaiting.
+    #[inline(always)]
+    pub fn actions(&self) -> &[AwaitingAction] {
+        &self.0
+    }
+}
+
+/// The action that a reactor is awaiting.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AwaitingAction {
+    id: u64,
+    started: u32,
+    /// The name given by [`ActionSeed::named`](crate::prelude::ActionSeed::named), or the type name of the action.
+    pub action: String,
+    /// The schedule label on which the action runs.
+    pub schedule: String,
+    /// The number of frames spent waiting for the action.
+    ///
+    /// This is counted by [`FrameCount`] even if the schedule does not run.
+    pub frames: u32,
+    /// The actions run in the last run of this action, in pre-order.
+    ///
+    /// The first one is this action itself, and the combined actions such as the steps of a sequence follow it.
+    /// This is recorded only while [`FlurxInspectorPlugin`](crate::prelude::FlurxInspectorPlugin) is added.
+    pub nested: Vec<NestedAction>,
+}
+
+/// The action run inside [`AwaitingAction`].
+#[derive(Reflect, Debug, Default, Clone, Eq, PartialEq)]
+#[reflect(Default)]
+pub struct NestedAction {
+    /// The name given by [`ActionSeed::named`](crate::prelude::ActionSeed::named), or the type name of the runner.
+    pub action: String,
+    /// The depth in the tree, which is `0` for the awaited action itself.
+    pub depth: u32,
+}
+
+#[derive(Resource, Default)]
+pub(crate) struct DiagnosticsEnabled;
+
+/// Makes [`AwaitingAction::nested`] recorded.
+#[derive(Resource, Default)]
+pub(crate) struct TraceNestedActions;
+
+/// The actions whose runners have been dropped without finishing,
+/// e.g. the losers of [`ReactorTask::will_either`](crate::prelude::ReactorTask::will_either).
+///
+/// They are removed from [`ReactorDiagnostics`] in [`update_frames`].
+#[derive(Resource, Default, Clone)]
+pub(crate) struct DroppedActions(Arc<Mutex<Vec<(Entity, u64)>>>);
+
+/// Returns the description of the action if the diagnostics are enabled.
+pub(crate) fn describe<I, O>(world: &World, action: &Action<I, O>) -> Option<String>
+where
+    I: 'static,
+    O: 'static,
+{
+    world.contains_resource::<DiagnosticsEnabled>().then(|| action_name(action))
+}
+
+/// Returns the name given by [`ActionSeed::named`](crate::prelude::ActionSeed::named), or the type name of the action.
+pub(crate) fn action_name<I, O>(action: &Action<I, O>) -> String
+where
+    I: 'static,
+    O: 'static,
+{
+    action
+        .1
+        .name()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| std::any::type_name::<Action<I, O>>().to_string())
+}
+
+/// Records the action into [`ReactorDiagnostics`] and wraps the runner to emit the span.
+pub(crate) fn create_runner<Label: ScheduleLabel>(
+    world: &mut World,
+    reactor: Entity,
+    label: &Label,
+    action: String,
+    runner: BoxedRunner,
+) -> BoxedRunner {
+    static ID: AtomicU64 = AtomicU64::new(0);
+    let id = ID.fetch_add(1, Ordering::Relaxed);
+    let schedule = format!("{label:?}");
+    let started = world.get_resource::<FrameCount>().map(|f| f.0).unwrap_or_default();
+    if let Ok(mut entity) = world.get_entity_mut(reactor) {
+        entity.entry::<ReactorDiagnostics>().or_default().0.push(AwaitingAction {
+            id,
+            started,
+            action: action.clone(),
+            schedule: schedule.clone(),
+            frames: 0,
+            nested: Vec::new(),
+        });
+    }
+    BoxedRunner::new(DiagnosticsRunner {
+        runner,
+        reactor,
+        id,
+        action,
+        schedule,
+        trace: world.contains_resource::<TraceNestedActions>(),
+        dropped: Some(world.get_resource_or_init::<DroppedActions>().clone()),
+    })
+}
+
+struct DiagnosticsRunner {
+    runner: BoxedRunner,
+    reactor: Entity,
+    id: u64,
+    action: String,
+    schedule: String,
+    trace: bool,
+    /// Notifies that the runner has been dropped without finishing; `None` once it has finished.
+    dropped: Option<DroppedActions>,
+}
+
+impl Runner for DiagnosticsRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let (status, nested) = {
+            let _span = info_span!("task.will", reactor = %self.reactor, action = %self.action, schedule = %self.schedule).entered();
+            if self.trace {
+                let (status, nested) = trace::record(|| self.runner.run(world, token));
+                (status, Some(nested))
+            } else {
+                (self.runner.run(world, token), None)
+            }
+        };
+        let finished = !matches!(status, RunnerIs::Running);
+        if finished {
+            self.dropped = None;
+        }
+        let Some(mut diagnostics) = world.get_mut::<ReactorDiagnostics>(self.reactor) else {
+            return status;
+        };
+        if finished {
+            diagnostics.0.retain(|action| action.id != self.id);
+        } else if let Some(nested) = nested {
+            if let Some(action) = diagnostics.0.iter_mut().find(|action| action.id == self.id) {
+                action.nested = nested;
+            }
+        }
+        status
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.runner.access()
+    }
+}
+
+impl Drop for DiagnosticsRunner {
+    fn drop(&mut self) {
+        if let Some(dropped) = self.dropped.take() {
+            if let Ok(mut dropped) = dropped.0.lock() {
+                dropped.push((self.reactor, self.id));
+            }
+        }
+    }
+}
+
+pub(crate) fn update_frames(
+    frame_count: Option<Res<FrameCount>>,
+    dropped: Option<Res<DroppedActions>>,
+    mut reactors: Query<&mut ReactorDiagnostics>,
+) {
+    if let Some(mut dropped) = dropped.as_ref().and_then(|dropped| dropped.0.lock().ok()) {
+        for (reactor, id) in dropped.drain(..) {
+            if let Ok(mut diagnostics) = reactors.get_mut(reactor) {
+                diagnostics.0.retain(|action| action.id != id);
+            }
+        }
+    }
+    let Some(frame_count) = frame_count else {
+        return;
+    };
+    for mut diagnostics in reactors.iter_mut() {
+        for action in diagnostics.0.iter_mut() {
+            action.frames = frame_count.0.wrapping_sub(action.started);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{FlurxDiagnosticsPlugin, Reactor, ReactorDiagnostics};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::schedule::ScheduleLabel;
+    use bevy::prelude::Commands;
+
+    #[derive(ScheduleLabel, Debug, Clone, Eq, PartialEq, Hash)]
+    struct NeverRun;
+
+    fn read_diagnostics(app: &mut bevy::app::App) -> ReactorDiagnostics {
+        app.world_mut().query::<&ReactorDiagnostics>().single(app.world()).clone()
+    }
+
+    #[test]
+    fn remove_dropped_actions() {
+        let mut app = test_app();
+        app.add_plugins(FlurxDiagnosticsPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will_either(
+                    (Update, once::run(|| {}).named("winner")),
+                    (NeverRun, wait::until(|| false).named("loser")),
+                ).await;
+                task.will(NeverRun, wait::until(|| false).named("stuck")).await;
+            }));
+        });
+        app.update();
+        app.update();
+        let diagnostics = read_diagnostics(&mut app);
+        assert_eq!(diagnostics.actions().len(), 1);
+        assert_eq!(diagnostics.actions()[0].action, "stuck");
+    }
+
+    #[test]
+    fn record_awaiting_actions() {
+        let mut app = test_app();
+        app.add_plugins(FlurxDiagnosticsPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {}).named("first")).await;
+                task.will(NeverRun, wait::until(|| false).named("stuck")).await;
+            }));
+        });
+        app.update();
+        let diagnostics = read_diagnostics(&mut app);
+        assert_eq!(diagnostics.actions().len(), 1);
+        assert_eq!(diagnostics.actions()[0].action, "stuck");
+        assert_eq!(diagnostics.actions()[0].schedule, "NeverRun");
+        let frames = diagnostics.actions()[0].frames;
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let diagnostics = read_diagnostics(&mut app);
+        assert_eq!(diagnostics.actions().len(), 1);
+        assert_eq!(diagnostics.actions()[0].frames, frames + 3);
+    }
+}
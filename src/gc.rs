@@ -0,0 +1,151 @@
+//! Provides the garbage collection of the runners whose reactors vanished abnormally.
+//!
+//! Normally, the pending runners and their [`Output`](crate::prelude::Output)s and [`CancellationHandlers`](crate::prelude::CancellationHandlers)
+//! are freed as soon as the reactor entity is despawned.
+//! However, they are left behind if the reactor vanished without notifying it, e.g. its observers were despawned by hand,
+//! and long-running servers slowly leak them.
+//!
+//! [`FlurxGcPlugin`](crate::prelude::FlurxGcPlugin) sweeps them at the end of the frames in which reactors were removed,
+//! and [`gc`] can also be called manually.
+
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::{Entity, ReflectResource, Reflect, Resource, World};
+
+/// The metrics of the garbage collection.
+#[derive(Resource, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[reflect(Resource)]
+pub struct GcMetrics {
+    /// The total number of leaked runners freed so far.
+    pub freed_runners: usize,
+}
+
+/// Whether any reactor has been removed since the last sweep.
+#[derive(Resource, Default)]
+pub(crate) struct GcRequested(bool);
+
+/// Requests the sweep when the reactor is removed, because its runners may have been left behind.
+pub(crate) fn request_gc(mut world: DeferredWorld, _: Entity, _: ComponentId) {
+    if let Some(mut requested) = world.get_resource_mut::<GcRequested>() {
+        requested.0 = true;
+    }
+}
+
+#[derive(Resource, Default)]
+pub(crate) struct Sweepers(Vec<fn(&mut World) -> usize>);
+
+/// Registers the function that frees the orphaned runners of a schedule label.
+pub(crate) fn register_sweeper(world: &mut World, sweeper: fn(&mut World) -> usize) {
+    world.get_resource_or_init::<Sweepers>().0.push(sweeper);
+}
+
+/// Frees the pending runners whose reactors vanished abnormally, and returns the number of freed runners.
+///
+/// The cancellation handlers registered by the runners are called as if the reactors had been canceled,
+/// and the number of freed runners is added to [`GcMetrics`].
+///
+/// This is an exclusive system, so it can also be run as an action.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// Reactor::schedule(|task| async move{
+///     let freed: usize = task.will(Update, once::run(bevy_flurx::gc::gc)).await;
+///     info!("freed runners: {freed}");
+/// });
+/// ```
+pub fn gc(world: &mut World) -> usize {
+    let Some(sweepers) = world.get_resource::<Sweepers>().map(|sweepers| sweepers.0.clone()) else {
+        return 0;
+    };
+    let freed = sweepers.iter().map(|sweep| sweep(world)).sum();
+    if 0 < freed {
+        world.get_resource_or_init::<GcMetrics>().freed_runners += freed;
+    }
+    freed
+}
+
+pub(crate) fn gc_system(world: &mut World) {
+    let requested = world
+        .get_resource_mut::<GcRequested>()
+        .is_some_and(|mut requested| std::mem::take(&mut requested.0));
+    if requested {
+        gc(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::gc::{gc, GcMetrics, GcRequested};
+    use crate::prelude::Reactor;
+    use crate::reactor::NativeReactor;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, Entity, Observer, Query, With};
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn not_free_runners_of_alive_reactors() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        assert_eq!(gc(app.world_mut()), 0);
+        app.assert_resource_eq(GcMetrics::default());
+    }
+
+    #[test]
+    fn free_runners_of_vanished_reactors() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        app.world_mut()
+            .run_system_once(|mut commands: Commands, observers: Query<Entity, With<Observer>>, reactor: Query<Entity, With<NativeReactor>>| {
+                for observer in observers.iter() {
+                    commands.entity(observer).despawn();
+                }
+                commands.entity(reactor.single()).despawn();
+            })
+            .expect("Failed to run system");
+        assert_eq!(gc(app.world_mut()), 1);
+        assert_eq!(gc(app.world_mut()), 0);
+        app.assert_resource_eq(GcMetrics { freed_runners: 1 });
+    }
+
+    #[test]
+    fn sweep_after_reactor_removed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.update();
+        assert!(!app.world().resource::<GcRequested>().0);
+
+        app.world_mut()
+            .run_system_once(|mut commands: Commands, observers: Query<Entity, With<Observer>>, reactor: Query<Entity, With<NativeReactor>>| {
+                for observer in observers.iter() {
+                    commands.entity(observer).despawn();
+                }
+                commands.entity(reactor.single()).despawn();
+            })
+            .expect("Failed to run system");
+        assert!(app.world().resource::<GcRequested>().0);
+        app.update();
+        assert!(!app.world().resource::<GcRequested>().0);
+        app.assert_resource_eq(GcMetrics { freed_runners: 1 });
+    }
+}
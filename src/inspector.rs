@@ -0,0 +1,218 @@
+//! Provides the components to inspect the running reactors live
+//! with reflection-based inspectors such as [`bevy-inspector-egui`](https://github.com/jakobhellermann/bevy-inspector-egui).
+//!
+//! Add [`FlurxInspectorPlugin`] to attach [`ReactorInspector`] to each reactor entity.
+//! This crate does not depend on `bevy-inspector-egui`; add its plugin to display the registered components.
+
+use crate::diagnostics::{update_frames, NestedAction, ReactorDiagnostics, TraceNestedActions};
+use crate::plugins::FlurxCorePlugin;
+use crate::prelude::{FlurxDiagnosticsPlugin, ReactorCheckpoints, ReactorGroup, ReactorPaused, RunningReactor, ReactorTime};
+use crate::reactor::NativeReactor;
+use crate::FlurxPlugin;
+use bevy::app::{App, Last, Plugin};
+use bevy::hierarchy::Children;
+use bevy::prelude::{Commands, Component, DetectChangesMut, Entity, IntoSystemConfigs, Query, Reflect, ReflectComponent, ReflectDefault, With};
+
+/// The plugin that attaches [`ReactorInspector`] to each reactor entity.
+///
+/// This also adds [`FlurxDiagnosticsPlugin`] to record the awaited actions and the actions nested in them,
+/// and registers the reflectable components of reactors, such as [`RunningReactor`] and [`ReactorPaused`],
+/// so that the inspectors can display and edit them.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         FlurxInspectorPlugin,
+///         // e.g. bevy_inspector_egui::quick::WorldInspectorPlugin::new(),
+///     ));
+/// ```
+pub struct FlurxInspectorPlugin;
+
+impl Plugin for FlurxInspectorPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FlurxCorePlugin>() {
+            app.add_plugins(FlurxPlugin);
+        }
+        if !app.is_plugin_added::<FlurxDiagnosticsPlugin>() {
+            app.add_plugins(FlurxDiagnosticsPlugin);
+        }
+        app
+            .init_resource::<TraceNestedActions>()
+            .register_type::<ReactorInspector>()
+            .register_type::<InspectedAction>()
+            .register_type::<NestedAction>()
+            .register_type::<RunningReactor>()
+            .register_type::<ReactorPaused>()
+            .register_type::<ReactorGroup>()
+            .register_type::<ReactorCheckpoints>()
+            .register_type::<ReactorTime>()
+            .add_systems(Last, sync_inspectors.after(update_frames));
+    }
+}
+
+/// The snapshot of the reactor attached to the same entity, updated every frame by [`FlurxInspectorPlugin`].
+#[derive(Component, Reflect, Debug, Default, Clone, Eq, PartialEq)]
+#[reflect(Component, Default)]
+pub struct ReactorInspector {
+    /// The actions the reactor is currently awaiting.
+    ///
+    /// There can be more than one if the actions are awaited concurrently,
+    /// e.g. by [`ReactorTask::scope`](crate::prelude::ReactorTask::scope).
+    pub actions: Vec<InspectedAction>,
+    /// The child reactors forked by [`ReactorTask::spawn`](crate::prelude::ReactorTask::spawn),
+    /// each of which also has [`ReactorInspector`], so the nested flows can be followed as a tree.
+    pub sub_reactors: Vec<Entity>,
+}
+
+/// The action that a reactor is awaiting.
+#[derive(Reflect, Debug, Default, Clone, Eq, PartialEq)]
+#[reflect(Default)]
+pub struct InspectedAction {
+    /// The name given by [`ActionSeed::named`](crate::prelude::ActionSeed::named), or the type name of the action.
+    ///
+    /// Naming the combinators such as [`wait::all`](crate::prelude::wait::all) makes the stuck part easy to spot.
+    pub action: String,
+    /// The schedule label on which the action runs.
+    pub schedule: String,
+    /// The number of frames spent waiting for the action.
+    pub elapsed_frames: u32,
+    /// The tree of the actions run in the last run of this action, in pre-order.
+    ///
+    /// The first one is this action itself, followed by the combined actions with their depths,
+    /// e.g. the branches of [`wait::all`](crate::prelude::wait::all()) or the current step of a sequence.
+    pub nested: Vec<NestedAction>,
+}
+
+fn sync_inspectors(
+    mut commands: Commands,
+    mut reactors: Query<(Entity, Option<&ReactorDiagnostics>, Option<&Children>, Option<&mut ReactorInspector>), With<NativeReactor>>,
+    native_reactors: Query<(), With<NativeReactor>>,
+) {
+    for (entity, diagnostics, children, inspector) in reactors.iter_mut() {
+        let snapshot = ReactorInspector {
+            actions: diagnostics
+                .map(|diagnostics| diagnostics
+                    .actions()
+                    .iter()
+                    .map(|action| InspectedAction {
+                        action: action.action.clone(),
+                        schedule: action.schedule.clone(),
+                        elapsed_frames: action.frames,
+                        nested: action.nested.clone(),
+                    })
+                    .collect())
+                .unwrap_or_default(),
+            sub_reactors: children
+                .map(|children| children
+                    .iter()
+                    .copied()
+                    .filter(|child| native_reactors.contains(*child))
+                    .collect())
+                .unwrap_or_default(),
+        };
+        match inspector {
+            Some(mut inspector) => {
+                inspector.set_if_neq(snapshot);
+            }
+            None => {
+                commands.entity(entity).insert(snapshot);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::sequence::Then;
+    use crate::action::{delay, once, wait};
+    use crate::actions;
+    use crate::inspector::{InspectedAction, ReactorInspector};
+    use crate::prelude::{FlurxInspectorPlugin, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::schedule::ScheduleLabel;
+    use bevy::hierarchy::Parent;
+    use bevy::prelude::{AppTypeRegistry, Commands, Entity, With};
+
+    #[derive(ScheduleLabel, Debug, Clone, Eq, PartialEq, Hash)]
+    struct NeverRun;
+
+    #[test]
+    fn inspect_awaiting_actions_and_sub_reactors() {
+        let mut app = test_app();
+        app.add_plugins(FlurxInspectorPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {})).await;
+                task.spawn(|task| async move {
+                    task.will(NeverRun, wait::until(|| false).named("child")).await;
+                }).await;
+                task.will(NeverRun, wait::until(|| false).named("stuck")).await;
+            }));
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        let child = app
+            .world_mut()
+            .query_filtered::<Entity, With<Parent>>()
+            .single(app.world());
+        let inspectors = app
+            .world_mut()
+            .query::<&ReactorInspector>()
+            .iter(app.world())
+            .cloned()
+            .collect::<Vec<_>>();
+        let parent = inspectors.iter().find(|inspector| inspector.actions[0].action == "stuck").unwrap();
+        assert_eq!(parent.sub_reactors, vec![child]);
+        assert_eq!(parent.actions[0].schedule, "NeverRun");
+        let child = inspectors.iter().find(|inspector| inspector.actions[0].action == "child").unwrap();
+        assert!(child.sub_reactors.is_empty());
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry.get(std::any::TypeId::of::<ReactorInspector>()).is_some());
+        assert!(registry.get(std::any::TypeId::of::<InspectedAction>()).is_some());
+    }
+
+    #[test]
+    fn inspect_nested_actions() {
+        let mut app = test_app();
+        app.add_plugins(FlurxInspectorPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {}).then(wait::all().with(actions![
+                    wait::until(|| false).named("never"),
+                    delay::frames().with(100),
+                ])).named("root")).await;
+            }));
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+        let inspector = app
+            .world_mut()
+            .query::<&ReactorInspector>()
+            .single(app.world())
+            .clone();
+        let nested = inspector.actions[0]
+            .nested
+            .iter()
+            .map(|action| (action.action.as_str(), action.depth))
+            .collect::<Vec<_>>();
+        assert_eq!(nested, vec![
+            ("root", 0),
+            ("AllRunner", 1),
+            ("OmitRunner", 2),
+            ("never", 3),
+            ("OmitRunner", 2),
+            ("WaitRunner", 3),
+        ]);
+    }
+}
@@ -6,48 +6,144 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::type_complexity)]
 
-use crate::reactor::NativeReactor;
-use crate::runner::CallCancellationHandlers;
+use crate::debug::FlurxDebug;
+use crate::plugins::FlurxPlugins;
+use crate::reactor::sleep::{is_awake, sleep, ElasticPolling};
+use crate::reactor::steps::{consume_step, MaxStepsPerFrame};
+use crate::reactor::{NativeReactor, ReactorPaused};
+use crate::runner::{CallCancellationHandlers, CompletedInFixedTick};
 use crate::world_ptr::WorldPtr;
-use bevy::app::{App, Last, Plugin, PostStartup};
+use bevy::app::{App, Plugin};
 use bevy::ecs::system::SystemState;
 use bevy::hierarchy::DespawnRecursiveExt;
-use bevy::prelude::{Entity, EventReader, IntoSystemConfigs, QueryState, World};
+use bevy::prelude::{Entity, EventReader, Mut, QueryState, Without, World};
 
 pub mod action;
+#[cfg(feature = "budget")]
+#[cfg_attr(docsrs, doc(cfg(feature = "budget")))]
+pub mod budget;
+#[cfg(feature = "chaos")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chaos")))]
+pub mod chaos;
+#[cfg(feature = "crash")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crash")))]
+pub mod crash;
+pub mod debug;
+pub mod diagnostics;
+#[cfg(feature = "gc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gc")))]
+pub mod gc;
+#[cfg(feature = "inspector")]
+#[cfg_attr(docsrs, doc(cfg(feature = "inspector")))]
+pub mod inspector;
+#[cfg(feature = "profiling")]
+#[cfg_attr(docsrs, doc(cfg(feature = "profiling")))]
+pub mod profiling;
 pub mod runner;
+#[cfg(feature = "scripting")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scripting")))]
+pub mod scripting;
 pub mod task;
+#[cfg(feature = "flurx_test")]
+#[cfg_attr(docsrs, doc(cfg(feature = "flurx_test")))]
+pub mod testing;
 
 #[allow(missing_docs)]
 pub mod prelude {
     #[cfg(feature = "effect")]
     pub use crate::action::effect::AsyncFunctor;
+    #[cfg(feature = "state")]
+    pub use crate::transition::PollAfterStateTransitionPlugin;
+    #[cfg(feature = "render")]
+    pub use crate::plugins::FlurxRenderPlugin;
+    #[cfg(feature = "profiling")]
+    pub use crate::profiling::FlurxProfilingPlugin;
+    #[cfg(feature = "inspector")]
+    pub use crate::inspector::{FlurxInspectorPlugin, ReactorInspector};
+    #[cfg(feature = "flurx_test")]
+    pub use crate::testing::FlurxTestExt;
+    #[cfg(feature = "scripting")]
+    pub use crate::scripting::{ScriptActions, ScriptActionsExtension, ScriptError};
     #[cfg(feature = "record")]
     pub use crate::action::record::{
         extension::{RecordExtension, RequestRedo, RequestUndo},
         EditRecordResult, Record, Redo, RedoAction, Rollback, Track, Undo, UndoRedoInProgress,
     };
+    #[cfg(feature = "audio")]
+    pub use crate::plugins::FlurxAudioPlugin;
+    #[cfg(feature = "asset")]
+    pub use crate::plugins::FlurxAssetPlugin;
+    #[cfg(feature = "scene")]
+    pub use crate::plugins::FlurxScenePlugin;
+    #[cfg(feature = "gc")]
+    pub use crate::plugins::FlurxGcPlugin;
+    #[cfg(feature = "budget")]
+    pub use crate::{
+        budget::{ActionOverBudget, ActionTimeBudget},
+        plugins::FlurxBudgetPlugin,
+    };
+    #[cfg(feature = "chaos")]
+    pub use crate::chaos::Chaos;
+    #[cfg(feature = "crash")]
+    pub use crate::crash::{CrashDump, CrashDumps};
+    #[cfg(feature = "locale")]
+    pub use crate::action::once::locale::{Localization, Localize};
+    #[cfg(feature = "ui")]
+    pub use crate::{
+        action::once::ui::{Toast, UiFocus},
+        plugins::FlurxUiPlugin,
+    };
+    #[cfg(feature = "cutscene")]
+    pub use crate::{
+        action::cutscene::{Cutscene, CutsceneDialog, SkipCutscene},
+        plugins::FlurxCutscenePlugin,
+    };
+    #[cfg(feature = "rng")]
+    pub use crate::{
+        action::once::rng::FlurxRng,
+        plugins::FlurxRngPlugin,
+    };
+    #[cfg(feature = "input")]
+    pub use crate::{
+        action::once::input::{input_unblocked, InputGuard},
+        action::wait::logical::{InputBinding, InputMap},
+        plugins::FlurxInputPlugin,
+    };
+    #[cfg(feature = "menu")]
+    pub use crate::action::wait::menu::MenuAction;
+    #[cfg(feature = "analytics")]
+    pub use crate::action::once::analytics::{Analytics, AnalyticsEvent, AnalyticsSink};
     pub use crate::{
         action::omit::*,
         action::pipe::Pipe,
+        action::result::{AndThen, MapErr, MapOk, TryPipe, TryThen},
         action::seed::ActionSeed,
         action::sequence::Then,
         action::switch::*,
-        action::through::{through, Through},
+        action::through::{through, through_with, Through},
         action::inspect::{inspect, Inspect},
         action::wait::Either,
+        action::wait::app::AppReadiness,
         action::Map,
         action::Remake,
         action::*,
-        reactor::Reactor,
+        debug::{self, FlurxDebug, ReactorInfo},
+        diagnostics::{AwaitingAction, FlurxDiagnosticsPlugin, NestedAction, ReactorDiagnostics},
+        reactor::{attach::AttachReactorExtension, checkpoint::ReactorCheckpoints, commands::{ReactorCommands, ReactorCommandsExtension}, group::{ReactorGroup, ReactorGroupExtension}, inbox::ReactorInbox, params::ReactorParams, sub::SubReactorHandle, time::ReactorTime, weak::{ReactorCompletion, ReactorStatus, WeakReactorHandle}, Reactor, ReactorPaused, RunningReactor},
         runner::*,
-        task::ReactorTask,
+        task::{ActionScope, ReactorTask},
         FlurxPlugin,
+        plugins::{FlurxCorePlugin, FlurxDelayPlugin, FlurxPlugins, FlurxSyncPlugin},
+        middleware::FlurxMiddlewarePlugin,
     };
 }
 
+mod middleware;
+mod plugins;
 mod reactor;
 mod selector;
+#[cfg(feature = "state")]
+mod transition;
 mod world_ptr;
 
 /// Define utilities for testing.
@@ -56,29 +152,38 @@ mod test_util;
 mod core;
 
 /// Provides the async systems.
+///
+/// This plugin adds all plugins in [`FlurxPlugins`](crate::prelude::FlurxPlugins);
+/// use the group instead if you want to disable or replace some of them.
+///
+/// The reactors are run in [`PostStartup`](bevy::prelude::PostStartup), [`Last`](bevy::prelude::Last) and [`FixedLast`](bevy::prelude::FixedLast) by [`FlurxCorePlugin`](crate::prelude::FlurxCorePlugin),
+/// and [`FlurxDelayPlugin`](crate::prelude::FlurxDelayPlugin) additionally uses [`FixedFirst`](bevy::prelude::FixedFirst) to count the fixed ticks.
+/// All of them are added by [`App::new`], so it also works with [`MinimalPlugins`](bevy::prelude::MinimalPlugins) in headless apps such as dedicated servers.
+/// Note that some actions require their plugins, e.g. [`wait::input`](crate::prelude::wait::input) requires [`InputPlugin`](bevy::input::InputPlugin).
 pub struct FlurxPlugin;
 
 impl Plugin for FlurxPlugin {
     #[inline]
     fn build(&self, app: &mut App) {
-        app
-            .add_event::<CallCancellationHandlers>()
-            .add_systems(PostStartup, initialize_reactors)
-            .add_systems(Last, (
-                call_cancel_handlers.run_if(bevy::prelude::on_event::<CallCancellationHandlers>),
-                run_reactors,
-            ));
+        app.add_plugins(FlurxPlugins);
     }
 }
 
 fn initialize_reactors(
     world: &mut World,
-    reactors: &mut QueryState<&mut NativeReactor>,
+    reactors: &mut QueryState<(&mut NativeReactor, Option<&mut MaxStepsPerFrame>, Option<&mut ElasticPolling>), Without<ReactorPaused>>,
 ) {
+    if world.get_resource::<FlurxDebug>().is_some_and(|debug| !debug.can_poll()) {
+        return;
+    }
     let world_ptr = WorldPtr::new(world);
-    for mut reactor in reactors.iter_mut(world).filter(|r| !r.initialized) {
+    for (mut reactor, mut steps, mut elastic) in reactors.iter_mut(world).filter(|(r, ..)| !r.initialized) {
+        if !consume_step(&mut steps) {
+            continue;
+        }
         reactor.run_sync(world_ptr);
         reactor.initialized = true;
+        sleep(&mut elastic);
     }
 }
 
@@ -89,29 +194,89 @@ fn call_cancel_handlers(
     let handlers = event_system_state
         .get_mut(world)
         .read()
-        .flat_map(|handler| handler.0.0.values().copied())
+        .flat_map(|handler| handler.0.handlers.values().copied())
         .collect::<Vec<_>>();
     for handler in handlers {
         handler(world);
     }
 }
 
-fn run_reactors(world: &mut World, reactors: &mut QueryState<(Entity, &mut NativeReactor)>) {
+fn run_reactors(world: &mut World, reactors: &mut ReactorsQuery) {
+    if let Some(mut debug) = world.get_resource_mut::<FlurxDebug>() {
+        if !debug.can_poll() {
+            return;
+        }
+        debug.consume_step();
+    }
+    poll_reactors(world, reactors, true);
+}
+
+/// Polls the reactors whose actions on the fixed schedules have been completed in this tick,
+/// so that the reactors can advance once per fixed tick even if multiple ticks run in a frame.
+fn run_reactors_in_fixed_tick(world: &mut World, reactors: &mut ReactorsQuery) {
+    let entities = std::mem::take(&mut world.resource_mut::<CompletedInFixedTick>().0);
+    if entities.is_empty() || world.get_resource::<FlurxDebug>().is_some_and(|debug| debug.is_stepping()) {
+        return;
+    }
     let world_ptr = WorldPtr::new(world);
-    let mut entities = Vec::new();
+    let mut finished = Vec::new();
+    for entity in entities {
+        let Ok((entity, mut reactor, mut steps, mut elastic)) = reactors.get_mut(world, entity) else {
+            continue;
+        };
+        if poll_reactor(world_ptr, &mut reactor, &mut steps, &mut elastic, false) {
+            finished.push(entity);
+        }
+    }
+    despawn_finished(world, finished);
+}
 
-    for (entity, mut reactor) in reactors.iter_mut(world) {
-        if !reactor.initialized {
-            reactor.run_sync(world_ptr);
-            reactor.initialized = true;
+/// The reactors polled by [`run_reactors`] and the other systems that advance them.
+pub(crate) type ReactorsQuery = QueryState<(Entity, &'static mut NativeReactor, Option<&'static mut MaxStepsPerFrame>, Option<&'static mut ElasticPolling>), Without<ReactorPaused>>;
+
+/// Polls all the running reactors and despawns the finished ones.
+///
+/// The reactors that have not been polled yet are started only if `initialize` is true.
+pub(crate) fn poll_reactors(world: &mut World, reactors: &mut ReactorsQuery, initialize: bool) {
+    let world_ptr = WorldPtr::new(world);
+    let mut finished = Vec::new();
+    for (entity, mut reactor, mut steps, mut elastic) in reactors.iter_mut(world) {
+        if poll_reactor(world_ptr, &mut reactor, &mut steps, &mut elastic, initialize) {
+            finished.push(entity);
         }
-        if reactor.run_sync(world_ptr) {
-            entities.push(entity);
+    }
+    despawn_finished(world, finished);
+}
+
+/// Polls the reactor if [`MaxStepsPerFrame`] and [`ElasticPolling`] allow it,
+/// and returns whether it has finished.
+fn poll_reactor(
+    world_ptr: WorldPtr,
+    reactor: &mut NativeReactor,
+    steps: &mut Option<Mut<MaxStepsPerFrame>>,
+    elastic: &mut Option<Mut<ElasticPolling>>,
+    initialize: bool,
+) -> bool {
+    if !reactor.initialized {
+        if !initialize || !consume_step(steps) {
+            return false;
         }
+        reactor.run_sync(world_ptr);
+        reactor.initialized = true;
+        sleep(elastic);
     }
+    if !is_awake(elastic) || !consume_step(steps) {
+        return false;
+    }
+    sleep(elastic);
+    reactor.run_sync(world_ptr)
+}
 
-    for entity in entities {
-        world.entity_mut(entity).despawn_recursive();
+fn despawn_finished(world: &mut World, finished: Vec<Entity>) {
+    for entity in finished {
+        if let Ok(entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.despawn_recursive();
+        }
     }
 }
 
@@ -176,6 +341,31 @@ mod tests {
     #[derive(Eq, PartialEq, Debug, Resource, Copy, Clone, Default)]
     pub struct TestResource;
 
+    #[test]
+    fn works_with_minimal_plugins() {
+        use crate::prelude::{delay, wait, Reactor};
+        use bevy::prelude::{Commands, MinimalPlugins, Startup, Update};
+        use bevy::time::TimeUpdateStrategy;
+        use bevy_test_helper::resource::DirectResourceControl;
+        use std::time::Duration;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, FlurxPlugin));
+        app.insert_resource(Count(0));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::tick::count().with(2)).await;
+                task.will(Update, delay::time().with(Duration::from_millis(100))).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
     #[allow(unused)]
     pub fn came_event<E: Event>(app: &mut App) -> bool {
         app.world_mut()
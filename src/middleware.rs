@@ -0,0 +1,284 @@
+use crate::action::Action;
+#[cfg(feature = "budget")]
+use crate::budget;
+#[cfg(feature = "chaos")]
+use crate::chaos;
+#[cfg(feature = "crash")]
+use crate::crash;
+use crate::prelude::{ActionSeed, CancellationHandlers, Runner, RunnerIs};
+use crate::runner::{BoxedRunner, Output};
+use crate::plugins::FlurxCorePlugin;
+use crate::FlurxPlugin;
+use bevy::app::{App, Plugin};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::prelude::{Entity, Resource, World};
+use std::cell::RefCell;
+use std::sync::Arc;
+
+type Middleware = Arc<dyn Fn(Option<&str>, ActionSeed) -> ActionSeed + Send + Sync>;
+
+#[derive(Resource, Default, Clone)]
+pub(crate) struct Middlewares(Vec<Middleware>);
+
+/// [`FlurxPlugin`] with middlewares that wrap every action passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will).
+///
+/// This is created by [`FlurxPlugin::with_middleware`].
+pub struct FlurxMiddlewarePlugin {
+    middlewares: Vec<Middleware>,
+}
+
+impl FlurxMiddlewarePlugin {
+    /// Adds a middleware.
+    ///
+    /// The middlewares are applied in the order they are added,
+    /// so the last one wraps all the others.
+    #[inline]
+    pub fn with_middleware(mut self, f: impl Fn(Option<&str>, ActionSeed) -> ActionSeed + Send + Sync + 'static) -> Self {
+        self.middlewares.push(Arc::new(f));
+        self
+    }
+}
+
+impl FlurxPlugin {
+    /// Creates [`FlurxPlugin`] with a middleware that wraps every action passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will).
+    ///
+    /// The middleware receives the name of the action given by [`ActionSeed::named`] and the action itself as [`ActionSeed`],
+    /// and returns the action to be run instead.
+    /// This is useful for cross-cutting decorators such as logging, timing, and fault injection for tests.
+    ///
+    /// Note that the returned action must run the passed action to complete it;
+    /// otherwise the output of the original action is never set and the task waits forever.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// App::new()
+    ///     .add_plugins((
+    ///         DefaultPlugins,
+    ///         FlurxPlugin::with_middleware(|name, seed| {
+    ///             let Some(name) = name.map(ToString::to_string) else {
+    ///                 return seed;
+    ///             };
+    ///             once::run(move || info!("start: {name}"))
+    ///                 .then(seed)
+    ///                 .omit()
+    ///         }),
+    ///     ));
+    /// ```
+    #[inline]
+    pub fn with_middleware(f: impl Fn(Option<&str>, ActionSeed) -> ActionSeed + Send + Sync + 'static) -> FlurxMiddlewarePlugin {
+        FlurxMiddlewarePlugin {
+            middlewares: Vec::new(),
+        }
+            .with_middleware(f)
+    }
+}
+
+impl Plugin for FlurxMiddlewarePlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FlurxCorePlugin>() {
+            app.add_plugins(FlurxPlugin);
+        }
+        app
+            .world_mut()
+            .get_resource_or_init::<Middlewares>()
+            .0
+            .extend(self.middlewares.iter().cloned());
+    }
+}
+
+/// Creates the runner of the action wrapped by the registered middlewares.
+#[cfg_attr(not(any(feature = "crash", feature = "budget")), allow(unused_variables))]
+pub(crate) fn create_runner<I, O>(
+    world: &World,
+    reactor: Entity,
+    action: Action<I, O>,
+    output: Output<O>,
+) -> BoxedRunner
+where
+    I: 'static,
+    O: 'static,
+{
+    let name = action.1.name().map(ToString::to_string);
+    #[cfg(feature = "chaos")]
+    let runner = chaos::create_runner(world, name.as_deref(), action, output);
+    #[cfg(not(feature = "chaos"))]
+    let runner = action.create_runner(output);
+    let runner = wrap_runner(world, name.as_deref(), runner);
+    #[cfg(feature = "crash")]
+    let runner = crash::create_runner(world, reactor, name.clone(), runner);
+    #[cfg(feature = "budget")]
+    let runner = budget::create_runner(world, reactor, name, runner);
+    runner
+}
+
+fn wrap_runner(
+    world: &World,
+    name: Option<&str>,
+    runner: BoxedRunner,
+) -> BoxedRunner {
+    let Some(middlewares) = world.get_resource::<Middlewares>().filter(|m| !m.0.is_empty()) else {
+        return runner;
+    };
+    let seed = middlewares.0.iter().fold(ActionSeed::new(|_, _| InnerRunner(None)), |seed, middleware| {
+        middleware(name, seed)
+    });
+    BoxedRunner::new(MiddlewareRunner {
+        runner: seed.create_runner((), Output::default()),
+        inner: Some(runner),
+    })
+}
+
+thread_local! {
+    /// The original runner lent by the [`MiddlewareRunner`] being run to its [`InnerRunner`].
+    static LENT_RUNNER: RefCell<Option<BoxedRunner>> = const { RefCell::new(None) };
+}
+
+/// The runner created by the middlewares, which owns the original runner.
+///
+/// The runners are not [`Send`], so the seed passed to the middlewares can not hold the original runner.
+/// Instead, the original runner is lent while this runner runs,
+/// and [`InnerRunner`] created by that seed takes it the first time it runs.
+/// If the middlewares never run the seed, the original runner is dropped together with this runner.
+struct MiddlewareRunner {
+    runner: BoxedRunner,
+    inner: Option<BoxedRunner>,
+}
+
+impl Runner for MiddlewareRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let Some(inner) = self.inner.take() else {
+            return self.runner.run(world, token);
+        };
+        let outer = LENT_RUNNER.replace(Some(inner));
+        let state = self.runner.run(world, token);
+        self.inner = LENT_RUNNER.replace(outer);
+        state
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.inner.as_ref().unwrap_or(&self.runner).access()
+    }
+}
+
+struct InnerRunner(Option<BoxedRunner>);
+
+impl Runner for InnerRunner {
+    #[inline]
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let runner = self.0.get_or_insert_with(|| {
+            LENT_RUNNER
+                .take()
+                .expect("The action passed to the middleware must be run by the action the middleware returns")
+        });
+        runner.run(world, token)
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.0.as_ref().and_then(Runner::access)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once};
+    use crate::prelude::{FlurxPlugin, Omit, Pipe, Reactor, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn wrap_all_actions() {
+        let mut app = test_app();
+        app.add_plugins(FlurxPlugin::with_middleware(|_, seed| {
+            once::run(|mut count: ResMut<Count>| {
+                count.increment();
+            })
+                .then(seed)
+                .omit()
+        }));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|| {})).await;
+                task.will(Update, once::run(|| {})).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn pass_action_name() {
+        let mut app = test_app();
+        app.add_plugins(FlurxPlugin::with_middleware(|name, seed| {
+            if name == Some("delay") {
+                once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })
+                    .then(seed)
+                    .omit()
+            } else {
+                seed
+            }
+        }));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(1).named("delay")).await;
+                task.will(Update, once::run(|| {})).await;
+            }));
+        });
+        app.update();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_action_piped_lazily() {
+        let mut app = test_app();
+        app.add_plugins(FlurxPlugin::with_middleware(|_, seed| {
+            delay::frames().with(1).pipe(seed).omit()
+        }));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn output_of_wrapped_action() {
+        let mut app = test_app();
+        app.add_plugins(FlurxPlugin::with_middleware(|_, seed| {
+            delay::frames().with(1).then(seed).omit()
+        }));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let num = task.will(Update, once::run(|| 3)).await;
+                task.will(Update, once::run(move |mut count: ResMut<Count>| {
+                    count.0 = num;
+                })).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+    }
+}
@@ -0,0 +1,342 @@
+#[cfg(feature = "cutscene")]
+use crate::action::cutscene::SkipCutscene;
+use crate::action::delay::{count_fixed_ticks, FixedTicks};
+#[cfg(feature = "input")]
+use crate::action::once::input::{release_removed_reactor, InputGuard};
+#[cfg(feature = "rng")]
+use crate::action::once::rng::FlurxRng;
+#[cfg(feature = "ui")]
+use crate::action::once::ui::{Toast, UiFocus};
+use crate::action::sync::Barriers;
+use crate::action::wait::app::AppReadiness;
+#[cfg(feature = "budget")]
+use crate::budget::ActionOverBudget;
+#[cfg(feature = "gc")]
+use crate::gc::{gc_system, GcMetrics, GcRequested};
+use crate::reactor::checkpoint::ReactorCheckpoints;
+use crate::reactor::output::ReactorOutputs;
+use crate::reactor::steps::reset_steps;
+use crate::reactor::time::ReactorTime;
+use crate::reactor::weak::cancel_requested_reactors;
+use crate::runner::{resolve_pending_schedules, CallCancellationHandlers, CompletedInFixedTick};
+use crate::{call_cancel_handlers, initialize_reactors, run_reactors, run_reactors_in_fixed_tick};
+use bevy::app::{App, FixedFirst, FixedLast, Last, Plugin, PluginGroup, PluginGroupBuilder, PostStartup};
+use bevy::prelude::IntoSystemConfigs;
+
+/// The group of all plugins of this library.
+///
+/// This is the same as [`FlurxPlugin`](crate::prelude::FlurxPlugin), but the plugins can be disabled or replaced individually,
+/// so that minimal or headless apps only pay for the subsystems they use.
+///
+/// - [`FlurxCorePlugin`]
+/// - [`FlurxDelayPlugin`]
+/// - [`FlurxSyncPlugin`]
+/// - [`FlurxGcPlugin`] (behind the `gc` feature flag)
+/// - [`FlurxBudgetPlugin`] (behind the `budget` feature flag)
+/// - [`FlurxInputPlugin`] (behind the `input` feature flag)
+/// - [`FlurxUiPlugin`] (behind the `ui` feature flag)
+/// - [`FlurxCutscenePlugin`] (behind the `cutscene` feature flag)
+/// - [`FlurxRngPlugin`] (behind the `rng` feature flag)
+/// - [`FlurxAudioPlugin`] (behind the `audio` feature flag)
+/// - [`FlurxAssetPlugin`] (behind the `asset` feature flag)
+/// - [`FlurxScenePlugin`] (behind the `scene` feature flag)
+/// - [`FlurxRenderPlugin`] (behind the `render` feature flag)
+///
+/// The subsystems that need no plugin, such as `chaos`, `crash`, `locale`, `menu` and `analytics`, are also behind their own feature flags,
+/// so headless apps do not compile what they do not use.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((
+///         MinimalPlugins,
+///         FlurxPlugins
+///             .build()
+///             .disable::<FlurxSyncPlugin>(),
+///     ));
+/// ```
+pub struct FlurxPlugins;
+
+impl PluginGroup for FlurxPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>()
+            .add(FlurxCorePlugin)
+            .add(FlurxDelayPlugin)
+            .add(FlurxSyncPlugin);
+        #[cfg(feature = "gc")]
+        let group = group.add(FlurxGcPlugin);
+        #[cfg(feature = "budget")]
+        let group = group.add(FlurxBudgetPlugin);
+        #[cfg(feature = "input")]
+        let group = group.add(FlurxInputPlugin);
+        #[cfg(feature = "ui")]
+        let group = group.add(FlurxUiPlugin);
+        #[cfg(feature = "cutscene")]
+        let group = group.add(FlurxCutscenePlugin);
+        #[cfg(feature = "rng")]
+        let group = group.add(FlurxRngPlugin);
+        #[cfg(feature = "audio")]
+        let group = group.add(FlurxAudioPlugin);
+        #[cfg(feature = "asset")]
+        let group = group.add(FlurxAssetPlugin);
+        #[cfg(feature = "scene")]
+        let group = group.add(FlurxScenePlugin);
+        #[cfg(feature = "render")]
+        let group = group.add(FlurxRenderPlugin);
+        group
+    }
+}
+
+/// Runs the reactors.
+///
+/// This is the only plugin required to run [`Reactor`](crate::prelude::Reactor).
+/// The reactors are run in [`PostStartup`], [`Last`] and [`FixedLast`],
+/// and [`FlurxDelayPlugin`] additionally uses [`FixedFirst`] to count the fixed ticks.
+pub struct FlurxCorePlugin;
+
+impl Plugin for FlurxCorePlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<CallCancellationHandlers>()
+            .register_type::<ReactorCheckpoints>()
+            .register_type::<ReactorTime>()
+            .init_resource::<CompletedInFixedTick>()
+            .init_resource::<ReactorOutputs>()
+            .add_systems(PostStartup, initialize_reactors)
+            .add_systems(FixedLast, run_reactors_in_fixed_tick)
+            .add_systems(Last, (
+                call_cancel_handlers.run_if(bevy::prelude::on_event::<CallCancellationHandlers>),
+                cancel_requested_reactors.before(run_reactors),
+                resolve_pending_schedules.before(run_reactors),
+                run_reactors,
+                reset_steps.after(run_reactors),
+            ));
+    }
+}
+
+/// Frees the runners of the reactors that vanished abnormally.
+///
+/// See [`gc`](crate::gc) for details.
+#[cfg(feature = "gc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gc")))]
+pub struct FlurxGcPlugin;
+
+#[cfg(feature = "gc")]
+impl Plugin for FlurxGcPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GcMetrics>()
+            .init_resource::<GcRequested>()
+            .add_systems(Last, gc_system.before(call_cancel_handlers));
+    }
+}
+
+/// Sets up [`ActionOverBudget`](crate::prelude::ActionOverBudget) sent by the instrumentation of [`budget`](crate::budget).
+#[cfg(feature = "budget")]
+#[cfg_attr(docsrs, doc(cfg(feature = "budget")))]
+pub struct FlurxBudgetPlugin;
+
+#[cfg(feature = "budget")]
+impl Plugin for FlurxBudgetPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app.add_event::<ActionOverBudget>();
+    }
+}
+
+/// Sets up [`delay::fixed_ticks`](crate::prelude::delay::fixed_ticks).
+pub struct FlurxDelayPlugin;
+
+impl Plugin for FlurxDelayPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<FixedTicks>()
+            .add_systems(FixedFirst, count_fixed_ticks);
+    }
+}
+
+/// Sets up [`InputGuard`](crate::prelude::InputGuard) used by [`once::input`](crate::prelude::once::input).
+#[cfg(feature = "input")]
+#[cfg_attr(docsrs, doc(cfg(feature = "input")))]
+pub struct FlurxInputPlugin;
+
+#[cfg(feature = "input")]
+impl Plugin for FlurxInputPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<InputGuard>()
+            .add_observer(release_removed_reactor);
+    }
+}
+
+/// Sets up [`UiFocus`](crate::prelude::UiFocus) and [`Toast`](crate::prelude::Toast) used by [`once::ui`](crate::prelude::once::ui) and [`wait::ui`](crate::prelude::wait::ui).
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+pub struct FlurxUiPlugin;
+
+#[cfg(feature = "ui")]
+impl Plugin for FlurxUiPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<Toast>()
+            .init_resource::<UiFocus>()
+            .register_type::<UiFocus>();
+    }
+}
+
+/// Sets up [`SkipCutscene`] used by [`Cutscene`](crate::prelude::Cutscene).
+#[cfg(feature = "cutscene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cutscene")))]
+pub struct FlurxCutscenePlugin;
+
+#[cfg(feature = "cutscene")]
+impl Plugin for FlurxCutscenePlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app.add_event::<SkipCutscene>();
+    }
+}
+
+/// Sets up [`FlurxRng`](crate::prelude::FlurxRng) used by [`once::rng`](crate::prelude::once::rng).
+#[cfg(feature = "rng")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rng")))]
+pub struct FlurxRngPlugin;
+
+#[cfg(feature = "rng")]
+impl Plugin for FlurxRngPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlurxRng>();
+    }
+}
+
+/// Sets up the synchronization between reactors:
+/// [`AppReadiness`](crate::prelude::AppReadiness) used by [`wait::app`](crate::prelude::wait::app),
+/// and the barriers used by [`sync`](crate::prelude::sync).
+pub struct FlurxSyncPlugin;
+
+impl Plugin for FlurxSyncPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<AppReadiness>()
+            .init_resource::<Barriers>();
+    }
+}
+
+/// Checks the setup of [`once::audio`](crate::prelude::once::audio) and [`wait::audio`](crate::prelude::wait::audio).
+///
+/// These actions require [`AudioPlugin`](bevy::audio::AudioPlugin), so a warning is logged if it has not been added.
+#[cfg(feature = "audio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
+pub struct FlurxAudioPlugin;
+
+#[cfg(feature = "audio")]
+impl Plugin for FlurxAudioPlugin {
+    #[inline]
+    fn build(&self, _: &mut App) {}
+
+    #[inline]
+    fn finish(&self, app: &mut App) {
+        warn_if_missing::<bevy::audio::AudioPlugin>(app, "once::audio and wait::audio");
+    }
+}
+
+/// Checks the setup of [`once::asset`](crate::prelude::once::asset) and [`wait::asset`](crate::prelude::wait::asset).
+///
+/// These actions require [`AssetPlugin`](bevy::asset::AssetPlugin), so a warning is logged if it has not been added.
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub struct FlurxAssetPlugin;
+
+#[cfg(feature = "asset")]
+impl Plugin for FlurxAssetPlugin {
+    #[inline]
+    fn build(&self, _: &mut App) {}
+
+    #[inline]
+    fn finish(&self, app: &mut App) {
+        warn_if_missing::<bevy::asset::AssetPlugin>(app, "once::asset and wait::asset");
+    }
+}
+
+/// Checks the setup of [`once::scene`](crate::prelude::once::scene) and [`wait::scene`](crate::prelude::wait::scene).
+///
+/// These actions require [`ScenePlugin`](bevy::scene::ScenePlugin), so a warning is logged if it has not been added.
+#[cfg(feature = "scene")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scene")))]
+pub struct FlurxScenePlugin;
+
+#[cfg(feature = "scene")]
+impl Plugin for FlurxScenePlugin {
+    #[inline]
+    fn build(&self, _: &mut App) {}
+
+    #[inline]
+    fn finish(&self, app: &mut App) {
+        warn_if_missing::<bevy::scene::ScenePlugin>(app, "once::scene and wait::scene");
+    }
+}
+
+#[cfg(any(feature = "audio", feature = "asset", feature = "scene"))]
+fn warn_if_missing<P: Plugin>(app: &App, actions: &str) {
+    if !app.is_plugin_added::<P>() {
+        bevy::log::warn!("{actions} require {}, but it has not been added", core::any::type_name::<P>());
+    }
+}
+
+/// Sets up [`wait::render`](crate::prelude::wait::render).
+#[cfg(feature = "render")]
+#[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+pub struct FlurxRenderPlugin;
+
+#[cfg(feature = "render")]
+impl Plugin for FlurxRenderPlugin {
+    #[inline]
+    fn build(&self, _: &mut App) {}
+
+    #[inline]
+    fn finish(&self, app: &mut App) {
+        crate::action::wait::render::setup(app);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once};
+    use crate::prelude::{FlurxCorePlugin, Reactor};
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Commands, MinimalPlugins, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn run_reactor_with_core_plugin_only() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, FlurxCorePlugin));
+        app.insert_resource(Count(0));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::frames().with(2)).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| count.increment())).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+        #[cfg(feature = "ui")]
+        assert!(!app.world().contains_resource::<crate::prelude::UiFocus>());
+        #[cfg(feature = "gc")]
+        assert!(!app.world().contains_resource::<crate::gc::GcMetrics>());
+    }
+}
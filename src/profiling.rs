@@ -0,0 +1,145 @@
+//! Provides the profiling of actions to find which actions dominate the frame time.
+//!
+//! Add [`FlurxProfilingPlugin`] to accumulate the execution time of each action into [`DiagnosticsStore`].
+
+use crate::action::Action;
+use crate::diagnostics::action_name;
+use crate::plugins::FlurxCorePlugin;
+use crate::prelude::{CancellationHandlers, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use crate::FlurxPlugin;
+use bevy::app::{App, First, Plugin};
+use bevy::diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::prelude::{ResMut, Resource, World};
+use bevy::utils::{HashMap, Instant};
+use std::time::Duration;
+
+/// The plugin that profiles the execution time of actions.
+///
+/// While this plugin is added, the time spent running each action passed to [`ReactorTask::will`](crate::prelude::ReactorTask::will)
+/// is accumulated per frame into the [`Diagnostic`] whose path is `flurx/action/{action}`,
+/// where `{action}` is the name given by [`ActionSeed::named`](crate::prelude::ActionSeed::named) or the type name of the action.
+/// The measurements are in milliseconds, so they can be printed by [`LogDiagnosticsPlugin`](bevy::diagnostic::LogDiagnosticsPlugin).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy::diagnostic::LogDiagnosticsPlugin;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins,
+///         FlurxProfilingPlugin,
+///         LogDiagnosticsPlugin::default(),
+///     ));
+/// ```
+pub struct FlurxProfilingPlugin;
+
+impl Plugin for FlurxProfilingPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FlurxCorePlugin>() {
+            app.add_plugins(FlurxPlugin);
+        }
+        app
+            .init_resource::<DiagnosticsStore>()
+            .init_resource::<ActionTimings>()
+            .add_systems(First, flush_timings);
+    }
+}
+
+/// The execution time of each action accumulated in the current frame.
+#[derive(Resource, Default)]
+struct ActionTimings(HashMap<String, Duration>);
+
+/// Returns the name of the action if the profiling is enabled.
+pub(crate) fn describe<I, O>(world: &World, action: &Action<I, O>) -> Option<String>
+where
+    I: 'static,
+    O: 'static,
+{
+    world.contains_resource::<ActionTimings>().then(|| action_name(action))
+}
+
+/// Wraps the runner to measure its execution time.
+pub(crate) fn create_runner(action: String, runner: BoxedRunner) -> BoxedRunner {
+    BoxedRunner::new(ProfilingRunner {
+        runner,
+        action,
+    })
+}
+
+struct ProfilingRunner {
+    runner: BoxedRunner,
+    action: String,
+}
+
+impl Runner for ProfilingRunner {
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let start = Instant::now();
+        let status = self.runner.run(world, token);
+        let elapsed = start.elapsed();
+        if let Some(mut timings) = world.get_resource_mut::<ActionTimings>() {
+            *timings.0.entry_ref(&self.action).or_default() += elapsed;
+        }
+        status
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        self.runner.access()
+    }
+}
+
+fn flush_timings(
+    mut timings: ResMut<ActionTimings>,
+    mut store: ResMut<DiagnosticsStore>,
+) {
+    let now = Instant::now();
+    for (action, elapsed) in timings.0.drain() {
+        let path = DiagnosticPath::new(format!("flurx/action/{action}"));
+        if store.get(&path).is_none() {
+            store.add(Diagnostic::new(path.clone()).with_suffix("ms"));
+        }
+        if let Some(diagnostic) = store.get_mut(&path) {
+            diagnostic.add_measurement(DiagnosticMeasurement {
+                time: now,
+                value: elapsed.as_secs_f64() * 1000.,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{FlurxProfilingPlugin, Reactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::diagnostic::{DiagnosticPath, DiagnosticsStore};
+    use bevy::prelude::{Commands, Local};
+
+    #[test]
+    fn record_action_timings() {
+        let mut app = test_app();
+        app.add_plugins(FlurxProfilingPlugin);
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|mut count: Local<u32>| {
+                    *count += 1;
+                    *count == 3
+                }).named("count")).await;
+            }));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        let store = app.world().resource::<DiagnosticsStore>();
+        let diagnostic = store.get(&DiagnosticPath::new("flurx/action/count")).unwrap();
+        assert_eq!(diagnostic.history_len(), 3);
+    }
+}
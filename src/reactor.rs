@@ -1,18 +1,45 @@
+use crate::action::Action;
+use crate::reactor::group::ReactorGroup;
+use crate::reactor::params::ReactorParams;
+use crate::reactor::output::StoreOutputSelector;
+use crate::reactor::sleep::ElasticPolling;
+use crate::reactor::steps::MaxStepsPerFrame;
+use crate::reactor::weak::WeakReactorHandle;
 use crate::core::scheduler::CoreScheduler;
+#[cfg(feature = "gc")]
+use crate::gc::request_gc;
 use crate::task::ReactorTask;
 use crate::world_ptr::WorldPtr;
-use bevy::ecs::component::{ComponentHooks, StorageType};
-use bevy::ecs::world::DeferredWorld;
-use bevy::prelude::{Component, Entity, ReflectComponent};
+use bevy::app::Main;
+use bevy::ecs::component::{ComponentHooks, ComponentId, StorageType};
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::ecs::world::{DeferredWorld, WorldId};
+use bevy::log::error;
+use bevy::prelude::{Commands, Component, Entity, EntityCommands, EntityWorldMut, ReflectComponent, World};
 use bevy::reflect::Reflect;
+use bevy::utils::synccell::SyncCell;
 use std::future::Future;
 use std::marker::PhantomData;
+use std::pin::Pin;
+
+pub mod attach;
+pub mod checkpoint;
+pub mod commands;
+pub mod group;
+pub mod inbox;
+pub(crate) mod output;
+pub mod params;
+pub(crate) mod sleep;
+pub(crate) mod steps;
+pub mod sub;
+pub mod time;
+pub mod weak;
 
 /// [`Reactor`] represents the asynchronous processing flow.
 ///
 /// This structure is created by [`Reactor::schedule`].
 ///
-/// Despawn the entity attached this component if you want to interrupt the processing flow.
+/// Despawn the entity attached this component or call [`ReactorCommands::cancel`](crate::prelude::ReactorCommands::cancel) if you want to interrupt the processing flow.
 ///
 /// After all scheduled processes have completed, the entity attached to this component
 /// and it's children will be despawn.
@@ -22,20 +49,51 @@ pub struct Reactor<F, Fut>
 where
     F: FnOnce(ReactorTask) -> Fut + Send + Sync + 'static,
     Fut: Future + Send + Sync + 'static,
+    Fut::Output: Send + 'static,
 {
     f: Option<F>,
+    #[reflect(ignore)]
+    cleanup: Option<Cleanup>,
+    #[reflect(ignore)]
+    params: Option<InsertParams>,
+    group: Option<ReactorGroup>,
+    #[reflect(ignore)]
+    weak: Option<WeakReactorHandle>,
+    max_steps_per_frame: Option<usize>,
+    elastic_polling: bool,
     _m: PhantomData<Fut>,
 }
 
+type Cleanup = Box<dyn FnOnce(&mut Commands) + Send + Sync>;
+
+type InsertParams = Box<dyn FnOnce(&mut EntityCommands) + Send + Sync>;
+
+/// Holds the cleanup registered by [`Reactor::finally`] until the reactor is removed.
+#[derive(Component)]
+#[component(on_remove = run_cleanup)]
+struct ReactorCleanup(Option<Cleanup>);
+
+fn run_cleanup(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let Some(cleanup) = world
+        .get_mut::<ReactorCleanup>(entity)
+        .and_then(|mut cleanup| cleanup.0.take()) else {
+        return;
+    };
+    cleanup(&mut world.commands());
+}
+
 impl<F, Fut> Reactor<F, Fut>
 where
     F: FnOnce(ReactorTask) -> Fut + Send + Sync + 'static,
     Fut: Future + Send + Sync + 'static,
+    Fut::Output: Send + 'static,
 {
     /// Create new [`Reactor`].
     ///
     /// The scheduled [`Reactor`] will be run and initialized at [Last](bevy::prelude::Last) schedule(and also initialized at [`PostStartup`](bevy::prelude::PostStartup)) ,
     ///
+    /// The output of the future can be awaited from other reactors with [`wait::reactor::finished`](crate::prelude::wait::reactor::finished).
+    ///
     /// ## Examples
     ///
     /// ```no_run
@@ -52,22 +110,321 @@ where
     pub fn schedule(f: F) -> Reactor<F, Fut> {
         Self {
             f: Some(f),
+            cleanup: None,
+            params: None,
+            group: None,
+            weak: None,
+            max_steps_per_frame: None,
+            elastic_polling: false,
+            _m: PhantomData,
+        }
+    }
+
+    /// Create new [`Reactor`] that belongs to the `group`.
+    ///
+    /// [`ReactorGroup`] is inserted into the entity along with the reactor,
+    /// so that all members of the group can be cancelled at once with [`ReactorGroupExtension::cancel_reactor_group`](crate::prelude::ReactorGroupExtension::cancel_reactor_group).
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule_in("cutscene", |task| async move{
+    ///     task.will(Update, delay::frames().with(60)).await;
+    /// });
+    /// ```
+    pub fn schedule_in(group: impl Into<ReactorGroup>, f: F) -> Reactor<F, Fut> {
+        Self {
+            f: Some(f),
+            cleanup: None,
+            params: None,
+            group: Some(group.into()),
+            weak: None,
+            max_steps_per_frame: None,
+            elastic_polling: false,
             _m: PhantomData,
         }
     }
+
+    /// Create new [`Reactor`] along with [`WeakReactorHandle`].
+    ///
+    /// The handle can be used to await the completion, query the status, or cancel the reactor
+    /// without holding the [`Entity`] or touching the [`World`].
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct Loading(WeakReactorHandle);
+    ///
+    /// fn setup(mut commands: Commands){
+    ///     let (reactor, handle) = Reactor::schedule_with_handle(|task| async move{
+    ///         task.will(Update, delay::frames().with(60)).await;
+    ///     });
+    ///     commands.spawn(reactor);
+    ///     commands.insert_resource(Loading(handle));
+    /// }
+    /// ```
+    pub fn schedule_with_handle(f: F) -> (Reactor<F, Fut>, WeakReactorHandle) {
+        let handle = WeakReactorHandle::new();
+        let mut reactor = Self::schedule(f);
+        reactor.weak = Some(handle.clone());
+        (reactor, handle)
+    }
+
+    /// Limits how many times this reactor advances to its next `await` per frame.
+    ///
+    /// By default, a reactor may advance several steps in a frame, e.g. twice when it starts
+    /// or once per fixed tick when several fixed ticks run in a frame.
+    /// With this limit, the remaining steps are deferred to the following frames,
+    /// so long chains of actions are amortized across frames.
+    /// Note that the actions already awaited keep running on their schedules.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     for _ in 0..100 {
+    ///         task.will(FixedUpdate, once::run(|| {})).await;
+    ///     }
+    /// })
+    ///     .with_max_steps_per_frame(1);
+    /// ```
+    #[inline]
+    pub fn with_max_steps_per_frame(mut self, max: usize) -> Reactor<F, Fut> {
+        self.max_steps_per_frame = Some(max);
+        self
+    }
+
+    /// Stops polling this reactor every frame while its awaited actions are running.
+    ///
+    /// Normally every reactor is polled each frame, which resumes its future to check whether the awaited action has completed.
+    /// With elastic polling, the reactor sleeps after each poll and its future is resumed only when one of its actions
+    /// has completed or been canceled.
+    ///
+    /// This only saves the cost of resuming the future.
+    /// The awaited actions, e.g. [`wait::event::comes`](crate::prelude::wait::event::comes) or [`wait::switch::on`](crate::prelude::wait::switch::on),
+    /// keep running on their schedules every frame, and a sleeping reactor is still visited, and skipped, each frame.
+    ///
+    /// Use this only if the reactor awaits nothing but actions:
+    /// futures that do not run on schedules, such as [`ReactorTask::recv`](crate::prelude::ReactorTask::recv),
+    /// never wake the reactor.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct QuestStarted;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, wait::switch::on::<QuestStarted>()).await;
+    ///     task.will(Update, wait::event::comes::<AppExit>()).await;
+    /// })
+    ///     .with_elastic_polling();
+    /// ```
+    #[inline]
+    pub fn with_elastic_polling(mut self) -> Reactor<F, Fut> {
+        self.elastic_polling = true;
+        self
+    }
+
+    /// Registers the action that is run after the reactor has finished, even if it is canceled mid-await.
+    ///
+    /// The cleanup action is run to completion by a new reactor on the schedule `label`,
+    /// when this reactor is completed, canceled, or its entity is despawned.
+    /// This is useful for guaranteed cleanup such as releasing an input lock.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Resource)]
+    /// struct InputLock;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, once::res::insert().with(InputLock)).await;
+    ///     task.will(Update, wait::input::just_pressed().with(KeyCode::Enter)).await;
+    /// })
+    ///     .finally(Update, once::res::remove::<InputLock>());
+    /// ```
+    pub fn finally<Label, I, O>(
+        mut self,
+        label: Label,
+        cleanup: impl Into<Action<I, O>> + Send + Sync + 'static,
+    ) -> Reactor<F, Fut>
+    where
+        Label: ScheduleLabel,
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+    {
+        self.cleanup.replace(Box::new(move |commands: &mut Commands| {
+            commands.spawn(Reactor::schedule(move |task| async move {
+                task.will(label, cleanup).await;
+            }));
+        }));
+        self
+    }
+
+    /// Spawns this reactor into `world`, and runs the [`Main`] schedule repeatedly until the reactor has finished
+    /// or `max_iterations` is reached.
+    ///
+    /// Returns true if the reactor has finished within the limit.
+    /// Each iteration is the same as one frame of [`App::update`](bevy::app::App::update),
+    /// so unit tests don't need to guess how many updates a flow requires.
+    /// Note that the actions waiting for the real time, such as [`delay::time`](crate::prelude::delay::time),
+    /// may need [`TimeUpdateStrategy::ManualDuration`](bevy::time::TimeUpdateStrategy::ManualDuration) to finish within the limit.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, FlurxPlugin));
+    /// let finished = Reactor::schedule(|task| async move{
+    ///     task.will(Update, delay::frames().with(30)).await;
+    /// })
+    ///     .fast_forward(app.world_mut(), 100);
+    /// assert!(finished);
+    /// ```
+    pub fn fast_forward(self, world: &mut World, max_iterations: usize) -> bool {
+        let entity = world.spawn(self).id();
+        for _ in 0..max_iterations {
+            world.run_schedule(Main);
+            world.clear_trackers();
+            if world.get::<NativeReactor>(entity).is_none() {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// The boxed future of the reactor created by [`Reactor::schedule_resumable`] and [`Reactor::schedule_with`].
+pub type ResumableFuture = Pin<Box<dyn Future<Output=()> + Send + Sync>>;
+
+/// The boxed function of the reactor created by [`Reactor::schedule_resumable`] and [`Reactor::schedule_with`].
+pub type ResumableFn = Box<dyn FnOnce(ReactorTask) -> ResumableFuture + Send + Sync>;
+
+impl Reactor<ResumableFn, ResumableFuture> {
+    /// Create new [`Reactor`] that can be resumed from the last checkpoint recorded by [`ReactorTask::checkpoint`].
+    ///
+    /// `f` receives the label of the last checkpoint in [`ReactorCheckpoints`](crate::prelude::ReactorCheckpoints) inserted together with the reactor,
+    /// or `None` if there is no checkpoint, so the reactor can fast-forward to it after loading a save.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// fn load(mut commands: Commands, saved: Res<SavedCheckpoints>){
+    ///     commands.spawn((
+    ///         saved.0.clone(),
+    ///         Reactor::schedule_resumable(|task, checkpoint| async move{
+    ///             if checkpoint.as_deref() != Some("boss") {
+    ///                 task.will(Update, delay::frames().with(30)).await;
+    ///                 task.checkpoint("boss").await;
+    ///             }
+    ///             task.will(Update, wait::event::comes::<AppExit>()).await;
+    ///         }),
+    ///     ));
+    /// }
+    ///
+    /// #[derive(Resource)]
+    /// struct SavedCheckpoints(ReactorCheckpoints);
+    /// ```
+    pub fn schedule_resumable<R, RFut>(f: R) -> Self
+    where
+        R: FnOnce(ReactorTask, Option<String>) -> RFut + Send + Sync + 'static,
+        RFut: Future + Send + Sync + 'static,
+    {
+        Self::schedule(Box::new(move |task: ReactorTask| -> ResumableFuture {
+            Box::pin(async move {
+                let checkpoint = task.last_checkpoint().await;
+                f(task, checkpoint).await;
+            })
+        }))
+    }
+
+    /// Create new [`Reactor`] with the parameters `params`.
+    ///
+    /// `params` is inserted into the reactor entity as [`ReactorParams`],
+    /// so the parameters of the flow are visible to reflection-based inspectors and can be saved,
+    /// rather than being hidden inside the closure.
+    /// `f` receives the parameters in [`ReactorParams`] when the reactor starts,
+    /// so if [`ReactorParams`] is inserted together with the reactor, e.g. after loading a save, that one is used instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Reflect, Clone)]
+    /// struct Quest {
+    ///     wait_frames: usize,
+    /// }
+    ///
+    /// fn start_quest(mut commands: Commands){
+    ///     commands.spawn(Reactor::schedule_with(Quest { wait_frames: 30 }, |task, quest| async move{
+    ///         task.will(Update, delay::frames().with(quest.wait_frames)).await;
+    ///     }));
+    /// }
+    ///
+    /// App::new()
+    ///     .register_type::<ReactorParams<Quest>>()
+    ///     .add_systems(Startup, start_quest);
+    /// ```
+    pub fn schedule_with<P, R, RFut>(params: P, f: R) -> Self
+    where
+        P: Clone + Send + Sync + 'static,
+        R: FnOnce(ReactorTask, P) -> RFut + Send + Sync + 'static,
+        RFut: Future + Send + Sync + 'static,
+    {
+        let fallback = params.clone();
+        let mut reactor = Self::schedule(Box::new(move |task: ReactorTask| -> ResumableFuture {
+            Box::pin(async move {
+                let params = task.params::<P>().await.unwrap_or(fallback);
+                f(task, params).await;
+            })
+        }));
+        reactor.params.replace(Box::new(move |entity_commands: &mut EntityCommands| {
+            entity_commands.queue(move |mut entity: EntityWorldMut| {
+                if !entity.contains::<ReactorParams<P>>() {
+                    entity.insert(ReactorParams(params));
+                }
+            });
+        }));
+        reactor
+    }
 }
 
 impl<F, Fut> Component for Reactor<F, Fut>
 where
     F: FnOnce(ReactorTask) -> Fut + Send + Sync + 'static,
     Fut: Future + Send + Sync + 'static,
+    Fut::Output: Send + 'static,
 {
     const STORAGE_TYPE: StorageType = StorageType::Table;
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         hooks
             .on_add(|mut world: DeferredWorld, entity: Entity, _| {
-                let f = {
+                let (f, cleanup, params, group, weak, max_steps_per_frame, elastic_polling) = {
                     let mut entity_mut = world.entity_mut(entity);
                     let Some(mut flow) = entity_mut.get_mut::<Reactor<F, Fut>>() else {
                         return;
@@ -75,33 +432,124 @@ where
                     let Some(f) = flow.f.take() else {
                         return;
                     };
-                    f
+                    (f, flow.cleanup.take(), flow.params.take(), flow.group.take(), flow.weak.take(), flow.max_steps_per_frame, flow.elastic_polling)
                 };
-                world.commands().entity(entity).insert(NativeReactor::schedule(entity, f));
+                let mut entity_commands = world.commands();
+                let mut entity_commands = entity_commands.entity(entity);
+                entity_commands.insert((
+                    NativeReactor::schedule(entity, f),
+                    RunningReactor,
+                ));
+                if let Some(cleanup) = cleanup {
+                    entity_commands.insert(ReactorCleanup(Some(cleanup)));
+                }
+                if let Some(params) = params {
+                    params(&mut entity_commands);
+                }
+                if let Some(group) = group {
+                    entity_commands.insert(group);
+                }
+                if let Some(weak) = weak {
+                    entity_commands.insert(weak.link());
+                }
+                if let Some(max) = max_steps_per_frame {
+                    entity_commands.insert(MaxStepsPerFrame::new(max));
+                }
+                if elastic_polling {
+                    entity_commands.insert(ElasticPolling::new());
+                }
             });
     }
 }
 
+/// The marker component inserted into the entity to which [`Reactor`] is attached.
+///
+/// [`Reactor`] is generic over its future, so query the running reactors from a normal system with this marker,
+/// and control them via [`ReactorCommandsExtension::reactor`](crate::prelude::ReactorCommandsExtension::reactor).
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn cancel_all(
+///     mut commands: Commands,
+///     reactors: Query<Entity, With<RunningReactor>>,
+/// ){
+///     for reactor in reactors.iter(){
+///         commands.reactor(reactor).cancel();
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[reflect(Component)]
+pub struct RunningReactor;
+
+/// The marker component that pauses the [`Reactor`] attached to the same entity.
+///
+/// This is inserted and removed by [`ReactorCommands::pause`](crate::prelude::ReactorCommands::pause)
+/// and [`ReactorCommands::resume`](crate::prelude::ReactorCommands::resume), or can be spawned together with the reactor to start it paused.
+///
+/// While this component exists, neither the reactor nor its pending actions are run,
+/// so in-flight awaits simply stop being polled without being canceled.
+/// They continue from where they left off once this component is removed.
+///
+/// Note that the actions which measure time, such as [`delay::time`](crate::prelude::delay::time),
+/// only count the time while they are running.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Gameplay;
+///
+/// fn open_pause_menu(
+///     mut commands: Commands,
+///     reactors: Query<Entity, (With<RunningReactor>, With<Gameplay>)>,
+/// ){
+///     for reactor in reactors.iter(){
+///         commands.reactor(reactor).pause();
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[reflect(Component)]
+pub struct ReactorPaused;
+
 #[derive(Component)]
+#[cfg_attr(feature = "gc", component(on_remove = request_gc))]
 pub(crate) struct NativeReactor {
     pub(crate) scheduler: CoreScheduler<WorldPtr>,
     pub(crate) initialized: bool,
+    entity: Entity,
+    world_id: Option<WorldId>,
 }
 
 impl NativeReactor {
     fn schedule<F>(entity: Entity, f: impl FnOnce(ReactorTask) -> F + Send + Sync + 'static) -> NativeReactor
     where
         F: Future + Send + Sync,
+        F::Output: Send + 'static,
     {
         let scheduler = CoreScheduler::schedule(move |task| async move {
-            f(ReactorTask {
+            let output = f(ReactorTask {
                 task,
                 entity,
             }).await;
+            task.will(StoreOutputSelector {
+                entity,
+                output: SyncCell::new(Some(output)),
+            }).await;
         });
         Self {
             scheduler,
             initialized: false,
+            entity,
+            world_id: None,
         }
     }
 
@@ -110,6 +558,17 @@ impl NativeReactor {
         if self.scheduler.finished {
             return true;
         }
+        // The id is compared before the pointer is dereferenced by the scheduler,
+        // so the reactor moved into another world never touches the state that belongs to the previous one.
+        if self.world_id.is_some() && self.world_id != world.id() {
+            error!(
+                "The reactor {} was aborted because it is run in a different world from the one it started in.",
+                self.entity
+            );
+            self.scheduler.finished = true;
+            return true;
+        }
+        self.world_id = world.id();
 
         #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
         {
@@ -127,17 +586,95 @@ impl NativeReactor {
 #[cfg(test)]
 mod tests {
     use crate::action::{delay, once, wait};
-    use crate::prelude::Reactor;
+    use crate::prelude::{Reactor, ReactorCommandsExtension, ReactorPaused, RunningReactor};
     use crate::reactor::NativeReactor;
     use crate::tests::test_app;
     use bevy::app::{Startup, Update};
+    use bevy::ecs::schedule::ScheduleLabel;
     use bevy::ecs::system::RunSystemOnce;
-    use bevy::prelude::{Commands, Entity, Query, ResMut, Resource, With};
+    use bevy::prelude::{Commands, Entity, Query, ResMut, Resource, Schedule, Time, Virtual, With};
+    use bevy::time::TimeUpdateStrategy;
     use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
 
     #[derive(Resource, Debug, Default, Eq, PartialEq)]
     struct Count(usize);
 
+    #[test]
+    fn limit_steps_per_frame() {
+        use bevy::app::FixedUpdate;
+        use bevy::time::Fixed;
+        use bevy_test_helper::resource::count::Count;
+
+        let run = |max: Option<usize>| {
+            let mut app = test_app();
+            app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(10)));
+            app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(30)));
+            let reactor = Reactor::schedule(|task| async move {
+                for _ in 0..100 {
+                    task.will(FixedUpdate, once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })).await;
+                }
+            });
+            match max {
+                Some(max) => app.world_mut().spawn(reactor.with_max_steps_per_frame(max)),
+                None => app.world_mut().spawn(reactor),
+            };
+            let mut counts = Vec::new();
+            for _ in 0..6 {
+                app.update();
+                counts.push(app.world().resource::<Count>().0);
+            }
+            counts
+        };
+        assert_eq!(run(None).last(), Some(&15));
+        for max in [1, 2] {
+            let counts = run(Some(max));
+            // The action already awaited at the start of the frame may also complete in addition to the limited steps.
+            assert!(counts.windows(2).all(|w| w[1] - w[0] <= max + 1));
+            assert!(0 < counts[5] && counts[5] < 15);
+        }
+    }
+
+    #[test]
+    fn sleep_while_actions_running_with_elastic_polling() {
+        use crate::reactor::sleep::ElasticPolling;
+        use bevy::prelude::Res;
+        use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+        use bevy_test_helper::resource::count::Count;
+
+        let run = |elastic: bool| {
+            let mut app = test_app();
+            let reactor = Reactor::schedule(|task| async move {
+                for _ in 0..2 {
+                    task.will(Update, wait::until(|b: Res<Bool>| b.0)).await;
+                    task.will(Update, once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })).await;
+                }
+            });
+            let entity = match elastic {
+                true => app.world_mut().spawn(reactor.with_elastic_polling()).id(),
+                false => app.world_mut().spawn(reactor).id(),
+            };
+            let mut counts = Vec::new();
+            for i in 0..8 {
+                if i == 3 {
+                    app.set_bool(true);
+                }
+                app.update();
+                counts.push(app.world().resource::<Count>().0);
+                if elastic && i == 2 {
+                    assert_eq!(app.world().get::<ElasticPolling>(entity), Some(&ElasticPolling { awake: false }));
+                }
+            }
+            counts
+        };
+        assert_eq!(run(true), run(false));
+        assert_eq!(run(true).last(), Some(&2));
+    }
+
     #[test]
     fn cancel_if_reactor_removed() {
         let mut app = test_app();
@@ -168,6 +705,281 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cancel_via_handle() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::until(|mut count: ResMut<Count>| {
+                        count.0 += 1;
+                        false
+                    }),
+                )
+                    .await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).cancel();
+            })
+            .expect("Failed to run system");
+        for _ in 0..10 {
+            app.update();
+            app.assert_resource_eq(Count(1));
+        }
+        assert!(app
+            .world_mut()
+            .query_filtered::<(), With<RunningReactor>>()
+            .get_single(app.world())
+            .is_err());
+    }
+
+    #[test]
+    fn pause_and_resume() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::until(|mut count: ResMut<Count>| {
+                        count.0 += 1;
+                        count.0 == 3
+                    }),
+                )
+                    .await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 = 10;
+                })).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).pause();
+            })
+            .expect("Failed to run system");
+        for _ in 0..10 {
+            app.update();
+            app.assert_resource_eq(Count(1));
+        }
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).resume();
+            })
+            .expect("Failed to run system");
+        app.update();
+        app.assert_resource_eq(Count(2));
+        app.update();
+        app.assert_resource_eq(Count(3));
+        app.update();
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn not_start_while_paused() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((
+                Reactor::schedule(|task| async move {
+                    task.will(Update, once::run(|mut count: ResMut<Count>| {
+                        count.0 += 1;
+                    })).await;
+                }),
+                ReactorPaused,
+            ));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).resume();
+            })
+            .expect("Failed to run system");
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_even_if_paused_while_reactor_paused() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(
+                    Update,
+                    wait::until(|mut count: ResMut<Count>| {
+                        count.0 += 1;
+                        count.0 == 3
+                    })
+                        .run_even_if_paused(),
+                )
+                    .await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 = 10;
+                })).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).pause();
+            })
+            .expect("Failed to run system");
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).resume();
+            })
+            .expect("Failed to run system");
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[test]
+    fn run_even_if_virtual_time_paused() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, delay::time().with(Duration::from_millis(300)).run_even_if_paused()).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                })).await;
+                task.will(Update, delay::time().with(Duration::from_millis(300))).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                })).await;
+            }));
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_cleanup_after_canceled() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|| false)).await;
+            })
+                .finally(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                })));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).cancel();
+            })
+            .expect("Failed to run system");
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn run_cleanup_after_completed() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                })).await;
+            })
+                .finally(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 *= 10;
+                })));
+        });
+        for _ in 0..5 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(10));
+    }
+
+    #[derive(ScheduleLabel, Debug, Clone, Eq, PartialEq, Hash)]
+    struct Custom;
+
+    #[test]
+    fn run_on_schedule_replaced_after_reactor_started() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Custom, once::run(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                })).await;
+                task.will(Custom, once::run(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                })).await;
+            }));
+        });
+        app.update();
+        app.world_mut().run_schedule(Custom);
+        app.assert_resource_eq(Count(1));
+
+        app.add_schedule(Schedule::new(Custom));
+        app.update();
+        app.world_mut().run_schedule(Custom);
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn retarget_schedule() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Custom, wait::until(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                    false
+                })).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(0));
+
+        app.world_mut()
+            .run_system_once(|mut cmd: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                cmd.reactor(reactor.single()).retarget_schedule(Custom, Update);
+            })
+            .expect("Failed to run system");
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
     #[test]
     fn despawn_after_finished_reactor() {
         let mut app = test_app();
@@ -213,4 +1025,62 @@ mod tests {
         app.assert_resource_eq(Count(2));
         app.assert_resource_eq(Bool2(true));
     }
+
+    #[test]
+    fn abort_if_moved_to_another_world() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|mut count: ResMut<Count>| {
+                    count.0 += 1;
+                    false
+                })).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.0 = 10;
+                })).await;
+            }));
+        });
+        app.update();
+        app.assert_resource_eq(Count(1));
+
+        let entity = app
+            .world_mut()
+            .query_filtered::<Entity, With<NativeReactor>>()
+            .single(app.world());
+        let reactor = app.world_mut().entity_mut(entity).take::<NativeReactor>().unwrap();
+        let mut other = test_app();
+        other.init_resource::<Count>();
+        other.world_mut().spawn(reactor);
+        for _ in 0..3 {
+            other.update();
+        }
+        other.assert_resource_eq(Count(0));
+        assert!(other
+            .world_mut()
+            .query::<&NativeReactor>()
+            .get_single(other.world())
+            .is_err());
+    }
+
+    #[test]
+    fn fast_forward_until_finished() {
+        let mut app = test_app();
+        app.init_resource::<Count>();
+        let finished = Reactor::schedule(|task| async move {
+            task.will(Update, delay::frames().with(10)).await;
+            task.will(Update, once::run(|mut count: ResMut<Count>| {
+                count.0 += 1;
+            })).await;
+        })
+            .fast_forward(app.world_mut(), 20);
+        assert!(finished);
+        app.assert_resource_eq(Count(1));
+
+        let finished = Reactor::schedule(|task| async move {
+            task.will(Update, wait::until(|| false)).await;
+        })
+            .fast_forward(app.world_mut(), 5);
+        assert!(!finished);
+    }
 }
@@ -0,0 +1,174 @@
+//! Provides [`AttachReactorExtension`], which starts a [`Reactor`] for each entity that gains a component.
+
+use crate::prelude::Reactor;
+use crate::task::ReactorTask;
+use bevy::app::App;
+use bevy::hierarchy::{BuildChildren, Children, DespawnRecursiveExt};
+use bevy::prelude::{Commands, Component, Entity, OnAdd, OnRemove, Query, Trigger, With};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// The marker of the reactor spawned by [`AttachReactorExtension::attach_reactor`].
+#[derive(Component)]
+struct AttachedReactor<C>(PhantomData<C>);
+
+/// Starts a [`Reactor`] for each entity that gains the component.
+pub trait AttachReactorExtension {
+    /// Spawns a [`Reactor`] as a child of each entity gaining the component `C`.
+    ///
+    /// `f` receives the entity and the task of the reactor.
+    /// The reactor is canceled, and its pending runners are dropped,
+    /// when `C` is removed from the entity or the entity is despawned.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// App::new()
+    ///     .add_plugins((DefaultPlugins, FlurxPlugin))
+    ///     .attach_reactor::<Enemy, _, _>(|enemy, task| async move{
+    ///         loop {
+    ///             task.will(Update, delay::time().with(Duration::from_secs(1))).await;
+    ///             task.will(Update, once::run(move || info!("{enemy} is alive"))).await;
+    ///         }
+    ///     });
+    /// ```
+    fn attach_reactor<C, F, Fut>(&mut self, f: F) -> &mut Self
+    where
+        C: Component,
+        F: Fn(Entity, ReactorTask) -> Fut + Send + Sync + 'static,
+        Fut: Future + Send + Sync + 'static,
+        Fut::Output: Send + 'static;
+}
+
+impl AttachReactorExtension for App {
+    fn attach_reactor<C, F, Fut>(&mut self, f: F) -> &mut Self
+    where
+        C: Component,
+        F: Fn(Entity, ReactorTask) -> Fut + Send + Sync + 'static,
+        Fut: Future + Send + Sync + 'static,
+        Fut::Output: Send + 'static,
+    {
+        let f = Arc::new(f);
+        self
+            .add_observer(move |trigger: Trigger<OnAdd, C>, mut commands: Commands| {
+                let entity = trigger.entity();
+                let f = f.clone();
+                commands.spawn((
+                    AttachedReactor::<C>(PhantomData),
+                    Reactor::schedule(move |task| f(entity, task)),
+                ))
+                    .set_parent(entity);
+            })
+            .add_observer(|trigger: Trigger<OnRemove, C>,
+                           mut commands: Commands,
+                           children: Query<&Children>,
+                           reactors: Query<Entity, With<AttachedReactor<C>>>| {
+                let Ok(children) = children.get(trigger.entity()) else {
+                    return;
+                };
+                for reactor in reactors.iter_many(children) {
+                    if let Some(entity_commands) = commands.get_entity(reactor) {
+                        entity_commands.despawn_recursive();
+                    }
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{ActionSeed, AttachReactorExtension};
+    use crate::reactor::NativeReactor;
+    use crate::runner::{CancellationHandlers, Runner, RunnerIs};
+    use crate::tests::test_app;
+    use bevy::app::Update;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, Component, Entity, In, Query, ResMut, With, World};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Component)]
+    struct Enemy;
+
+    struct WaitForeverRunner;
+
+    impl Runner for WaitForeverRunner {
+        fn run(&mut self, _: &mut World, handlers: &mut CancellationHandlers) -> RunnerIs {
+            if handlers.handlers.is_empty() {
+                handlers.register(|world| {
+                    world.resource_mut::<Count>().increment();
+                });
+            }
+            RunnerIs::Running
+        }
+    }
+
+    fn wait_forever_and_count_cancel() -> ActionSeed {
+        ActionSeed::new(|_, _| WaitForeverRunner)
+    }
+
+    #[test]
+    fn start_reactor_when_component_added() {
+        let mut app = test_app();
+        app.attach_reactor::<Enemy, _, _>(|entity, task| async move {
+            task.will(Update, once::run(move |In(e): In<Entity>, mut count: ResMut<Count>| {
+                assert_eq!(e, entity);
+                count.increment();
+            }).with(entity)).await;
+            task.will(Update, wait::until(|| false)).await;
+        });
+        app.world_mut().spawn(Enemy);
+        app.world_mut().spawn(Enemy);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn cancel_reactor_when_entity_despawned() {
+        let mut app = test_app();
+        app.attach_reactor::<Enemy, _, _>(|_, task| async move {
+            task.will(Update, wait_forever_and_count_cancel()).await;
+        });
+        let enemy = app.world_mut().spawn(Enemy).id();
+        app.update();
+        app.update();
+
+        app.world_mut().despawn(enemy);
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.world_mut()
+            .run_system_once(|reactors: Query<Entity, With<NativeReactor>>| {
+                assert!(reactors.is_empty());
+            })
+            .expect("Failed to run system");
+    }
+
+    #[test]
+    fn cancel_reactor_when_component_removed() {
+        let mut app = test_app();
+        app.attach_reactor::<Enemy, _, _>(|_, task| async move {
+            task.will(Update, wait_forever_and_count_cancel()).await;
+        });
+        let enemy = app.world_mut().spawn(Enemy).id();
+        app.update();
+        app.update();
+
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands| {
+                commands.entity(enemy).remove::<Enemy>();
+            })
+            .expect("Failed to run system");
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
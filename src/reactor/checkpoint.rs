@@ -0,0 +1,141 @@
+//! Provides [`ReactorCheckpoints`], which records the checkpoints reached by a reactor so that the flow can be resumed after loading a save.
+
+use crate::core::selector::Selector;
+use crate::world_ptr::WorldPtr;
+use bevy::prelude::{Component, Entity, ReflectComponent, ReflectDefault, World};
+use bevy::reflect::Reflect;
+use std::borrow::Cow;
+
+/// The checkpoints reached by the reactor, in the order they were reached.
+///
+/// This component is inserted into the reactor entity when [`ReactorTask::checkpoint`](crate::prelude::ReactorTask::checkpoint) is first awaited.
+/// Since the future of a reactor cannot be serialized, this component is the part of the reactor that can be saved with [`bevy_reflect`](bevy::reflect),
+/// e.g. as a part of a [`DynamicScene`](https://docs.rs/bevy/latest/bevy/scene/struct.DynamicScene.html).
+///
+/// After loading, insert it together with [`Reactor::schedule_resumable`](crate::prelude::Reactor::schedule_resumable)
+/// so that the reactor can fast-forward to the last checkpoint.
+#[derive(Component, Reflect, Debug, Default, Clone, Eq, PartialEq)]
+#[reflect(Component, Default)]
+pub struct ReactorCheckpoints(Vec<String>);
+
+impl ReactorCheckpoints {
+    /// Returns the label of the last reached checkpoint.
+    #[inline]
+    pub fn last(&self) -> Option<&str> {
+        self.0.last().map(String::as_str)
+    }
+
+    /// Returns true if the checkpoint `label` has been reached.
+    #[inline]
+    pub fn reached(&self, label: &str) -> bool {
+        self.0.iter().any(|reached| reached == label)
+    }
+
+    /// Returns the labels of the reached checkpoints in the order they were reached.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item=&str> {
+        self.0.iter().map(String::as_str)
+    }
+}
+
+pub(crate) struct CheckpointSelector {
+    pub(crate) entity: Entity,
+    pub(crate) label: Cow<'static, str>,
+}
+
+impl Selector<WorldPtr> for CheckpointSelector {
+    type Output = ();
+
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        let entity = self.entity;
+        let label = self.label.to_string();
+        // The reactors are being polled now, so the checkpoint is recorded via commands
+        // to avoid moving the storage of the reactors.
+        world.as_mut().commands().queue(move |world: &mut World| {
+            if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                entity_mut
+                    .entry::<ReactorCheckpoints>()
+                    .or_default()
+                    .0
+                    .push(label);
+            }
+        });
+        Some(())
+    }
+}
+
+pub(crate) struct LastCheckpointSelector(pub(crate) Entity);
+
+impl Selector<WorldPtr> for LastCheckpointSelector {
+    type Output = Option<String>;
+
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        Some(world
+            .as_mut()
+            .get::<ReactorCheckpoints>(self.0)
+            .and_then(|checkpoints| checkpoints.last().map(ToString::to_string)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Reactor, ReactorCheckpoints};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, Res, ResMut, Resource};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Resource)]
+    struct Proceed;
+
+    fn spawn_resumable(app: &mut bevy::app::App, checkpoints: Option<ReactorCheckpoints>) {
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let reactor = Reactor::schedule_resumable(|task, checkpoint| async move {
+                if checkpoint.is_none() {
+                    task.will(Update, once::run(|mut count: ResMut<Count>| count.0 += 1)).await;
+                    task.checkpoint("stage2").await;
+                }
+                task.will(Update, wait::until(|proceed: Option<Res<Proceed>>| proceed.is_some())).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| count.0 += 10)).await;
+            });
+            match checkpoints.clone() {
+                Some(checkpoints) => commands.spawn((reactor, checkpoints)),
+                None => commands.spawn(reactor),
+            };
+        });
+    }
+
+    fn checkpoints(app: &mut bevy::app::App) -> ReactorCheckpoints {
+        app.world_mut().query::<&ReactorCheckpoints>().single(app.world()).clone()
+    }
+
+    #[test]
+    fn record_checkpoint() {
+        let mut app = test_app();
+        spawn_resumable(&mut app, None);
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(1));
+        let checkpoints = checkpoints(&mut app);
+        assert_eq!(checkpoints.last(), Some("stage2"));
+        assert!(checkpoints.reached("stage2"));
+    }
+
+    #[test]
+    fn resume_from_last_checkpoint() {
+        let mut app = test_app();
+        let mut saved = ReactorCheckpoints::default();
+        saved.0.push("stage2".to_string());
+        spawn_resumable(&mut app, Some(saved));
+        app.insert_resource(Proceed);
+        for _ in 0..3 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(10));
+    }
+}
@@ -0,0 +1,224 @@
+//! Provides [`ReactorCommandsExtension`], which controls a running [`Reactor`](crate::prelude::Reactor) from ordinary systems.
+
+use crate::prelude::ReactorPaused;
+use crate::reactor::inbox::ReactorInbox;
+use crate::runner::retarget_runners;
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Entity, World};
+
+/// Controls a running [`Reactor`](crate::prelude::Reactor) via [`Commands`].
+///
+/// This is created by [`ReactorCommandsExtension::reactor`].
+/// All commands are ignored if the reactor has already been despawned.
+pub struct ReactorCommands<'w, 's, 'a> {
+    commands: &'a mut Commands<'w, 's>,
+    entity: Entity,
+}
+
+impl ReactorCommands<'_, '_, '_> {
+    /// Returns the entity to which the reactor is attached.
+    #[inline(always)]
+    pub const fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Pauses the reactor by inserting [`ReactorPaused`].
+    #[inline]
+    pub fn pause(&mut self) -> &mut Self {
+        if let Some(mut entity_commands) = self.commands.get_entity(self.entity) {
+            entity_commands.insert(ReactorPaused);
+        }
+        self
+    }
+
+    /// Resumes the reactor paused by [`ReactorCommands::pause`].
+    #[inline]
+    pub fn resume(&mut self) -> &mut Self {
+        if let Some(mut entity_commands) = self.commands.get_entity(self.entity) {
+            entity_commands.remove::<ReactorPaused>();
+        }
+        self
+    }
+
+    /// Cancels the reactor.
+    ///
+    /// The entity and its children are despawned, all pending runners are dropped,
+    /// the cancellation handlers registered via [`CancellationHandlers`](crate::prelude::CancellationHandlers) are called,
+    /// and the cleanup action registered by [`Reactor::finally`](crate::prelude::Reactor::finally) is run.
+    #[inline]
+    pub fn cancel(self) {
+        if let Some(entity_commands) = self.commands.get_entity(self.entity) {
+            entity_commands.despawn_recursive();
+        }
+    }
+
+    /// Moves the pending actions of the reactor from the schedule `from` to the schedule `to`.
+    ///
+    /// This is useful for migrating reactors when the schedules are reconfigured by plugins or mods.
+    /// The moved actions keep their progress and outputs, and are run on the schedule `to` from the next time it runs.
+    ///
+    /// Note that `from` is used only to specify the type of schedule label,
+    /// all pending actions of the reactor whose schedule label has the same type are moved.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// fn migrate(
+    ///     mut commands: Commands,
+    ///     reactors: Query<Entity, With<RunningReactor>>,
+    /// ){
+    ///     for reactor in reactors.iter(){
+    ///         commands.reactor(reactor).retarget_schedule(Update, PostUpdate);
+    ///     }
+    /// }
+    /// ```
+    pub fn retarget_schedule<From, To>(&mut self, _from: From, to: To) -> &mut Self
+    where
+        From: ScheduleLabel,
+        To: ScheduleLabel,
+    {
+        let entity = self.entity;
+        self.commands.queue(move |world: &mut World| {
+            retarget_runners::<From, To>(world, entity, &to);
+        });
+        self
+    }
+
+    /// Sends the message to the reactor.
+    ///
+    /// The messages are queued per type, and received in the order they were sent by [`ReactorTask::recv`](crate::prelude::ReactorTask::recv).
+    #[inline]
+    pub fn send<M>(&mut self, message: M) -> &mut Self
+    where
+        M: Send + Sync + 'static,
+    {
+        let entity = self.entity;
+        self.commands.queue(move |world: &mut World| {
+            let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                return;
+            };
+            entity_mut
+                .entry::<ReactorInbox<M>>()
+                .or_default()
+                .push(message);
+        });
+        self
+    }
+}
+
+/// Adds [`ReactorCommandsExtension::reactor`] to [`Commands`].
+pub trait ReactorCommandsExtension<'w, 's> {
+    /// Returns [`ReactorCommands`] to control the reactor attached to `entity`.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// #[derive(Clone)]
+    /// enum Speed {
+    ///     Up,
+    ///     Down,
+    /// }
+    ///
+    /// fn speed_up(
+    ///     mut commands: Commands,
+    ///     reactors: Query<Entity, With<RunningReactor>>,
+    /// ){
+    ///     for reactor in reactors.iter(){
+    ///         commands.reactor(reactor).send(Speed::Up);
+    ///     }
+    /// }
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     match task.recv::<Speed>().await {
+    ///         Speed::Up => {}
+    ///         Speed::Down => {}
+    ///     }
+    /// });
+    /// ```
+    fn reactor(&mut self, entity: Entity) -> ReactorCommands<'w, 's, '_>;
+}
+
+impl<'w, 's> ReactorCommandsExtension<'w, 's> for Commands<'w, 's> {
+    #[inline]
+    fn reactor(&mut self, entity: Entity) -> ReactorCommands<'w, 's, '_> {
+        ReactorCommands {
+            commands: self,
+            entity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Reactor, ReactorCommandsExtension, ReactorPaused, RunningReactor};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, Entity, In, Query, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    fn control(app: &mut bevy::app::App, f: fn(&mut Commands, Entity)) {
+        app.world_mut()
+            .run_system_once(move |mut commands: Commands, reactor: Query<Entity, With<RunningReactor>>| {
+                f(&mut commands, reactor.single());
+            })
+            .expect("Failed to run system");
+    }
+
+    #[test]
+    fn recv_sent_messages_in_order() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                loop {
+                    let n = task.recv::<usize>().await;
+                    task.will(Update, once::run(|In(n): In<usize>, mut count: ResMut<Count>| {
+                        count.0 = count.0 * 10 + n;
+                    }).with(n)).await;
+                }
+            }));
+        });
+        app.update();
+        control(&mut app, |commands, reactor| {
+            commands.reactor(reactor).send(1_usize).send(2_usize);
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(12));
+    }
+
+    #[test]
+    fn pause_resume_and_cancel() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.recv::<()>().await;
+            }));
+        });
+        app.update();
+        control(&mut app, |commands, reactor| {
+            commands.reactor(reactor).pause();
+        });
+        assert!(app.world_mut().query_filtered::<(), With<ReactorPaused>>().get_single(app.world()).is_ok());
+
+        control(&mut app, |commands, reactor| {
+            commands.reactor(reactor).resume();
+        });
+        assert!(app.world_mut().query_filtered::<(), With<ReactorPaused>>().get_single(app.world()).is_err());
+
+        control(&mut app, |commands, reactor| {
+            commands.reactor(reactor).cancel();
+        });
+        assert!(app.world_mut().query_filtered::<(), With<RunningReactor>>().get_single(app.world()).is_err());
+    }
+}
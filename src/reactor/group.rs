@@ -0,0 +1,158 @@
+//! Provides [`ReactorGroup`], which allows cancelling many reactors at once.
+
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Component, Entity, ReflectComponent, World};
+use bevy::reflect::Reflect;
+use std::borrow::Cow;
+
+/// The group to which the [`Reactor`](crate::prelude::Reactor) attached to the same entity belongs.
+///
+/// This component is inserted by [`Reactor::schedule_in`](crate::prelude::Reactor::schedule_in),
+/// or can be inserted along with [`Reactor`](crate::prelude::Reactor) directly.
+/// All members of the group can be cancelled in one call with [`ReactorGroupExtension::cancel_reactor_group`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn skip_cutscene(mut commands: Commands){
+///     commands.cancel_reactor_group("cutscene");
+/// }
+///
+/// fn play_cutscene(mut commands: Commands){
+///     for _ in 0..10 {
+///         commands.spawn(Reactor::schedule_in("cutscene", |task| async move{
+///             task.will(Update, delay::frames().with(60)).await;
+///         }));
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Eq, PartialEq, Hash)]
+#[reflect(Component)]
+pub struct ReactorGroup(Cow<'static, str>);
+
+impl ReactorGroup {
+    /// Creates a new [`ReactorGroup`].
+    #[inline]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self(name.into())
+    }
+
+    /// Returns the name of the group.
+    #[inline(always)]
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for ReactorGroup {
+    #[inline]
+    fn from(name: &'static str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<String> for ReactorGroup {
+    #[inline]
+    fn from(name: String) -> Self {
+        Self::new(name)
+    }
+}
+
+/// Adds [`ReactorGroupExtension::cancel_reactor_group`] to [`World`] and [`Commands`].
+pub trait ReactorGroupExtension {
+    /// Cancels every reactor that belongs to the `group`.
+    ///
+    /// The reactor entities and their children are despawned, all pending runners are dropped,
+    /// and the cancellation handlers are called.
+    fn cancel_reactor_group(&mut self, group: impl Into<ReactorGroup>);
+}
+
+impl ReactorGroupExtension for World {
+    fn cancel_reactor_group(&mut self, group: impl Into<ReactorGroup>) {
+        let group = group.into();
+        let members = self
+            .query::<(Entity, &ReactorGroup)>()
+            .iter(self)
+            .filter(|(_, g)| **g == group)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+        for entity in members {
+            if let Ok(entity_mut) = self.get_entity_mut(entity) {
+                entity_mut.despawn_recursive();
+            }
+        }
+    }
+}
+
+impl ReactorGroupExtension for Commands<'_, '_> {
+    #[inline]
+    fn cancel_reactor_group(&mut self, group: impl Into<ReactorGroup>) {
+        let group = group.into();
+        self.queue(move |world: &mut World| {
+            world.cancel_reactor_group(group);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, ReactorGroup, ReactorGroupExtension};
+    use crate::reactor::NativeReactor;
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::Commands;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn cancel_all_members() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            for _ in 0..3 {
+                commands.spawn(Reactor::schedule_in("cutscene", |task| async move {
+                    task.will(Update, wait::until(|| false)).await;
+                }));
+            }
+            commands.spawn((
+                ReactorGroup::new("cutscene"),
+                Reactor::schedule(|task| async move {
+                    task.will(Update, wait::until(|| false)).await;
+                }),
+            ));
+            commands.spawn(Reactor::schedule_in("hud", |task| async move {
+                task.will(Update, wait::until(|| false)).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(world.query::<&NativeReactor>().iter(world).len(), 5);
+
+        world.cancel_reactor_group("cutscene");
+        assert_eq!(world.query::<&NativeReactor>().iter(world).len(), 1);
+        assert_eq!(world.query::<&ReactorGroup>().single(world).name(), "hud");
+    }
+
+    #[test]
+    fn cancel_via_commands() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule_in("cutscene", |task| async move {
+                task.will(Update, wait::until(|| false)).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+        app.update();
+        app.add_systems(Update, |mut commands: Commands| {
+            commands.cancel_reactor_group("cutscene");
+        });
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(world.query::<&ReactorGroup>().iter(world).len(), 0);
+        app.assert_resource_eq(Count(0));
+    }
+}
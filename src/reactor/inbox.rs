@@ -0,0 +1,148 @@
+//! Provides [`ReactorInbox`], the mailbox to send messages into a running reactor.
+
+use crate::core::selector::Selector;
+use crate::world_ptr::WorldPtr;
+use bevy::prelude::{Component, Entity};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+/// The per-reactor mailbox of the messages `M`, which are received by [`ReactorTask::recv`](crate::prelude::ReactorTask::recv).
+///
+/// This component is inserted into the reactor entity when [`ReactorTask::recv`](crate::prelude::ReactorTask::recv) is first awaited
+/// or a message is sent by [`ReactorCommands::send`](crate::prelude::ReactorCommands::send).
+/// It can also be inserted together with [`Reactor`](crate::prelude::Reactor), so that the other systems can push the messages from the beginning.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// enum Command {
+///     Abort,
+///     SpeedUp,
+/// }
+///
+/// fn spawn(mut commands: Commands){
+///     commands.spawn((
+///         Reactor::schedule(|task| async move{
+///             loop {
+///                 match task.recv::<Command>().await {
+///                     Command::Abort => break,
+///                     Command::SpeedUp => {}
+///                 }
+///             }
+///         }),
+///         ReactorInbox::<Command>::default(),
+///     ));
+/// }
+///
+/// fn abort(mut inboxes: Query<&mut ReactorInbox<Command>>){
+///     for mut inbox in inboxes.iter_mut(){
+///         inbox.push(Command::Abort);
+///     }
+/// }
+/// ```
+#[derive(Component)]
+pub struct ReactorInbox<M: Send + Sync + 'static>(VecDeque<M>);
+
+impl<M: Send + Sync + 'static> ReactorInbox<M> {
+    /// Pushes the message to the back of the inbox.
+    ///
+    /// The messages are received in the order they were pushed.
+    #[inline]
+    pub fn push(&mut self, message: M) {
+        self.0.push_back(message);
+    }
+
+    /// Returns the number of the messages that have not been received yet.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether all messages have been received.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Discards all messages that have not been received yet.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl<M: Send + Sync + 'static> Default for ReactorInbox<M> {
+    #[inline]
+    fn default() -> Self {
+        Self(VecDeque::new())
+    }
+}
+
+pub(crate) struct RecvSelector<M> {
+    pub(crate) entity: Entity,
+    pub(crate) _m: PhantomData<fn() -> M>,
+}
+
+impl<M> Selector<WorldPtr> for RecvSelector<M>
+where
+    M: Send + Sync + 'static,
+{
+    type Output = M;
+
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        let world = world.as_mut();
+        if let Some(mut inbox) = world.get_mut::<ReactorInbox<M>>(self.entity) {
+            inbox.0.pop_front()
+        } else {
+            // The reactors are being polled now, so the inbox is inserted via commands
+            // to avoid moving the storage of the reactors.
+            if let Some(mut entity_commands) = world.commands().get_entity(self.entity) {
+                entity_commands.insert(ReactorInbox::<M>::default());
+            }
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{Reactor, ReactorInbox};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, In, Query, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn recv_pushed_messages() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                loop {
+                    let n = task.recv::<usize>().await;
+                    task.will(Update, once::run(|In(n): In<usize>, mut count: ResMut<Count>| {
+                        count.0 += n;
+                    }).with(n)).await;
+                }
+            }));
+        });
+        app.update();
+        app.world_mut()
+            .run_system_once(|mut inboxes: Query<&mut ReactorInbox<usize>>| {
+                let mut inbox = inboxes.single_mut();
+                inbox.push(1);
+                inbox.push(2);
+            })
+            .expect("Failed to run system");
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(3));
+    }
+}
@@ -0,0 +1,75 @@
+//! Passes the outputs of finished reactors to [`wait::reactor::finished`](crate::prelude::wait::reactor::finished).
+
+use crate::core::selector::Selector;
+use crate::world_ptr::WorldPtr;
+use bevy::prelude::{Entity, Resource};
+use bevy::utils::synccell::SyncCell;
+use bevy::utils::HashMap;
+use std::any::Any;
+use std::sync::{Arc, Weak};
+
+/// The outputs of the finished reactors, keyed by their entities.
+///
+/// An output is kept only if a waiter has registered for the reactor before it finished,
+/// and the waiter is still alive, so the outputs nobody awaits are dropped as soon as the reactors finish.
+#[derive(Resource, Default)]
+pub(crate) struct ReactorOutputs {
+    waiters: HashMap<Entity, Vec<Weak<()>>>,
+    outputs: HashMap<Entity, SyncCell<Box<dyn Any + Send>>>,
+}
+
+impl ReactorOutputs {
+    /// Registers the waiter for the output of the reactor.
+    ///
+    /// The registration lasts until `waiter` is dropped.
+    pub(crate) fn register(&mut self, entity: Entity, waiter: &Arc<()>) {
+        // Forget the waiters canceled before the reactors finished.
+        self.waiters.retain(|_, waiters| {
+            waiters.retain(|waiter| waiter.strong_count() != 0);
+            !waiters.is_empty()
+        });
+        self.waiters.entry(entity).or_default().push(Arc::downgrade(waiter));
+    }
+
+    /// Takes the output of the reactor if it is `O`.
+    pub(crate) fn take<O: 'static>(&mut self, entity: Entity) -> Option<O> {
+        self.waiters.remove(&entity);
+        let output = SyncCell::to_inner(self.outputs.remove(&entity)?);
+        output.downcast::<O>().ok().map(|output| *output)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.waiters.is_empty() && self.outputs.is_empty()
+    }
+
+    fn store(&mut self, entity: Entity, output: Box<dyn Any + Send>) {
+        let Some(waiters) = self.waiters.remove(&entity) else {
+            return;
+        };
+        if waiters.iter().any(|waiter| waiter.strong_count() != 0) {
+            self.outputs.insert(entity, SyncCell::new(output));
+        }
+    }
+}
+
+pub(crate) struct StoreOutputSelector<O> {
+    pub(crate) entity: Entity,
+    pub(crate) output: SyncCell<Option<O>>,
+}
+
+impl<O> Selector<WorldPtr> for StoreOutputSelector<O>
+where
+    O: Send + 'static,
+{
+    type Output = ();
+
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        let output = self.output.get().take()?;
+        if let Some(mut outputs) = world.as_mut().get_resource_mut::<ReactorOutputs>() {
+            outputs.store(self.entity, Box::new(output));
+        }
+        Some(())
+    }
+}
@@ -0,0 +1,95 @@
+//! Provides [`ReactorParams`], which holds the parameters of the reactor created by [`Reactor::schedule_with`](crate::prelude::Reactor::schedule_with).
+
+use crate::core::selector::Selector;
+use crate::world_ptr::WorldPtr;
+use bevy::prelude::{Component, Entity, ReflectComponent};
+use bevy::reflect::Reflect;
+use std::marker::PhantomData;
+
+/// The parameters of the reactor attached to the same entity.
+///
+/// This component is inserted by [`Reactor::schedule_with`](crate::prelude::Reactor::schedule_with),
+/// so unlike the values captured by the closure, the parameters are visible to reflection-based inspectors
+/// and can be saved with [`bevy_reflect`](bevy::reflect).
+/// Register `ReactorParams<P>` with [`App::register_type`](bevy::app::App::register_type) to make it reflectable.
+///
+/// If this component is inserted together with the reactor, e.g. after loading a save,
+/// the reactor starts with it instead of the parameters passed to [`Reactor::schedule_with`](crate::prelude::Reactor::schedule_with).
+#[derive(Component, Reflect, Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[reflect(Component)]
+pub struct ReactorParams<P: Send + Sync + 'static>(pub P);
+
+pub(crate) struct ParamsSelector<P> {
+    pub(crate) entity: Entity,
+    pub(crate) _m: PhantomData<P>,
+}
+
+impl<P> Selector<WorldPtr> for ParamsSelector<P>
+where
+    P: Clone + Send + Sync + 'static,
+{
+    type Output = Option<P>;
+
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        Some(world
+            .as_mut()
+            .get::<ReactorParams<P>>(self.entity)
+            .map(|params| params.0.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Reactor, ReactorParams};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{AppTypeRegistry, Commands, In, ResMut};
+    use bevy::reflect::Reflect;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(Reflect, Debug, Clone, Eq, PartialEq)]
+    struct Quest {
+        reward: usize,
+    }
+
+    fn spawn_quest(app: &mut bevy::app::App, loaded: Option<Quest>) {
+        app.add_systems(Startup, move |mut commands: Commands| {
+            let reactor = Reactor::schedule_with(Quest { reward: 3 }, |task, quest| async move {
+                task.will(Update, once::run(|In(reward): In<usize>, mut count: ResMut<Count>| {
+                    count.0 += reward;
+                }).with(quest.reward)).await;
+                task.will(Update, wait::until(|| false)).await;
+            });
+            match loaded.clone() {
+                Some(quest) => commands.spawn((reactor, ReactorParams(quest))),
+                None => commands.spawn(reactor),
+            };
+        });
+    }
+
+    #[test]
+    fn insert_params_into_reactor_entity() {
+        let mut app = test_app();
+        app.register_type::<ReactorParams<Quest>>();
+        spawn_quest(&mut app, None);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(3));
+        let params = app.world_mut().query::<&ReactorParams<Quest>>().single(app.world()).clone();
+        assert_eq!(params, ReactorParams(Quest { reward: 3 }));
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        assert!(registry.get(std::any::TypeId::of::<ReactorParams<Quest>>()).is_some());
+    }
+
+    #[test]
+    fn start_with_inserted_params() {
+        let mut app = test_app();
+        spawn_quest(&mut app, Some(Quest { reward: 7 }));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(7));
+    }
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::{Component, Entity, Mut, World};
+
+/// Lets the reactor sleep while its awaited actions are running, instead of being polled every frame.
+///
+/// This is inserted by [`Reactor::with_elastic_polling`](crate::prelude::Reactor::with_elastic_polling).
+/// The reactor is woken when any of its runners has completed or been canceled.
+/// The runners themselves are run every frame regardless of this.
+#[derive(Component, Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ElasticPolling {
+    pub(crate) awake: bool,
+}
+
+impl ElasticPolling {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            awake: true,
+        }
+    }
+}
+
+/// Returns whether the reactor needs to be polled.
+///
+/// The reactors without [`ElasticPolling`] are always awake.
+#[inline]
+pub(crate) fn is_awake(elastic: &Option<Mut<ElasticPolling>>) -> bool {
+    elastic.as_ref().is_none_or(|elastic| elastic.awake)
+}
+
+/// Puts the reactor to sleep after it has been polled.
+#[inline]
+pub(crate) fn sleep(elastic: &mut Option<Mut<ElasticPolling>>) {
+    if let Some(elastic) = elastic.as_mut() {
+        elastic.awake = false;
+    }
+}
+
+/// Wakes the reactor so that it is polled in the next [`run_reactors`](crate::run_reactors).
+#[inline]
+pub(crate) fn wake(world: &mut World, entity: Entity) {
+    if let Some(mut elastic) = world.get_mut::<ElasticPolling>(entity) {
+        elastic.awake = true;
+    }
+}
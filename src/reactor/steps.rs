@@ -0,0 +1,41 @@
+use bevy::prelude::{Component, Mut, Query};
+
+/// Limits how many times the reactor attached to the same entity is polled per frame.
+///
+/// This is inserted by [`Reactor::with_max_steps_per_frame`](crate::prelude::Reactor::with_max_steps_per_frame).
+#[derive(Component, Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct MaxStepsPerFrame {
+    pub(crate) max: usize,
+    pub(crate) steps: usize,
+}
+
+impl MaxStepsPerFrame {
+    #[inline]
+    pub(crate) const fn new(max: usize) -> Self {
+        Self {
+            max,
+            steps: 0,
+        }
+    }
+}
+
+/// Consumes a step of the reactor, and returns whether the reactor can be polled in this frame.
+///
+/// The reactors without [`MaxStepsPerFrame`] can always be polled.
+#[inline]
+pub(crate) fn consume_step(steps: &mut Option<Mut<MaxStepsPerFrame>>) -> bool {
+    let Some(steps) = steps.as_mut() else {
+        return true;
+    };
+    if steps.max <= steps.steps {
+        return false;
+    }
+    steps.steps += 1;
+    true
+}
+
+pub(crate) fn reset_steps(mut reactors: Query<&mut MaxStepsPerFrame>) {
+    for mut steps in reactors.iter_mut() {
+        steps.steps = 0;
+    }
+}
@@ -0,0 +1,116 @@
+//! Provides [`SubReactorHandle`], the handle to the child reactor forked by [`ReactorTask::spawn`](crate::prelude::ReactorTask::spawn).
+
+use crate::core::selector::Selector;
+use crate::prelude::{Reactor, ReactorTask};
+use crate::world_ptr::WorldPtr;
+use bevy::hierarchy::BuildChildren;
+use bevy::prelude::Entity;
+use std::future::Future;
+
+/// The handle to the child reactor forked by [`ReactorTask::spawn`](crate::prelude::ReactorTask::spawn).
+///
+/// The completion of the child reactor can be awaited with [`wait::sub_reactor`](crate::prelude::wait::sub_reactor).
+/// Like the other reactors, the child reactor is controlled via [`ReactorCommandsExtension::reactor`](crate::prelude::ReactorCommandsExtension::reactor),
+/// e.g. `commands.reactor(handle.entity()).cancel()`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct SubReactorHandle(pub(crate) Entity);
+
+impl SubReactorHandle {
+    /// Returns the entity to which the child reactor is attached.
+    #[inline(always)]
+    pub const fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+pub(crate) struct SpawnSelector<F> {
+    pub(crate) parent: Entity,
+    pub(crate) f: Option<F>,
+}
+
+impl<F, Fut> Selector<WorldPtr> for SpawnSelector<F>
+where
+    F: FnOnce(ReactorTask) -> Fut + Send + Sync + 'static,
+    Fut: Future + Send + Sync + 'static,
+    Fut::Output: Send + 'static,
+{
+    type Output = SubReactorHandle;
+
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        let f = self.f.take()?;
+        // The reactors are being polled now, so the child is spawned via commands
+        // to avoid moving the storage of the reactors.
+        let child = world
+            .as_mut()
+            .commands()
+            .spawn(Reactor::schedule(f))
+            .set_parent(self.parent)
+            .id();
+        Some(SubReactorHandle(child))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{Reactor, ReactorCommandsExtension, RunningReactor};
+    use crate::reactor::NativeReactor;
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::{Commands, Entity, Query, ResMut, With};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn await_sub_reactor() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let child = task.spawn(|task| async move {
+                    task.will(Update, increment_count()).await;
+                    task.will(Update, increment_count()).await;
+                }).await;
+                task.will(Update, wait::sub_reactor().with(child)).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    assert_eq!(count.0, 2);
+                    count.0 += 10;
+                })).await;
+            }));
+        });
+        for _ in 0..6 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(12));
+        let world = app.world_mut();
+        assert_eq!(world.query::<&NativeReactor>().iter(world).len(), 0);
+    }
+
+    #[test]
+    fn cancel_sub_reactor_with_parent() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let child = task.spawn(|task| async move {
+                    task.will(Update, wait::until(|| false)).await;
+                }).await;
+                task.will(Update, wait::sub_reactor().with(child)).await;
+            }));
+        });
+        app.update();
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(world.query::<&NativeReactor>().iter(world).len(), 2);
+
+        world
+            .run_system_once(|mut commands: Commands, reactors: Query<Entity, With<RunningReactor>>| {
+                for reactor in reactors.iter() {
+                    commands.reactor(reactor).cancel();
+                }
+            })
+            .expect("Failed to run system");
+        let world = app.world_mut();
+        assert_eq!(world.query::<&NativeReactor>().iter(world).len(), 0);
+    }
+}
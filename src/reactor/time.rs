@@ -0,0 +1,108 @@
+//! Provides [`ReactorTime`], which scales the time elapsed in a single reactor.
+
+use crate::runner::{CurrentReactor, InRealTime};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{Component, Query, Real, Reflect, ReflectComponent, Res, Time};
+use std::time::Duration;
+
+/// The multiplier of the time elapsed in the reactor, inserted into the reactor entity.
+///
+/// This affects only the time-based actions run by the reactor,
+/// such as [`delay::time`](crate::prelude::delay::time) and [`tween::component`](crate::prelude::tween::component),
+/// so individual cutscenes can be fast-forwarded or slowed down for debugging without touching the global [`Time`].
+/// The multiplier can be changed while the reactor is running.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// fn spawn(mut commands: Commands){
+///     commands.spawn((
+///         Reactor::schedule(|task| async move{
+///             task.will(Update, delay::time().with(Duration::from_secs(10))).await;
+///         }),
+///         ReactorTime(4.),
+///     ));
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Copy, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct ReactorTime(pub f32);
+
+impl Default for ReactorTime {
+    #[inline]
+    fn default() -> Self {
+        Self(1.)
+    }
+}
+
+/// Reads the delta time scaled by [`ReactorTime`] of the reactor whose runners are currently running.
+///
+/// The delta time is read from [`Time<Real>`] while [`InRealTime`] exists, and from [`Time`] otherwise.
+#[derive(SystemParam)]
+pub(crate) struct ReactorDelta<'w, 's> {
+    time: Res<'w, Time>,
+    real: Option<Res<'w, Time<Real>>>,
+    in_real_time: Option<Res<'w, InRealTime>>,
+    reactor: Option<Res<'w, CurrentReactor>>,
+    scales: Query<'w, 's, &'static ReactorTime>,
+}
+
+impl ReactorDelta<'_, '_> {
+    /// Returns the delta time of this frame scaled by [`ReactorTime`].
+    pub(crate) fn delta(&self) -> Duration {
+        let delta = match self.real.as_ref().filter(|_| self.in_real_time.is_some()) {
+            Some(real) => real.delta(),
+            None => self.time.delta(),
+        };
+        delta.mul_f32(self.scale())
+    }
+
+    /// Returns the scale of [`ReactorTime`], or `1.0` if it does not exist.
+    pub(crate) fn scale(&self) -> f32 {
+        self
+            .reactor
+            .as_ref()
+            .and_then(|reactor| self.scales.get(reactor.0).ok())
+            .map(|scale| scale.0.max(0.))
+            .unwrap_or(1.)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{delay, once};
+    use crate::prelude::{Reactor, ReactorTime, Then};
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+    use std::time::Duration;
+
+    #[test]
+    fn fast_forward_delay() {
+        let mut app = test_app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn((
+                Reactor::schedule(|task| async move {
+                    task.will(Update, delay::time().with(Duration::from_millis(800))
+                        .then(once::run(|mut count: ResMut<Count>| {
+                            count.increment();
+                        }))).await;
+                }),
+                ReactorTime(4.),
+            ));
+        });
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+}
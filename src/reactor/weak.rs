@@ -0,0 +1,202 @@
+//! Provides [`WeakReactorHandle`], the handle that outside code can use to observe and cancel a [`Reactor`](crate::prelude::Reactor)
+//! without holding its [`Entity`] or touching the [`World`](bevy::prelude::World).
+
+use crate::reactor::NativeReactor;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::world::DeferredWorld;
+use bevy::hierarchy::DespawnRecursiveExt;
+use bevy::prelude::{Commands, Component, Entity, Query};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// The status of the [`Reactor`](crate::prelude::Reactor) observed via [`WeakReactorHandle`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ReactorStatus {
+    /// The reactor has not finished yet.
+    Running,
+    /// The reactor has completed all its processing.
+    Completed,
+    /// The reactor has been canceled, or its entity has been despawned before completion.
+    Cancelled,
+}
+
+impl ReactorStatus {
+    /// Returns true if the reactor has finished, whether completed or canceled.
+    #[inline(always)]
+    pub const fn is_finished(&self) -> bool {
+        !matches!(self, ReactorStatus::Running)
+    }
+}
+
+#[derive(Debug)]
+struct Shared {
+    status: Mutex<ReactorStatus>,
+    wakers: Mutex<Vec<Waker>>,
+    cancel_requested: AtomicBool,
+}
+
+/// The cloneable, weak handle to a [`Reactor`](crate::prelude::Reactor) created by [`Reactor::schedule_with_handle`](crate::prelude::Reactor::schedule_with_handle).
+///
+/// Unlike [`ReactorCommands`](crate::prelude::ReactorCommands), this does not require [`Commands`] or the [`World`](bevy::prelude::World),
+/// so it can be moved into other threads or async runtimes to await the completion, query the status, or cancel the reactor.
+/// The handle does not keep the reactor alive.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let (reactor, handle) = Reactor::schedule_with_handle(|task| async move{
+///     task.will(Update, delay::frames().with(60)).await;
+/// });
+/// std::thread::spawn(move ||{
+///     let status = pollster::block_on(handle.completion());
+///     println!("{status:?}");
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeakReactorHandle(Arc<Shared>);
+
+impl WeakReactorHandle {
+    pub(crate) fn new() -> Self {
+        Self(Arc::new(Shared {
+            status: Mutex::new(ReactorStatus::Running),
+            wakers: Mutex::new(Vec::new()),
+            cancel_requested: AtomicBool::new(false),
+        }))
+    }
+
+    /// Returns the current status of the reactor.
+    #[inline]
+    pub fn status(&self) -> ReactorStatus {
+        *self.0.status.lock().expect("Failed to lock the reactor status")
+    }
+
+    /// Requests to cancel the reactor.
+    ///
+    /// The reactor is canceled the next time [`FlurxPlugin`](crate::prelude::FlurxPlugin) polls reactors.
+    /// This has no effect if the reactor has already finished.
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.cancel_requested.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns the future that resolves with the final status when the reactor has finished.
+    #[inline]
+    pub fn completion(&self) -> ReactorCompletion {
+        ReactorCompletion(self.clone())
+    }
+
+    pub(crate) fn link(&self) -> ReactorLink {
+        ReactorLink(self.clone())
+    }
+
+    fn finish(&self, status: ReactorStatus) {
+        *self.0.status.lock().expect("Failed to lock the reactor status") = status;
+        for waker in self.0.wakers.lock().expect("Failed to lock the wakers").drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+/// The future returned by [`WeakReactorHandle::completion`].
+#[derive(Debug)]
+pub struct ReactorCompletion(WeakReactorHandle);
+
+impl Future for ReactorCompletion {
+    type Output = ReactorStatus;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let shared = &self.0.0;
+        let mut wakers = shared.wakers.lock().expect("Failed to lock the wakers");
+        let status = *shared.status.lock().expect("Failed to lock the reactor status");
+        if status.is_finished() {
+            Poll::Ready(status)
+        } else {
+            wakers.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Links [`WeakReactorHandle`] to the reactor entity, and reports the final status when it is removed.
+#[derive(Component)]
+#[component(on_remove = report_status)]
+pub(crate) struct ReactorLink(WeakReactorHandle);
+
+fn report_status(world: DeferredWorld, entity: Entity, _: ComponentId) {
+    let status = if world
+        .get::<NativeReactor>(entity)
+        .is_some_and(|reactor| reactor.scheduler.finished) {
+        ReactorStatus::Completed
+    } else {
+        ReactorStatus::Cancelled
+    };
+    if let Some(link) = world.get::<ReactorLink>(entity) {
+        link.0.finish(status);
+    }
+}
+
+pub(crate) fn cancel_requested_reactors(
+    mut commands: Commands,
+    links: Query<(Entity, &ReactorLink)>,
+) {
+    for (entity, link) in links.iter() {
+        if link.0.0.cancel_requested.load(Ordering::Relaxed) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::wait;
+    use crate::prelude::{Reactor, ReactorStatus, WeakReactorHandle};
+    use crate::tests::test_app;
+    use bevy::app::Update;
+    use bevy::prelude::{Res, Resource};
+
+    #[derive(Resource)]
+    struct Finish;
+
+    fn spawn_reactor(app: &mut bevy::app::App) -> WeakReactorHandle {
+        let (reactor, handle) = Reactor::schedule_with_handle(|task| async move {
+            task.will(Update, wait::until(|finish: Option<Res<Finish>>| finish.is_some())).await;
+        });
+        app.world_mut().spawn(reactor);
+        handle
+    }
+
+    #[test]
+    fn completed() {
+        let mut app = test_app();
+        let handle = spawn_reactor(&mut app);
+        app.update();
+        assert_eq!(handle.status(), ReactorStatus::Running);
+
+        app.world_mut().insert_resource(Finish);
+        app.update();
+        assert_eq!(handle.status(), ReactorStatus::Completed);
+        assert_eq!(pollster::block_on(handle.completion()), ReactorStatus::Completed);
+    }
+
+    #[test]
+    fn cancel_without_world() {
+        let mut app = test_app();
+        let handle = spawn_reactor(&mut app);
+        app.update();
+        let completion = std::thread::spawn({
+            let handle = handle.clone();
+            move || pollster::block_on(handle.completion())
+        });
+
+        handle.cancel();
+        app.update();
+        assert_eq!(handle.status(), ReactorStatus::Cancelled);
+        assert_eq!(completion.join().unwrap(), ReactorStatus::Cancelled);
+    }
+}
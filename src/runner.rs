@@ -1,15 +1,28 @@
 //! `Runner` defines what does the actual processing of the action.
 
-use crate::reactor::NativeReactor;
+#[cfg(feature = "gc")]
+use crate::gc::register_sweeper;
+use crate::reactor::sleep::wake;
+use crate::reactor::{NativeReactor, ReactorPaused};
 pub use crate::runner::cancellation_handlers::{CancellationHandlers, CancellationId};
-use bevy::ecs::schedule::ScheduleLabel;
-use bevy::prelude::{Component, Entity, EventWriter, NonSendMut, Observer, OnRemove, Reflect, ReflectComponent, Schedules, Trigger, With, World};
+use bevy::app::{FixedFirst, FixedLast, FixedPostUpdate, FixedPreUpdate, FixedUpdate};
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::query::Access;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::{Component, Entity, EventWriter, IntoSystemConfigs, NonSendMut, Observer, OnRemove, Reflect, ReflectComponent, Resource, Schedules, System, SystemSet, Time, Trigger, Virtual, With, World};
 pub(crate) use cancellation_handlers::CallCancellationHandlers;
 pub use output::Output;
+pub use progress::{Progress, ProgressReceiver, ReactorProgress};
+use std::any::TypeId;
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 mod output;
+pub(crate) mod progress;
 mod cancellation_handlers;
+pub(crate) mod trace;
 
 
 /// The current state of the [Runner].
@@ -47,39 +60,169 @@ pub trait Runner {
     /// If this runner finishes, it must return `true`.
     /// If it returns `true`, an entity attached this runner will be removed.
     fn run(&mut self, world: &mut World, cancellation_handlers: &mut CancellationHandlers) -> RunnerIs;
+
+    /// Returns the components and resources this runner accesses, or `None` if unknown.
+    ///
+    /// This is only metadata, and does not restrict what the runner actually accesses.
+    /// It is used to detect the actions that run concurrently, such as in [`wait::all`](crate::prelude::wait::all),
+    /// and conflict with each other, since their results depend on the order in which they run.
+    ///
+    /// The access of the runners that run systems is known after they have run at least once.
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        None
+    }
+}
+
+/// Returns the access of the system, regarding the exclusive systems as writing all data.
+pub(crate) fn system_access<Sys: System>(system: &Sys) -> Access<ComponentId> {
+    let mut access = system.component_access().clone();
+    if system.is_exclusive() {
+        access.write_all();
+    }
+    access
 }
 
 /// The boxed runner.
 ///
 /// It is created by [`Action`](crate::prelude::Action).
-#[repr(transparent)]
-pub struct BoxedRunner(Option<Box<dyn Runner>>);
+pub struct BoxedRunner {
+    runner: Option<Box<dyn Runner>>,
+    /// The access of the runner, kept after the runner has been dropped on completion.
+    access: Option<Access<ComponentId>>,
+    run_even_if_paused: bool,
+    settle: Option<Settle>,
+    /// The type name of the runner.
+    type_name: &'static str,
+    /// Whether this runner runs an action, rather than wrapping another runner such as those of the middlewares.
+    traced: bool,
+    /// The name given by [`ActionSeed::named`](crate::prelude::ActionSeed::named).
+    name: Option<Cow<'static, str>>,
+}
+
+/// Shared by the two runners of [`ReactorTask::will_either`](crate::prelude::ReactorTask::will_either),
+/// so that the losing runner is dropped in the same frame the winner completes.
+struct Settle {
+    settled: Arc<AtomicBool>,
+    /// The ids of the cancellation handlers registered by the runner.
+    ids: Vec<CancellationId>,
+    /// Drops the losing runners from the reactor map of the other schedule.
+    drop_losers: fn(&mut World, Entity),
+}
 
 impl BoxedRunner {
     #[inline]
-    pub(crate) fn new(runner: impl Runner + 'static) -> Self {
-        Self(Some(Box::new(runner)))
+    pub(crate) fn new<R: Runner + 'static>(runner: R) -> Self {
+        Self {
+            runner: Some(Box::new(runner)),
+            access: None,
+            run_even_if_paused: false,
+            settle: None,
+            type_name: std::any::type_name::<R>(),
+            traced: false,
+            name: None,
+        }
+    }
+
+    /// Marks this runner as the one of the action, so that it is recorded as a node of [`NestedAction`](crate::diagnostics::NestedAction).
+    #[inline]
+    pub(crate) fn traced(mut self, name: Option<Cow<'static, str>>) -> Self {
+        self.traced = true;
+        if name.is_some() {
+            self.name = name;
+        }
+        self
+    }
+
+    /// Makes this runner run even while its reactor is paused.
+    #[inline]
+    pub(crate) fn run_even_if_paused(mut self, run_even_if_paused: bool) -> Self {
+        self.run_even_if_paused = run_even_if_paused;
+        self
+    }
+
+    /// Makes this runner settle `settled` when it completes.
+    ///
+    /// Once `settled` is set, this runner no longer runs and is dropped by `drop_losers` of the winner.
+    #[inline]
+    pub(crate) fn settle(mut self, settled: Arc<AtomicBool>, drop_losers: fn(&mut World, Entity)) -> Self {
+        self.settle.replace(Settle {
+            settled,
+            ids: Vec::new(),
+            drop_losers,
+        });
+        self
+    }
+
+    /// Runs the runner, and returns its access along with the status.
+    ///
+    /// The access is available even if the runner has been completed in this run.
+    pub(crate) fn run_with_access(&mut self, world: &mut World, cancellation_handlers: &mut CancellationHandlers) -> (RunnerIs, Option<Access<ComponentId>>) {
+        let status = self.run(world, cancellation_handlers);
+        (status, self.access())
     }
 }
 
 impl Runner for BoxedRunner {
     #[inline(always)]
     fn run(&mut self, world: &mut World, cancellation_handlers: &mut CancellationHandlers) -> RunnerIs {
-        if let Some(mut runner) = self.0.take() {
-            match runner.run(world, cancellation_handlers) {
-                RunnerIs::Completed => RunnerIs::Completed,
-                RunnerIs::Canceled => RunnerIs::Canceled,
-                status => {
-                    self.0.replace(runner);
-                    status
-                }
-            }
-        } else {
-            RunnerIs::Completed
+        let Self { runner, access, settle, type_name, traced, name, .. } = self;
+        if !*traced {
+            return run_settled(runner, access, settle, world, cancellation_handlers);
+        }
+        trace::enter(
+            || name.as_deref().unwrap_or_else(|| trace::short_type_name(type_name)).to_string(),
+            || run_settled(runner, access, settle, world, cancellation_handlers),
+        )
+    }
+
+    #[inline]
+    fn access(&self) -> Option<Access<ComponentId>> {
+        match self.runner.as_ref() {
+            Some(runner) => runner.access(),
+            None => self.access.clone(),
         }
     }
 }
 
+fn run_settled(
+    runner: &mut Option<Box<dyn Runner>>,
+    access: &mut Option<Access<ComponentId>>,
+    settle: &mut Option<Settle>,
+    world: &mut World,
+    cancellation_handlers: &mut CancellationHandlers,
+) -> RunnerIs {
+    let Some(settle) = settle else {
+        return run_boxed(runner, access, world, cancellation_handlers);
+    };
+    if settle.settled.load(Ordering::Relaxed) {
+        return RunnerIs::Running;
+    }
+    let status = cancellation_handlers.record(&mut settle.ids, |token| run_boxed(runner, access, world, token));
+    if status.is_completed() {
+        settle.settled.store(true, Ordering::Relaxed);
+    }
+    status
+}
+
+fn run_boxed(
+    runner: &mut Option<Box<dyn Runner>>,
+    access: &mut Option<Access<ComponentId>>,
+    world: &mut World,
+    cancellation_handlers: &mut CancellationHandlers,
+) -> RunnerIs {
+    let Some(mut inner) = runner.take() else {
+        return RunnerIs::Completed;
+    };
+    let status = inner.run(world, cancellation_handlers);
+    if matches!(status, RunnerIs::Running) {
+        runner.replace(inner);
+    } else {
+        *access = inner.access();
+    }
+    status
+}
+
 #[repr(transparent)]
 struct ReactorMap<L: Send + Sync>(Vec<(Entity, Vec<BoxedRunner>, CancellationHandlers)>, PhantomData<L>);
 
@@ -97,6 +240,45 @@ struct ReactorEntity(Entity);
 #[reflect(Component)]
 struct ReactorScheduleLabel<Label: ScheduleLabel>(PhantomData<Label>);
 
+/// The entity of the reactor whose runners are currently running.
+///
+/// This exists only while the runners are running, so the actions can access their reactor entity.
+#[derive(Resource, Debug, Copy, Clone)]
+pub(crate) struct CurrentReactor(pub(crate) Entity);
+
+/// Marks that the runner created by [`ActionSeed::run_even_if_paused`](crate::prelude::ActionSeed::run_even_if_paused) is running
+/// while [`Time<Virtual>`] is paused.
+///
+/// This exists only while the runner is running, like [`CurrentReactor`].
+#[derive(Resource, Debug, Copy, Clone)]
+pub(crate) struct InRealTime;
+
+/// The reactors whose actions on the fixed schedules have been completed in the current fixed tick.
+#[derive(Resource, Default)]
+pub(crate) struct CompletedInFixedTick(pub(crate) Vec<Entity>);
+
+/// Returns whether `L` is one of the schedules run by [`FixedMain`](bevy::app::FixedMain).
+fn is_fixed_schedule<L: 'static>() -> bool {
+    [
+        TypeId::of::<FixedFirst>(),
+        TypeId::of::<FixedPreUpdate>(),
+        TypeId::of::<FixedUpdate>(),
+        TypeId::of::<FixedPostUpdate>(),
+        TypeId::of::<FixedLast>(),
+    ].contains(&TypeId::of::<L>())
+}
+
+/// The system set to which [`run_runners`] belongs, used to check whether a schedule contains it.
+#[derive(SystemSet, Debug, Clone, Eq, PartialEq, Hash)]
+struct RunRunnersSet(TypeId);
+
+impl RunRunnersSet {
+    #[inline(always)]
+    fn of<Label: 'static>() -> Self {
+        Self(TypeId::of::<Label>())
+    }
+}
+
 pub(crate) fn initialize_runner<Label>(
     world: &mut World,
     label: &Label,
@@ -113,15 +295,135 @@ where
         } else {
             map.0.push((entity, vec![runner], CancellationHandlers::default()));
         }
+        let label = label.intern();
+        if !resolve_schedule::<Label>(world, label) {
+            let mut pending = world.get_resource_or_init::<PendingSchedules>();
+            if !pending.0.iter().any(|(l, _)| l == &label) {
+                pending.0.push((label, resolve_pending_schedule::<Label>));
+            }
+        }
     } else {
         let mut reactor_map = ReactorMap::<Label>::default();
         reactor_map.0.push((entity, vec![runner], CancellationHandlers::default()));
         world.insert_non_send_resource(reactor_map);
+        #[cfg(feature = "gc")]
+        register_sweeper(world, sweep_orphans::<Label>);
 
         let Some(mut schedules) = world.get_resource_mut::<Schedules>() else {
             return;
         };
-        schedules.add_systems(label.intern(), run_runners::<Label>);
+        schedules.add_systems(label.intern(), run_runners::<Label>.in_set(RunRunnersSet::of::<Label>()));
+    }
+}
+
+/// The schedules that could not be resolved when the runners were initialized, because they were running.
+#[derive(Resource, Default)]
+pub(crate) struct PendingSchedules(Vec<(InternedScheduleLabel, fn(&mut World, InternedScheduleLabel) -> bool)>);
+
+/// Retries resolving the [`PendingSchedules`].
+///
+/// Once a schedule is resolved, it is never checked again.
+pub(crate) fn resolve_pending_schedules(world: &mut World) {
+    if world.get_resource::<PendingSchedules>().is_none_or(|pending| pending.0.is_empty()) {
+        return;
+    }
+    let Some(mut pending) = world.remove_resource::<PendingSchedules>() else {
+        return;
+    };
+    pending.0.retain(|(label, resolve)| !resolve(world, *label));
+    world.insert_resource(pending);
+}
+
+/// Resolves the pending schedule, or gives it up if there are no runners waiting for it anymore.
+fn resolve_pending_schedule<Label>(
+    world: &mut World,
+    label: InternedScheduleLabel,
+) -> bool
+where
+    Label: ScheduleLabel,
+{
+    let waiting = world
+        .get_non_send_resource::<ReactorMap<Label>>()
+        .is_some_and(|map| map.0.iter().any(|(_, runners, _)| !runners.is_empty()));
+    !waiting || resolve_schedule::<Label>(world, label)
+}
+
+/// Adds the system that runs the runners into the schedule related to `label` if it does not contain it yet.
+///
+/// This is called only when the runners are initialized,
+/// so that the runners also run on the schedule that has been replaced after the reactor started.
+/// Returns `false` if the schedule does not exist now, or it is running now.
+fn resolve_schedule<Label>(
+    world: &mut World,
+    label: InternedScheduleLabel,
+) -> bool
+where
+    Label: ScheduleLabel,
+{
+    let Some(mut schedules) = world.get_resource_mut::<Schedules>() else {
+        return false;
+    };
+    let Some(schedule) = schedules.get_mut(label) else {
+        return false;
+    };
+    if !schedule.graph().contains_set(RunRunnersSet::of::<Label>()) {
+        schedule.add_systems(run_runners::<Label>.in_set(RunRunnersSet::of::<Label>()));
+    }
+    true
+}
+
+/// Drops the runners of the reactor that have lost [`ReactorTask::will_either`](crate::prelude::ReactorTask::will_either),
+/// and calls the cancellation handlers registered by them.
+pub(crate) fn drop_settled_runners<Label>(
+    world: &mut World,
+    entity: Entity,
+)
+where
+    Label: ScheduleLabel,
+{
+    let Some(mut map) = world.get_non_send_resource_mut::<ReactorMap<Label>>() else {
+        return;
+    };
+    let Some((_, runners, token)) = map.0.iter_mut().find(|(e, ..)| e == &entity) else {
+        return;
+    };
+    let mut handlers = Vec::new();
+    runners.retain(|runner| {
+        let Some(settle) = runner.settle.as_ref().filter(|settle| settle.settled.load(Ordering::Relaxed)) else {
+            return true;
+        };
+        handlers.extend(settle.ids.iter().filter_map(|id| token.handlers.remove(id)));
+        false
+    });
+    for handler in handlers {
+        handler(world);
+    }
+}
+
+/// Moves the pending runners of the reactor from the schedule `from` to the schedule `to`.
+pub(crate) fn retarget_runners<From, To>(
+    world: &mut World,
+    entity: Entity,
+    to: &To,
+)
+where
+    From: ScheduleLabel,
+    To: ScheduleLabel,
+{
+    let Some(mut map) = world.get_non_send_resource_mut::<ReactorMap<From>>() else {
+        return;
+    };
+    let Some(i) = map.0.iter().position(|(e, ..)| e == &entity) else {
+        return;
+    };
+    let (_, runners, handlers) = map.0.remove(i);
+    for runner in runners {
+        initialize_runner(world, to, entity, runner);
+    }
+    if let Some(mut map) = world.get_non_send_resource_mut::<ReactorMap<To>>() {
+        if let Some((.., to_handlers)) = map.0.iter_mut().find(|(e, ..)| e == &entity) {
+            to_handlers.handlers.extend(handlers.handlers);
+        }
     }
 }
 
@@ -160,18 +462,69 @@ fn observe_remove_reactor<Label: ScheduleLabel>(
     ));
 }
 
+/// Frees the runners whose reactor entity no longer has [`NativeReactor`], and returns the number of them.
+#[cfg(feature = "gc")]
+fn sweep_orphans<L: Send + Sync + 'static>(world: &mut World) -> usize {
+    let Some(mut reactor_map) = world.remove_non_send_resource::<ReactorMap<L>>() else {
+        return 0;
+    };
+    let mut freed = 0;
+    let mut handlers = Vec::new();
+    reactor_map.0.retain_mut(|(entity, runners, token)| {
+        if world.get::<NativeReactor>(*entity).is_some() {
+            return true;
+        }
+        freed += runners.len();
+        handlers.push(core::mem::take(token));
+        false
+    });
+    world.insert_non_send_resource(reactor_map);
+    for handlers in handlers {
+        world.send_event(CallCancellationHandlers(handlers));
+    }
+    freed
+}
+
 fn run_runners<L: Send + Sync + 'static>(world: &mut World) {
     let Some(mut reactor_map) = world.remove_non_send_resource::<ReactorMap<L>>() else {
         return;
     };
+    let fixed = is_fixed_schedule::<L>();
+    let mut completed = Vec::new();
+    let mut settled = Vec::new();
     for (entity, runners, token) in reactor_map.0.iter_mut() {
+        if runners.is_empty() {
+            continue;
+        }
+        let paused = world.get::<ReactorPaused>(*entity).is_some();
+        if paused && runners.iter().all(|runner| !runner.run_even_if_paused) {
+            continue;
+        }
+        world.insert_resource(CurrentReactor(*entity));
         let mut request_cancel = false;
         runners.retain_mut(|runner| {
             if request_cancel {
                 return false;
             }
-            match runner.run(world, token) {
-                RunnerIs::Completed => false,
+            if paused && !runner.run_even_if_paused {
+                return true;
+            }
+            let status = if runner.run_even_if_paused {
+                run_in_real_time_if_paused(world, |world| runner.run(world, token))
+            } else {
+                runner.run(world, token)
+            };
+            match status {
+                RunnerIs::Completed => {
+                    if fixed && !completed.contains(entity) {
+                        completed.push(*entity);
+                    }
+                    if let Some(settle) = runner.settle.as_ref() {
+                        settled.push((*entity, settle.drop_losers));
+                    }
+                    wake(world, *entity);
+                    false
+                }
                 RunnerIs::Running => true,
                 RunnerIs::Canceled => {
                     request_cancel = true;
@@ -183,7 +536,28 @@ fn run_runners<L: Send + Sync + 'static>(world: &mut World) {
             world.commands().entity(*entity).despawn();
         }
     }
+    world.remove_resource::<CurrentReactor>();
     world.insert_non_send_resource(reactor_map);
+    for (entity, drop_losers) in settled {
+        drop_losers(world, entity);
+    }
+    if let Some(mut completed_in_fixed_tick) = world.get_resource_mut::<CompletedInFixedTick>() {
+        completed_in_fixed_tick.0.extend(completed);
+    }
+}
+
+/// Runs `f` in real time if [`Time<Virtual>`] is paused.
+///
+/// While [`InRealTime`] exists, [`ReactorDelta`](crate::reactor::time::ReactorDelta) reads [`Time<Real>`](bevy::time::Real) instead of [`Time`].
+fn run_in_real_time_if_paused(world: &mut World, f: impl FnOnce(&mut World) -> RunnerIs) -> RunnerIs {
+    let virtual_paused = world.get_resource::<Time<Virtual>>().is_some_and(Time::<Virtual>::is_paused);
+    if !virtual_paused || world.contains_resource::<InRealTime>() {
+        return f(world);
+    }
+    world.insert_resource(InRealTime);
+    let status = f(world);
+    world.remove_resource::<InRealTime>();
+    status
 }
 
 pub(crate) mod macros {
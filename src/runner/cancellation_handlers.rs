@@ -14,9 +14,12 @@ pub struct CancellationId(u64);
 ///
 /// This is passed as argument in [`Runner::run`](crate::prelude::Runner::run),
 /// and the [`Reactor`](crate::prelude::Reactor) can be cancelled by despawning the entity to which it is attached.
-#[repr(transparent)]
 #[derive(Default, Component)]
-pub struct CancellationHandlers(pub(crate) HashMap<CancellationId, fn(&mut World)>);
+pub struct CancellationHandlers {
+    pub(crate) handlers: HashMap<CancellationId, fn(&mut World)>,
+    /// The ids registered while [`CancellationHandlers::record`] is running.
+    recorded: Option<Vec<CancellationId>>,
+}
 
 impl CancellationHandlers {
     /// Register a function that will be called when [`CancellationHandlers`] is cancelled.
@@ -24,17 +27,59 @@ impl CancellationHandlers {
     pub fn register(&mut self, f: fn(&mut World)) -> CancellationId {
         static ID: AtomicU64 = AtomicU64::new(0);
         let id = CancellationId(ID.fetch_add(1, Ordering::Relaxed));
-        self.0.insert(id, f);
+        self.handlers.insert(id, f);
+        if let Some(recorded) = self.recorded.as_mut() {
+            recorded.push(id);
+        }
         id
     }
 
     /// Unregister a cancellation handler related to [`CancellationId`].
     #[inline]
     pub fn unregister(&mut self, id: &CancellationId) {
-        self.0.remove(id);
+        self.handlers.remove(id);
+    }
+
+    /// Runs `f`, appending the ids of the handlers registered during it to `ids`.
+    ///
+    /// The ids that have been unregistered are removed from `ids` afterwards.
+    /// The ids are also recorded by the outer call if this is nested.
+    pub(crate) fn record<R>(&mut self, ids: &mut Vec<CancellationId>, f: impl FnOnce(&mut Self) -> R) -> R {
+        let start = ids.len();
+        let outer = self.recorded.replace(core::mem::take(ids));
+        let output = f(self);
+        *ids = core::mem::replace(&mut self.recorded, outer).unwrap_or_default();
+        if let Some(outer) = self.recorded.as_mut() {
+            outer.extend_from_slice(&ids[start..]);
+        }
+        ids.retain(|id| self.handlers.contains_key(id));
+        output
     }
 }
 
 #[repr(transparent)]
 #[derive(Event)]
-pub(crate) struct CallCancellationHandlers(pub(crate) CancellationHandlers);
\ No newline at end of file
+pub(crate) struct CallCancellationHandlers(pub(crate) CancellationHandlers);
+
+#[cfg(test)]
+mod tests {
+    use crate::runner::CancellationHandlers;
+
+    #[test]
+    fn record_ids_registered_in_nested_calls() {
+        let mut handlers = CancellationHandlers::default();
+        handlers.register(|_| {});
+        let mut outer = Vec::new();
+        let mut inner = Vec::new();
+        let (id1, id2) = handlers.record(&mut outer, |handlers| {
+            let id1 = handlers.register(|_| {});
+            let id2 = handlers.record(&mut inner, |handlers| handlers.register(|_| {}));
+            (id1, id2)
+        });
+        assert_eq!(outer, vec![id1, id2]);
+        assert_eq!(inner, vec![id2]);
+
+        handlers.record(&mut outer, |handlers| handlers.unregister(&id1));
+        assert_eq!(outer, vec![id2]);
+    }
+}
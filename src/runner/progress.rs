@@ -0,0 +1,160 @@
+use crate::prelude::{CancellationHandlers, Runner, RunnerIs};
+use crate::runner::BoxedRunner;
+use bevy::prelude::{Component, Resource, World};
+use bevy::utils::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// The channel through which a long-running action reports its intermediate progress.
+///
+/// While the action passed to [`ReactorTask::will_with_progress`](crate::prelude::ReactorTask::will_with_progress) is running,
+/// this is inserted as a resource, so the systems of the action can report the progress via `Res<Progress<T>>`.
+/// Custom [`Runner`]s can also get it from the world.
+///
+/// The reported value can be read from the paired [`ProgressReceiver`].
+#[derive(Resource)]
+pub struct Progress<T>(Arc<RwLock<Option<T>>>);
+
+impl<T> Clone for Progress<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> Progress<T> {
+    /// Reports the progress.
+    ///
+    /// If the previous progress has not been read yet, it is replaced.
+    #[inline]
+    pub fn set(&self, progress: T) {
+        self.0.write().expect("Failed to set the progress").replace(progress);
+    }
+}
+
+/// Receives the progress reported via [`Progress`].
+///
+/// This is created by [`ReactorTask::will_with_progress`](crate::prelude::ReactorTask::will_with_progress),
+/// and can be inserted as a component or a resource so that other systems, such as UI, can read it each frame.
+#[derive(Component, Resource)]
+pub struct ProgressReceiver<T>(Arc<RwLock<Option<T>>>);
+
+impl<T> Clone for ProgressReceiver<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<T> ProgressReceiver<T> {
+    /// Returns the latest reported progress.
+    #[inline]
+    pub fn get(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.0.read().ok()?.clone()
+    }
+
+    /// Takes the latest reported progress.
+    ///
+    /// Returns `None` until the next progress is reported.
+    #[inline]
+    pub fn take(&self) -> Option<T> {
+        self.0.write().ok()?.take()
+    }
+}
+
+pub(crate) fn channel<T>() -> (Progress<T>, ProgressReceiver<T>) {
+    let cell = Arc::new(RwLock::new(None));
+    (Progress(Arc::clone(&cell)), ProgressReceiver(cell))
+}
+
+/// Inserts [`Progress`] as a resource only while the runner of the action is running.
+pub(crate) struct ProgressRunner<T> {
+    pub(crate) runner: BoxedRunner,
+    pub(crate) progress: Progress<T>,
+}
+
+impl<T> Runner for ProgressRunner<T>
+where
+    T: Send + Sync + 'static,
+{
+    fn run(&mut self, world: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+        let outer = world.remove_resource::<Progress<T>>();
+        world.insert_resource(self.progress.clone());
+        let status = self.runner.run(world, token);
+        world.remove_resource::<Progress<T>>();
+        if let Some(outer) = outer {
+            world.insert_resource(outer);
+        }
+        status
+    }
+}
+
+/// The aggregated progress of the actions weighted by [`Weighted::weighted`](crate::prelude::Weighted::weighted).
+///
+/// This component is inserted into the reactor entity when a weighted action is run for the first time.
+/// Each weighted action contributes its weight multiplied by its own progress, which is either
+/// the value reported via [`Progress<f32>`] in the range `0.0..=1.0`, or the aggregated progress of the weighted actions nested in it.
+///
+/// The weights of the top-level actions are the fractions of the whole,
+/// and the weights of the nested actions are the fractions of their parent.
+#[derive(Component, Debug, Default, Clone)]
+pub struct ReactorProgress {
+    stages: HashMap<u64, ProgressStage>,
+}
+
+#[derive(Debug, Clone)]
+struct ProgressStage {
+    parent: Option<u64>,
+    weight: f32,
+    local: f32,
+    completed: bool,
+}
+
+impl ReactorProgress {
+    /// Returns the aggregated progress in the range `0.0..=1.0`.
+    #[inline]
+    pub fn get(&self) -> f32 {
+        self.children_progress(None).clamp(0., 1.)
+    }
+
+    /// Forgets all reported progress, so the next loading phase can be tracked from zero.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.stages.clear();
+    }
+
+    pub(crate) fn report(&mut self, id: u64, parent: Option<u64>, weight: f32, local: Option<f32>, completed: bool) {
+        let stage = self.stages.entry(id).or_insert(ProgressStage {
+            parent,
+            weight,
+            local: 0.,
+            completed: false,
+        });
+        if let Some(local) = local {
+            stage.local = local.clamp(0., 1.);
+        }
+        stage.completed |= completed;
+    }
+
+    fn stage_progress(&self, id: u64, stage: &ProgressStage) -> f32 {
+        if stage.completed {
+            1.
+        } else {
+            stage.local.max(self.children_progress(Some(id)))
+        }
+    }
+
+    fn children_progress(&self, parent: Option<u64>) -> f32 {
+        self.stages
+            .iter()
+            .filter(|(_, stage)| stage.parent == parent)
+            .map(|(id, stage)| stage.weight * self.stage_progress(*id, stage))
+            .sum()
+    }
+}
+
+/// The id of the weighted action that is currently running, used as the parent of the nested weighted actions.
+#[derive(Resource, Copy, Clone)]
+pub(crate) struct CurrentProgressStage(pub(crate) u64);
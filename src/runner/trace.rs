@@ -0,0 +1,52 @@
+//! Records the tree of the actions run inside an awaited action for [`ReactorInspector`](crate::prelude::ReactorInspector).
+
+use crate::diagnostics::NestedAction;
+use std::cell::RefCell;
+
+thread_local! {
+    static TRACE: RefCell<Option<Trace>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct Trace {
+    depth: u32,
+    actions: Vec<NestedAction>,
+}
+
+/// Runs `f` while recording the actions run in it, in pre-order.
+pub(crate) fn record<R>(f: impl FnOnce() -> R) -> (R, Vec<NestedAction>) {
+    let outer = TRACE.replace(Some(Trace::default()));
+    let output = f();
+    let trace = TRACE.replace(outer);
+    (output, trace.map(|trace| trace.actions).unwrap_or_default())
+}
+
+/// Runs `f` as the action named by `name` if the actions are being recorded.
+pub(crate) fn enter<R>(name: impl FnOnce() -> String, f: impl FnOnce() -> R) -> R {
+    let recording = TRACE.with_borrow_mut(|trace| {
+        let Some(trace) = trace.as_mut() else {
+            return false;
+        };
+        trace.actions.push(NestedAction {
+            action: name(),
+            depth: trace.depth,
+        });
+        trace.depth += 1;
+        true
+    });
+    let output = f();
+    if recording {
+        TRACE.with_borrow_mut(|trace| {
+            if let Some(trace) = trace.as_mut() {
+                trace.depth -= 1;
+            }
+        });
+    }
+    output
+}
+
+/// Returns the type name without the module path and the generic parameters, e.g. `AllRunner`.
+pub(crate) fn short_type_name(type_name: &str) -> &str {
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    base.rsplit("::").next().unwrap_or(base)
+}
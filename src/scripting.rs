@@ -0,0 +1,214 @@
+//! Provides the bridge to compose reactors from [Lua](https://www.lua.org) scripts at runtime.
+//!
+//! Only the actions registered in [`ScriptActions`] can be called from scripts,
+//! so mods can reorder and repeat them, but can not access the world or the file system directly.
+//!
+//! The Lua version is selected by one of the `lua54`, `lua53`, `lua52`, `lua51` or `luajit` features,
+//! and `lua_vendored` builds it from source.
+
+use crate::prelude::{ActionSeed, Reactor};
+use bevy::app::{App, Update};
+use bevy::prelude::{Component, Resource};
+use bevy::utils::HashMap;
+use mlua::{HookTriggers, Lua, LuaOptions, StdLib, Value};
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// The maximum number of instructions a script can execute, which prevents the scripts from running forever.
+const MAX_INSTRUCTIONS: u32 = 1_000_000;
+
+/// The maximum number of bytes a script can allocate, which prevents the scripts from exhausting memory.
+const MAX_MEMORY: usize = 16 * 1024 * 1024;
+
+/// The number of instructions between each check of [`MAX_INSTRUCTIONS`].
+const INSTRUCTIONS_PER_HOOK: u32 = 1_000;
+
+/// The global functions removed from scripts because they can access the file system.
+const UNSAFE_GLOBALS: [&str; 4] = ["dofile", "loadfile", "load", "require"];
+
+/// The registry of actions that can be called from scripts.
+///
+/// Each action is called by `run("name")` in the Lua script,
+/// and the actions are executed in [`Update`] in the order they were called.
+///
+/// Note that the script is evaluated all at once when the reactor is created, before any action runs,
+/// so `run` returns nothing and the script can not branch on the outputs of the actions.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin))
+///     .register_script_action("wait_a_second", || delay::time().with(core::time::Duration::from_secs(1)).omit_input())
+///     .register_script_action("exit", || once::event::app_exit_success().omit_input())
+///     .add_systems(Startup, |mut commands: Commands, actions: Res<ScriptActions>|{
+///         let reactor = actions.reactor(r#"
+///             for i = 1, 3 do
+///                 run("wait_a_second")
+///             end
+///             run("exit")
+///         "#).unwrap();
+///         commands.spawn(reactor);
+///     });
+/// ```
+#[derive(Resource, Default, Clone)]
+pub struct ScriptActions(HashMap<String, Arc<dyn Fn() -> ActionSeed + Send + Sync>>);
+
+impl ScriptActions {
+    /// Registers the action that can be called by `run("name")` in scripts.
+    ///
+    /// If the action with the same name has already been registered, it is replaced.
+    #[inline]
+    pub fn register(&mut self, name: impl Into<String>, seed: impl Fn() -> ActionSeed + Send + Sync + 'static) -> &mut Self {
+        self.0.insert(name.into(), Arc::new(seed));
+        self
+    }
+
+    /// Returns whether the action is registered.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Evaluates the script and creates the reactor that runs the called actions in order.
+    ///
+    /// The script is evaluated immediately, so the errors such as calling unregistered actions are returned here
+    /// rather than when the reactor runs.
+    /// The script can use at most 16 MiB of memory and one million instructions.
+    pub fn reactor(&self, script: &str) -> Result<impl Component, ScriptError> {
+        let seeds = self.evaluate(script)?;
+        Ok(Reactor::schedule(|task| async move {
+            for seed in seeds {
+                task.will(Update, seed).await;
+            }
+        }))
+    }
+
+    fn evaluate(&self, script: &str) -> Result<Vec<ActionSeed>, ScriptError> {
+        let lua = Lua::new_with(StdLib::TABLE | StdLib::STRING | StdLib::MATH, LuaOptions::default())?;
+        lua.set_memory_limit(MAX_MEMORY)?;
+        let globals = lua.globals();
+        for name in UNSAFE_GLOBALS {
+            globals.set(name, Value::Nil)?;
+        }
+        let hooks = Cell::new(0);
+        lua.set_hook(HookTriggers::new().every_nth_instruction(INSTRUCTIONS_PER_HOOK), move |_, _| {
+            hooks.set(hooks.get() + 1);
+            if MAX_INSTRUCTIONS / INSTRUCTIONS_PER_HOOK < hooks.get() {
+                Err(mlua::Error::runtime("the script exceeded the maximum number of instructions"))
+            } else {
+                Ok(())
+            }
+        });
+
+        let seeds = RefCell::new(Vec::new());
+        lua.scope(|scope| {
+            let run = scope.create_function(|_, name: String| {
+                let seed = self
+                    .0
+                    .get(&name)
+                    .ok_or_else(|| mlua::Error::runtime(format!("the action `{name}` is not registered")))?;
+                seeds.borrow_mut().push(seed());
+                Ok(())
+            })?;
+            globals.set("run", run)?;
+            lua.load(script).exec()
+        })?;
+        Ok(seeds.into_inner())
+    }
+}
+
+/// Thrown when the script passed to [`ScriptActions::reactor`] could not be evaluated.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScriptError(pub String);
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to evaluate the script: {}", self.0)
+    }
+}
+
+impl Error for ScriptError {}
+
+impl From<mlua::Error> for ScriptError {
+    #[inline]
+    fn from(error: mlua::Error) -> Self {
+        Self(error.to_string())
+    }
+}
+
+/// Allows registering the actions called from scripts.
+pub trait ScriptActionsExtension {
+    /// Registers the action into [`ScriptActions`].
+    fn register_script_action(&mut self, name: impl Into<String>, seed: impl Fn() -> ActionSeed + Send + Sync + 'static) -> &mut Self;
+}
+
+impl ScriptActionsExtension for App {
+    fn register_script_action(&mut self, name: impl Into<String>, seed: impl Fn() -> ActionSeed + Send + Sync + 'static) -> &mut Self {
+        self
+            .world_mut()
+            .get_resource_or_init::<ScriptActions>()
+            .register(name, seed);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::ScriptActions;
+    use crate::scripting::ScriptActionsExtension;
+    use crate::tests::{decrement_count, increment_count, test_app};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn run_registered_actions_in_order() {
+        let mut app = test_app();
+        app
+            .register_script_action("increment", increment_count)
+            .register_script_action("decrement", decrement_count);
+        let reactor = app.world().resource::<ScriptActions>().reactor(r#"
+            for i = 1, 3 do
+                run("increment")
+            end
+            run("decrement")
+        "#).unwrap();
+        app.world_mut().spawn(reactor);
+        for _ in 0..8 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(2));
+    }
+
+    #[test]
+    fn failed_if_action_not_registered() {
+        let mut app = test_app();
+        app.register_script_action("increment", increment_count);
+        let actions = app.world().resource::<ScriptActions>();
+        assert!(actions.reactor(r#"run("increment"); run("despawn_all")"#).is_err());
+    }
+
+    #[test]
+    fn failed_if_script_accesses_file_system() {
+        let actions = ScriptActions::default();
+        assert!(actions.reactor(r#"dofile("save.lua")"#).is_err());
+        assert!(actions.reactor(r#"io.open("save.lua")"#).is_err());
+    }
+
+    #[test]
+    fn failed_if_script_runs_forever() {
+        let actions = ScriptActions::default();
+        assert!(actions.reactor("while true do end").is_err());
+    }
+
+    #[test]
+    fn failed_if_script_exhausts_memory() {
+        let actions = ScriptActions::default();
+        assert!(actions.reactor(r#"local s = string.rep("x", 2^31)"#).is_err());
+    }
+}
@@ -1,15 +1,22 @@
 use crate::action::Action;
+use crate::action::wait::Either;
 use crate::core::selector::Selector;
-use crate::runner::{initialize_runner, Output};
+use crate::diagnostics;
+use crate::middleware;
+use crate::runner::{drop_settled_runners, initialize_runner, Output};
 use crate::world_ptr::WorldPtr;
+use bevy::core::FrameCount;
 use bevy::ecs::schedule::ScheduleLabel;
-use bevy::prelude::Entity;
+use bevy::prelude::{Entity, World};
 use std::marker::PhantomData;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 pub(crate) struct WorldSelector<Label, In, Out> {
     action: Option<(Entity, Action<In, Out>)>,
     output: Output<Out>,
     label: Label,
+    settle: Option<(Arc<AtomicBool>, fn(&mut World, Entity))>,
     _m: PhantomData<In>,
 }
 
@@ -25,9 +32,17 @@ where
             action: Some((entity, action)),
             output: Output::default(),
             label,
+            settle: None,
             _m: PhantomData,
         }
     }
+
+    /// Makes the runner settle `settled`, and drop the losers via `drop_losers` when it wins.
+    #[inline]
+    fn settle(mut self, settled: Arc<AtomicBool>, drop_losers: fn(&mut World, Entity)) -> Self {
+        self.settle.replace((settled, drop_losers));
+        self
+    }
 }
 
 impl<Label, In, Out> Selector<WorldPtr> for WorldSelector<Label, In, Out>
@@ -41,7 +56,22 @@ where
     #[inline(always)]
     fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
         if let Some((entity, action)) = self.action.take() {
-            let runner = action.create_runner(self.output.clone());
+            let description = diagnostics::describe(world.as_mut(), &action);
+            #[cfg(feature = "profiling")]
+            let profiled = crate::profiling::describe(world.as_mut(), &action);
+            let run_even_if_paused = action.1.is_run_even_if_paused();
+            let mut runner = middleware::create_runner(world.as_mut(), entity, action, self.output.clone());
+            if let Some(description) = description {
+                runner = diagnostics::create_runner(world.as_mut(), entity, &self.label, description, runner);
+            }
+            #[cfg(feature = "profiling")]
+            if let Some(action) = profiled {
+                runner = crate::profiling::create_runner(action, runner);
+            }
+            runner = runner.run_even_if_paused(run_even_if_paused);
+            if let Some((settled, drop_losers)) = self.settle.take() {
+                runner = runner.settle(settled, drop_losers);
+            }
             initialize_runner(world.as_mut(), &self.label, entity, runner);
             None
         } else {
@@ -50,7 +80,99 @@ where
     }
 }
 
+/// Runs the two [`WorldSelector`]s registered in different schedules, and outputs whichever completes first.
+pub(crate) struct EitherSelector<L1, I1, O1, L2, I2, O2> {
+    lhs: WorldSelector<L1, I1, O1>,
+    rhs: WorldSelector<L2, I2, O2>,
+}
+
+impl<L1, I1, O1, L2, I2, O2> EitherSelector<L1, I1, O1, L2, I2, O2>
+where
+    L1: ScheduleLabel,
+    I1: 'static,
+    O1: 'static,
+    L2: ScheduleLabel,
+    I2: 'static,
+    O2: 'static,
+{
+    /// Creates the selector.
+    ///
+    /// Both runners share the settled flag, so once either runner has completed,
+    /// the other one is dropped in the same frame without running its action again,
+    /// and its cancellation handlers are called.
+    pub(crate) fn new(
+        entity: Entity,
+        (l1, a1): (L1, Action<I1, O1>),
+        (l2, a2): (L2, Action<I2, O2>),
+    ) -> Self {
+        let settled = Arc::new(AtomicBool::new(false));
+        Self {
+            lhs: WorldSelector::new(l1, entity, a1).settle(settled.clone(), drop_settled_runners::<L2>),
+            rhs: WorldSelector::new(l2, entity, a2).settle(settled, drop_settled_runners::<L1>),
+        }
+    }
+}
+
+impl<L1, I1, O1, L2, I2, O2> Selector<WorldPtr> for EitherSelector<L1, I1, O1, L2, I2, O2>
+where
+    L1: ScheduleLabel,
+    I1: 'static,
+    O1: 'static,
+    L2: ScheduleLabel,
+    I2: 'static,
+    O2: 'static,
+{
+    type Output = Either<O1, O2>;
+
+    #[inline(always)]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        let lhs = self.lhs.select(world);
+        let rhs = self.rhs.select(world);
+        lhs.map(Either::Left).or_else(|| rhs.map(Either::Right))
+    }
+}
+
+/// The selector registered by [`ActionScope::add`](crate::prelude::ActionScope::add) whose output is discarded.
+pub(crate) type ScopedSelector = Box<dyn Selector<WorldPtr, Output=()> + Send + Sync>;
+
+/// Discards the output of [`WorldSelector`], so that the selectors with different outputs can be stored together.
+pub(crate) struct DiscardSelector<Label, In, Out>(pub(crate) WorldSelector<Label, In, Out>);
+
+impl<Label, In, Out> Selector<WorldPtr> for DiscardSelector<Label, In, Out>
+where
+    Label: ScheduleLabel,
+    In: 'static,
+    Out: 'static,
+{
+    type Output = ();
+
+    #[inline(always)]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        self.0.select(world).map(|_| ())
+    }
+}
+
+/// Starts all the selectors of the scope together, and outputs when all of them have completed.
+pub(crate) struct ScopeSelector(pub(crate) Vec<ScopedSelector>);
+
+impl Selector<WorldPtr> for ScopeSelector {
+    type Output = ();
 
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        self.0.retain_mut(|selector| selector.select(world).is_none());
+        self.0.is_empty().then_some(())
+    }
+}
 
+/// Outputs the current [`FrameCount`] immediately.
+pub(crate) struct FrameSelector;
 
+impl Selector<WorldPtr> for FrameSelector {
+    type Output = u32;
 
+    #[inline]
+    fn select(&mut self, world: WorldPtr) -> Option<Self::Output> {
+        Some(world.as_mut().get_resource::<FrameCount>().map(|frame| frame.0).unwrap_or_default())
+    }
+}
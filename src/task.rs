@@ -1,13 +1,22 @@
 //! Create a task that runs the system until certain conditions are met.
 
 use crate::action::Action;
+use crate::action::wait::Either;
 use crate::core::task::CoreTask;
-use crate::selector::WorldSelector;
+use crate::prelude::{ActionSeed, ProgressReceiver};
+use crate::reactor::checkpoint::{CheckpointSelector, LastCheckpointSelector};
+use crate::reactor::inbox::RecvSelector;
+use crate::reactor::params::ParamsSelector;
+use crate::reactor::sub::{SpawnSelector, SubReactorHandle};
+use crate::runner::progress::{self, ProgressRunner};
+use crate::selector::{DiscardSelector, EitherSelector, FrameSelector, ScopeSelector, ScopedSelector, WorldSelector};
 use crate::world_ptr::WorldPtr;
 use bevy::ecs::schedule::ScheduleLabel;
 use bevy::prelude::Entity;
 use futures_polling::FuturePollingExt;
+use std::borrow::Cow;
 use std::future::Future;
+use std::marker::PhantomData;
 
 /// Create a task that runs the system until certain conditions are met.
 #[derive(Clone)]
@@ -23,6 +32,10 @@ impl ReactorTask {
     ///
     /// For `action`, please see [`here`](crate::action). 
     ///
+    /// The fixed schedules such as [`FixedUpdate`](bevy::prelude::FixedUpdate) are also supported.
+    /// The reactor is polled at the end of each fixed tick in which its action has been completed,
+    /// so the actions awaited one after another advance once per fixed tick, even if several ticks run in a frame.
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -57,6 +70,287 @@ impl ReactorTask {
         self.task.will(WorldSelector::new(label, self.entity, action.into()))
     }
 
+    /// Create a new task that runs the action outputs [`Result`].
+    ///
+    /// This is the same as [`ReactorTask::will`], but its output is restricted to [`Result`],
+    /// so the error can be propagated with `?` operator if the reactor's future returns [`Result`].
+    ///
+    /// To skip the remaining steps within an action, use [`TryPipe`](crate::prelude::TryPipe) and [`TryThen`](crate::prelude::TryThen).
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let num: usize = task.try_will(Update, once::run(|| Ok::<_, String>(1))).await?;
+    ///     task.try_will(Update, once::run(move || {
+    ///         if num == 1 { Ok(()) } else { Err("unexpected".to_string()) }
+    ///     })).await?;
+    ///     Ok::<_, String>(())
+    /// });
+    /// ```
+    #[inline]
+    pub fn try_will<Label, In, T, E>(
+        &self,
+        label: Label,
+        action: impl Into<Action<In, Result<T, E>>> + 'static,
+    ) -> impl Future<Output=Result<T, E>>
+    where
+        Label: ScheduleLabel,
+        In: 'static,
+        T: 'static,
+        E: 'static,
+    {
+        self.will(label, action)
+    }
+
+    /// Create a new task that runs the two actions in different schedules, and outputs whichever completes first.
+    ///
+    /// This is useful when what you want to detect may occur in either schedule, e.g. [`PreUpdate`](bevy::prelude::PreUpdate) or [`Update`](bevy::prelude::Update)
+    /// depending on other plugins.
+    /// Once either action has completed, the other one is dropped in the same frame without running again,
+    /// and the cancellation handlers registered by it are called.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let pressed = task.will_either(
+    ///         (PreUpdate, wait::input::just_pressed().with(KeyCode::KeyA)),
+    ///         (Update, wait::input::just_pressed().with(KeyCode::KeyB)),
+    ///     ).await;
+    ///     if pressed.is_left(){
+    ///         info!("A");
+    ///     }
+    /// });
+    /// ```
+    #[inline]
+    pub fn will_either<L1, I1, O1, L2, I2, O2>(
+        &self,
+        (l1, a1): (L1, impl Into<Action<I1, O1>> + 'static),
+        (l2, a2): (L2, impl Into<Action<I2, O2>> + 'static),
+    ) -> impl Future<Output=Either<O1, O2>>
+    where
+        L1: ScheduleLabel,
+        I1: 'static,
+        O1: 'static,
+        L2: ScheduleLabel,
+        I2: 'static,
+        O2: 'static,
+    {
+        self.task.will(EitherSelector::new(self.entity, (l1, a1.into()), (l2, a2.into())))
+    }
+
+    /// Create a new task that starts all the actions added in `f` together, and completes when all of them have finished.
+    ///
+    /// Unlike [`wait::all`](crate::prelude::wait::all), each action can run on a different schedule.
+    /// The outputs of the actions are discarded.
+    /// The actions belong to this reactor, so if the reactor is cancelled, all of them are dropped in the same frame.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.scope(|s| {
+    ///         s.add(Update, delay::frames().with(30));
+    ///         s.add(FixedUpdate, wait::until(|mut ticks: Local<u32>| {
+    ///             *ticks += 1;
+    ///             *ticks == 10
+    ///         }));
+    ///     }).await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn scope(&self, f: impl FnOnce(&mut ActionScope)) -> impl Future<Output=()> {
+        let mut scope = ActionScope {
+            entity: self.entity,
+            selectors: Vec::new(),
+        };
+        f(&mut scope);
+        self.task.will(ScopeSelector(scope.selectors))
+    }
+
+    /// Create a new task that reports the progress of the action.
+    ///
+    /// Returns the task and [`ProgressReceiver`] paired with it.
+    /// The type of the progress cannot be inferred from the action, so it must be annotated on the receiver.
+    /// While the action is running, [`Progress`](crate::prelude::Progress) is inserted as a resource,
+    /// so the systems of the action can report the progress through it.
+    /// [`ProgressReceiver`] can be inserted as a component or a resource so that other systems, such as UI, can read it each frame.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let (loading, receiver): (_, ProgressReceiver<f32>) = task.will_with_progress(Update, wait::until(|mut frames: Local<u32>, progress: Res<Progress<f32>>|{
+    ///         *frames += 1;
+    ///         progress.set(*frames as f32 / 60.);
+    ///         *frames == 60
+    ///     }));
+    ///     task.will(Update, once::res::insert().with(receiver)).await;
+    ///     loading.await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn will_with_progress<Label, In, Out, P>(
+        &self,
+        label: Label,
+        action: impl Into<Action<In, Out>> + 'static,
+    ) -> (impl Future<Output=Out>, ProgressReceiver<P>)
+    where
+        Label: ScheduleLabel,
+        In: 'static,
+        Out: 'static,
+        P: Send + Sync + 'static,
+    {
+        let (progress, receiver) = progress::channel();
+        let Action(input, seed) = action.into();
+        let name = seed.name().map(ToString::to_string);
+        let mut seed = ActionSeed::new(move |input, output| ProgressRunner {
+            runner: seed.create_runner(input, output),
+            progress,
+        });
+        if let Some(name) = name {
+            seed = seed.named(name);
+        }
+        (self.will(label, seed.with(input)), receiver)
+    }
+
+    /// Waits until the message `M` is sent to this reactor, and returns it.
+    ///
+    /// The messages are sent by [`ReactorCommands::send`](crate::prelude::ReactorCommands::send) or [`ReactorInbox::push`](crate::prelude::ReactorInbox::push),
+    /// and received in the order they were sent.
+    /// Unlike [`ReactorTask::will`], this does not depend on any schedule, and the message is checked each time the reactor is polled.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// struct Abort;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.recv::<Abort>().await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn recv<M>(&self) -> impl Future<Output=M>
+    where
+        M: Send + Sync + 'static,
+    {
+        self.task.will(RecvSelector {
+            entity: self.entity,
+            _m: PhantomData,
+        })
+    }
+
+    /// Forks a child reactor from this reactor, and returns the handle to it.
+    ///
+    /// The child reactor runs concurrently with this reactor,
+    /// and its completion can be awaited with [`wait::sub_reactor`](crate::prelude::wait::sub_reactor).
+    /// The child is spawned as a child entity of this reactor,
+    /// so it is cancelled when this reactor is completed or cancelled.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let child = task.spawn(|task| async move{
+    ///         task.will(Update, delay::frames().with(30)).await;
+    ///     }).await;
+    ///     task.will(Update, wait::sub_reactor().with(child)).await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn spawn<F, Fut>(&self, f: F) -> impl Future<Output=SubReactorHandle>
+    where
+        F: FnOnce(ReactorTask) -> Fut + Send + Sync + 'static,
+        Fut: Future + Send + Sync + 'static,
+        Fut::Output: Send + 'static,
+    {
+        self.task.will(SpawnSelector {
+            parent: self.entity,
+            f: Some(f),
+        })
+    }
+
+    /// Records the checkpoint `label` in [`ReactorCheckpoints`](crate::prelude::ReactorCheckpoints) of this reactor.
+    ///
+    /// The future of a reactor cannot be saved, but the last checkpoint can,
+    /// and [`Reactor::schedule_resumable`](crate::prelude::Reactor::schedule_resumable) passes it to the reactor after loading.
+    /// This resolves immediately.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     task.will(Update, delay::frames().with(30)).await;
+    ///     task.checkpoint("boss").await;
+    /// });
+    /// ```
+    #[inline]
+    pub fn checkpoint(&self, label: impl Into<Cow<'static, str>>) -> impl Future<Output=()> {
+        self.task.will(CheckpointSelector {
+            entity: self.entity,
+            label: label.into(),
+        })
+    }
+
+    /// Returns the current [`FrameCount`](bevy::core::FrameCount), or `0` if it does not exist.
+    ///
+    /// This resolves immediately, so it can be combined with [`wait::tick::reach`](crate::prelude::wait::tick::reach)
+    /// to schedule the steps at absolute ticks.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// use bevy::prelude::*;
+    /// use bevy_flurx::prelude::*;
+    ///
+    /// Reactor::schedule(|task| async move{
+    ///     let start = task.frame().await;
+    ///     for step in 1..=10{
+    ///         task.will(Update, wait::tick::reach().with(start + step * 6)).await;
+    ///     }
+    /// });
+    /// ```
+    #[inline]
+    pub fn frame(&self) -> impl Future<Output=u32> {
+        self.task.will(FrameSelector)
+    }
+
+    pub(crate) fn last_checkpoint(&self) -> impl Future<Output=Option<String>> {
+        self.task.will(LastCheckpointSelector(self.entity))
+    }
+
+    pub(crate) fn params<P>(&self) -> impl Future<Output=Option<P>>
+    where
+        P: Clone + Send + Sync + 'static,
+    {
+        self.task.will(ParamsSelector {
+            entity: self.entity,
+            _m: PhantomData,
+        })
+    }
+
     /// Create a new initialized task.
     ///
     /// Unlike [`ReactorTask::run`], returns a task that registered a system.
@@ -96,14 +390,40 @@ impl ReactorTask {
     }
 }
 
+/// Collects the actions started together by [`ReactorTask::scope`].
+pub struct ActionScope {
+    entity: Entity,
+    selectors: Vec<ScopedSelector>,
+}
+
+impl ActionScope {
+    /// Adds the action that runs on the schedule `label`.
+    #[inline]
+    pub fn add<Label, In, Out>(
+        &mut self,
+        label: Label,
+        action: impl Into<Action<In, Out>> + 'static,
+    ) -> &mut Self
+    where
+        Label: ScheduleLabel,
+        In: Send + Sync + 'static,
+        Out: Send + Sync + 'static,
+    {
+        self.selectors.push(Box::new(DiscardSelector(WorldSelector::new(label, self.entity, action.into()))));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::action::once;
-    use crate::prelude::wait;
+    use crate::prelude::{wait, Either, Progress, ProgressReceiver};
     use crate::reactor::Reactor;
     use crate::tests::test_app;
-    use bevy::app::{AppExit, First, Startup, Update};
-    use bevy::prelude::Commands;
+    use bevy::app::{AppExit, First, PreUpdate, Startup, Update};
+    use bevy::prelude::{Commands, Local, Res, ResMut};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
 
     #[test]
     fn run() {
@@ -123,4 +443,175 @@ mod tests {
         app.update();
         assert!(app.world().get_non_send_resource::<AppExit>().is_some());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_will_propagate_err() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.try_will(Update, once::run(|| Err::<(), _>("failed"))).await?;
+                task.will(Update, once::non_send::insert().with(AppExit::Success)).await;
+                Ok::<_, &'static str>(())
+            }));
+        });
+
+        app.update();
+        app.update();
+        assert!(app.world().get_non_send_resource::<AppExit>().is_none());
+    }
+
+    #[test]
+    fn will_with_progress() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let (counting, receiver): (_, ProgressReceiver<u32>) = task.will_with_progress(Update, wait::until(|mut count: Local<u32>, progress: Res<Progress<u32>>| {
+                    *count += 1;
+                    progress.set(*count);
+                    *count == 3
+                }));
+                task.will(Update, once::res::insert().with(receiver)).await;
+                counting.await;
+            }));
+        });
+
+        app.update();
+        assert_eq!(app.world().resource::<ProgressReceiver<u32>>().get(), None);
+        for expect in 1..=3 {
+            app.update();
+            assert_eq!(app.world().resource::<ProgressReceiver<u32>>().get(), Some(expect));
+        }
+        assert!(app.world().get_resource::<Progress<u32>>().is_none());
+        assert_eq!(app.world().resource::<ProgressReceiver<u32>>().take(), Some(3));
+        assert_eq!(app.world().resource::<ProgressReceiver<u32>>().take(), None);
+    }
+
+    #[test]
+    fn will_either_first_completed() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                let output = task.will_either(
+                    (PreUpdate, wait::until(|mut count: Local<u32>| {
+                        *count += 1;
+                        *count == 2
+                    })),
+                    (Update, wait::until(|mut count: ResMut<Count>| {
+                        count.increment();
+                        false
+                    })),
+                ).await;
+                if let Either::Left(()) = output {
+                    task.will(Update, once::non_send::insert().with(AppExit::Success)).await;
+                }
+            }));
+        });
+
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(1));
+        assert!(app.world().get_non_send_resource::<AppExit>().is_some());
+    }
+
+    #[test]
+    fn will_either_drop_loser_in_same_frame() {
+        use crate::prelude::{ActionSeed, CancellationHandlers, Runner, RunnerIs};
+        use bevy::ecs::schedule::ScheduleLabel;
+        use bevy::prelude::World;
+        use bevy_test_helper::resource::bool::{Bool, BoolExtension};
+
+        #[derive(ScheduleLabel, Debug, Clone, Eq, PartialEq, Hash)]
+        struct NeverRun;
+
+        struct LoserRunner;
+
+        impl Runner for LoserRunner {
+            fn run(&mut self, _: &mut World, token: &mut CancellationHandlers) -> RunnerIs {
+                token.register(|world| {
+                    world.resource_mut::<Count>().increment();
+                });
+                RunnerIs::Running
+            }
+        }
+
+        impl Drop for LoserRunner {
+            fn drop(&mut self) {
+                DROPPED.store(true, std::sync::atomic::Ordering::Relaxed);
+            }
+        }
+
+        static DROPPED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will_either(
+                    (Update, wait::until(|b: Res<Bool>| b.0)),
+                    (NeverRun, ActionSeed::<(), ()>::new(|_, _| LoserRunner)),
+                ).await;
+            }));
+        });
+        app.update();
+        app.world_mut().run_schedule(NeverRun);
+        app.assert_resource_eq(Count(0));
+
+        app.set_bool(true);
+        app.update();
+        assert!(DROPPED.load(std::sync::atomic::Ordering::Relaxed));
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn scope_waits_all_actions_on_different_schedules() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.scope(|s| {
+                    s.add(PreUpdate, wait::until(|mut frames: Local<u32>| {
+                        *frames += 1;
+                        *frames == 2
+                    }));
+                    s.add(Update, wait::until(|mut frames: Local<u32>| {
+                        *frames += 1;
+                        *frames == 4
+                    }));
+                }).await;
+                task.will(Update, once::run(|mut count: ResMut<Count>| {
+                    count.increment();
+                })).await;
+            }));
+        });
+        for _ in 0..4 {
+            app.update();
+        }
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn drop_scoped_actions_if_reactor_cancelled() {
+        let mut app = test_app();
+        let reactor = app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.scope(|s| {
+                for _ in 0..2 {
+                    s.add(Update, wait::until(|mut count: ResMut<Count>| {
+                        count.increment();
+                        false
+                    }));
+                }
+            }).await;
+        })).id();
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(2));
+
+        app.world_mut().despawn(reactor);
+        app.update();
+        app.assert_resource_eq(Count(2));
+    }
+}
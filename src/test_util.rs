@@ -8,13 +8,15 @@ use std::future::Future;
 pub trait SpawnReactor {
     fn spawn_reactor<F>(&mut self, f: fn(ReactorTask) -> F)
     where
-        F: Future + Send + Sync + 'static;
+        F: Future + Send + Sync + 'static,
+        F::Output: Send + 'static;
 }
 
 impl SpawnReactor for App {
     fn spawn_reactor<F>(&mut self, f: fn(ReactorTask) -> F)
     where
         F: Future + Send + Sync + 'static,
+        F::Output: Send + 'static,
     {
         self.add_systems(Startup, move |mut commands: Commands| {
             commands.spawn(Reactor::schedule(f));
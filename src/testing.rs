@@ -0,0 +1,153 @@
+//! Provides the utilities for testing reactors deterministically.
+//!
+//! [`FlurxTestExt`] advances the app until the reactors have settled and asserts on their progress,
+//! so that tests no longer need to count `app.update()` calls by trial and error.
+
+use crate::debug::{self, ReactorInfo};
+use crate::diagnostics::DiagnosticsEnabled;
+use crate::prelude::ReactorPaused;
+use crate::reactor::NativeReactor;
+use bevy::app::App;
+use bevy::prelude::{Entity, With, Without};
+
+/// The extension trait of [`App`] for testing reactors.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// let mut app = App::new();
+/// app
+///     .add_plugins((MinimalPlugins, FlurxDiagnosticsPlugin))
+///     .add_systems(Startup, |mut commands: Commands|{
+///         commands.spawn(Reactor::schedule(|task| async move{
+///             task.will(Update, wait::event::comes::<AppExit>().named("exit")).await;
+///         }));
+///     });
+/// app.update();
+/// app.assert_awaiting("exit");
+///
+/// app.world_mut().send_event(AppExit::Success);
+/// app.advance_until_reactor_idle(10);
+/// ```
+pub trait FlurxTestExt {
+    /// Updates the app until no running reactors remain, and returns the number of frames advanced.
+    ///
+    /// The reactors paused by [`ReactorPaused`] are not counted as running.
+    /// The app is always updated at least once, so that the reactors spawned in [`Startup`](bevy::prelude::Startup) are also waited for.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if any reactor is still running after `max_frames` frames.
+    fn advance_until_reactor_idle(&mut self, max_frames: usize) -> usize;
+
+    /// Returns the state of the reactor attached to `entity`, or `None` if it has finished or does not exist.
+    fn reactor_state(&mut self, entity: Entity) -> Option<ReactorInfo>;
+
+    /// Asserts that any reactor is awaiting the action named by [`ActionSeed::named`](crate::prelude::ActionSeed::named).
+    ///
+    /// ## Panics
+    ///
+    /// Panics if no reactor is awaiting the action,
+    /// or [`FlurxDiagnosticsPlugin`](crate::prelude::FlurxDiagnosticsPlugin) has not been added.
+    fn assert_awaiting(&mut self, action: &str);
+}
+
+impl FlurxTestExt for App {
+    fn advance_until_reactor_idle(&mut self, max_frames: usize) -> usize {
+        for frame in 1..=max_frames {
+            self.update();
+            let world = self.world_mut();
+            if world
+                .query_filtered::<(), (With<NativeReactor>, Without<ReactorPaused>)>()
+                .iter(world)
+                .next()
+                .is_none()
+            {
+                return frame;
+            }
+        }
+        panic!(
+            "reactors are still running after {max_frames} frames: {:?}",
+            debug::reactors(self.world_mut())
+        );
+    }
+
+    #[inline]
+    fn reactor_state(&mut self, entity: Entity) -> Option<ReactorInfo> {
+        debug::reactors(self.world_mut())
+            .into_iter()
+            .find(|reactor| reactor.entity == entity)
+    }
+
+    fn assert_awaiting(&mut self, action: &str) {
+        assert!(
+            self.world().contains_resource::<DiagnosticsEnabled>(),
+            "`FlurxDiagnosticsPlugin` must be added to assert on the awaited actions"
+        );
+        let reactors = debug::reactors(self.world_mut());
+        assert!(
+            reactors.iter().any(|reactor| reactor.pending.iter().any(|pending| pending == action)),
+            "no reactor is awaiting `{action}`: {reactors:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::{once, wait};
+    use crate::prelude::{FlurxDiagnosticsPlugin, Reactor};
+    use crate::testing::FlurxTestExt;
+    use crate::tests::test_app;
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{Commands, ResMut};
+    use bevy_test_helper::event::{DirectEvents, TestEvent1};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[test]
+    fn advance_until_all_reactors_finished() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                for _ in 0..3 {
+                    task.will(Update, once::run(|mut count: ResMut<Count>| {
+                        count.increment();
+                    })).await;
+                }
+            }));
+        });
+        app.advance_until_reactor_idle(10);
+        app.assert_resource_eq(Count(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic_if_reactors_still_running() {
+        let mut app = test_app();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, wait::until(|| false)).await;
+            }));
+        });
+        app.advance_until_reactor_idle(5);
+    }
+
+    #[test]
+    fn assert_awaiting_named_action() {
+        let mut app = test_app();
+        app.add_plugins(FlurxDiagnosticsPlugin);
+        let entity = app.world_mut().spawn(Reactor::schedule(|task| async move {
+            task.will(Update, wait::event::comes::<TestEvent1>().named("event")).await;
+        })).id();
+        app.update();
+        app.assert_awaiting("event");
+        assert_eq!(app.reactor_state(entity).map(|reactor| reactor.pending), Some(vec!["event".to_string()]));
+
+        app.send(TestEvent1);
+        app.advance_until_reactor_idle(5);
+        assert!(app.reactor_state(entity).is_none());
+    }
+}
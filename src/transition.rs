@@ -0,0 +1,139 @@
+use crate::debug::FlurxDebug;
+use crate::plugins::FlurxCorePlugin;
+use crate::{poll_reactors, FlurxPlugin, ReactorsQuery};
+use bevy::app::{App, Plugin};
+use bevy::prelude::{IntoSystemConfigs, World};
+use bevy::state::state::{StateTransition, StateTransitionSteps};
+
+/// Additionally polls the reactors right after [`StateTransition`] has fully applied.
+///
+/// By default, the reactors are polled only in [`Last`](bevy::prelude::Last),
+/// so the continuation of an action awaited on [`OnEnter`](bevy::prelude::OnEnter) or [`OnExit`](bevy::prelude::OnExit)
+/// is not run until the end of the frame, and the actions scheduled after it start from the next frame.
+///
+/// With this plugin, the reactors are also polled after all exit, transition and enter schedules have run,
+/// so they always observe the states after the transition, and the following actions on [`Update`](bevy::prelude::Update)
+/// start in the same frame as the transition.
+///
+/// This plugin adds [`FlurxPlugin`] if it has not been added yet.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_flurx::prelude::*;
+///
+/// #[derive(States, Eq, PartialEq, Copy, Clone, Hash, Default, Debug)]
+/// enum Status{
+///     #[default]
+///     Loading,
+///     InGame,
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, FlurxPlugin, PollAfterStateTransitionPlugin))
+///     .init_state::<Status>()
+///     .add_systems(Startup, |mut commands: Commands|{
+///         commands.spawn(Reactor::schedule(|task| async move{
+///             task.will(Update, once::state::set().with(Status::InGame)).await;
+///             task.will(OnEnter(Status::InGame), once::no_op()).await;
+///             // This action starts in the same frame as entering `Status::InGame`.
+///             task.will(Update, once::run(|| info!("entered"))).await;
+///         }));
+///     });
+/// ```
+pub struct PollAfterStateTransitionPlugin;
+
+impl Plugin for PollAfterStateTransitionPlugin {
+    #[inline]
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FlurxCorePlugin>() {
+            app.add_plugins(FlurxPlugin);
+        }
+        app.add_systems(StateTransition, run_reactors_after_state_transition.after(StateTransitionSteps::EnterSchedules));
+    }
+}
+
+fn run_reactors_after_state_transition(world: &mut World, reactors: &mut ReactorsQuery) {
+    // In step mode, each reactor is polled at most once per step in `run_reactors`.
+    if world.get_resource::<FlurxDebug>().is_some_and(|debug| debug.is_stepping()) {
+        return;
+    }
+    poll_reactors(world, reactors, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::action::once;
+    use crate::prelude::{PollAfterStateTransitionPlugin, Reactor, RunningReactor};
+    use crate::tests::{increment_count, test_app};
+    use bevy::app::{Startup, Update};
+    use bevy::prelude::{AppExtStates, Commands, NextState, OnEnter, States};
+    use bevy_test_helper::resource::count::Count;
+    use bevy_test_helper::resource::DirectResourceControl;
+
+    #[derive(States, Eq, PartialEq, Copy, Clone, Hash, Default, Debug)]
+    enum Status {
+        #[default]
+        First,
+        Second,
+    }
+
+    fn set_state_then_wait(app: &mut bevy::app::App) {
+        app.init_state::<Status>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(Update, once::state::set().with(Status::Second)).await;
+                task.will(OnEnter(Status::Second), once::no_op()).await;
+                task.will(Update, increment_count()).await;
+            }));
+        });
+    }
+
+    #[test]
+    fn continue_after_next_frame_without_plugin() {
+        let mut app = test_app();
+        set_state_then_wait(&mut app);
+        app.update();
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn continue_in_same_frame_as_transition() {
+        let mut app = test_app();
+        app.add_plugins(PollAfterStateTransitionPlugin);
+        set_state_then_wait(&mut app);
+        app.update();
+        app.assert_resource_eq(Count(0));
+        app.update();
+        app.assert_resource_eq(Count(1));
+    }
+
+    #[test]
+    fn count_poll_after_transition_as_step() {
+        let mut app = test_app();
+        app.add_plugins(PollAfterStateTransitionPlugin);
+        app.init_state::<Status>();
+        app.add_systems(Startup, |mut commands: Commands| {
+            commands.spawn(Reactor::schedule(|task| async move {
+                task.will(OnEnter(Status::Second), once::no_op()).await;
+                task.will(Update, increment_count()).await;
+                task.will(Update, increment_count()).await;
+            }).with_max_steps_per_frame(1));
+        });
+        app.update();
+        app.world_mut().resource_mut::<NextState<Status>>().set(Status::Second);
+        app.update();
+        app.assert_resource_eq(Count(1));
+        app.update();
+        app.assert_resource_eq(Count(2));
+        // The reactor has already used up its step after the transition,
+        // so it finishes in the next frame.
+        assert_eq!(app.world_mut().query::<&RunningReactor>().iter(app.world()).len(), 1);
+        app.update();
+        assert_eq!(app.world_mut().query::<&RunningReactor>().iter(app.world()).len(), 0);
+    }
+}
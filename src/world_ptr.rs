@@ -1,9 +1,12 @@
+use bevy::ecs::world::WorldId;
 use bevy::prelude::World;
 use std::ptr;
 
-#[repr(transparent)]
 #[derive(Clone, Copy)]
-pub struct WorldPtr(*mut World);
+pub struct WorldPtr {
+    ptr: *mut World,
+    id: Option<WorldId>,
+}
 
  // SAFETY: The actual value is created only by the exclusive system and is not used at any other time.
 unsafe impl Send for WorldPtr {}
@@ -13,7 +16,10 @@ unsafe impl Sync for WorldPtr {}
 impl WorldPtr {
     #[inline]
     pub(crate) fn new(value: &mut World) -> Self {
-        Self(value as *mut World)
+        Self {
+            id: Some(value.id()),
+            ptr: value as *mut World,
+        }
     }
 
     #[allow(clippy::mut_from_ref)]
@@ -22,7 +28,15 @@ impl WorldPtr {
         // SAFETY:
         // The world is guaranteed to be alive from the time this structure is created until the reactor is executed, 
         // and the reactor and tasks run on the main thread, so it is probably safe.
-        unsafe { &mut *self.0 }
+        unsafe { &mut *self.ptr }
+    }
+
+    /// Returns the id of the world this pointer was created from.
+    ///
+    /// The id is captured in [`WorldPtr::new`], so it can be checked without dereferencing the pointer.
+    #[inline(always)]
+    pub(crate) const fn id(&self) -> Option<WorldId> {
+        self.id
     }
 }
 
@@ -30,9 +44,9 @@ impl WorldPtr {
 impl Default for WorldPtr {
     #[inline]
     fn default() -> Self {
-        Self(ptr::null_mut())
+        Self {
+            ptr: ptr::null_mut(),
+            id: None,
+        }
     }
 }
-
-
-